@@ -6,16 +6,14 @@
 
 use std::io::Result;
 
-// Replace the committed protobuf files with the generated one.
+// List the .proto files of a directory.
 #[cfg(feature = "regenerate-protobuf")]
-fn generate_proto_sources() -> Result<()> {
+fn proto_files_in(path: &str) -> Result<Vec<String>> {
     use std::fs::read_dir;
     use std::io::{Error, ErrorKind};
     use std::path::Path;
-    use std::process::Command;
 
-    let path = String::from("parsec-operations/protobuf");
-    let dir_entries = read_dir(Path::new(&path))?;
+    let dir_entries = read_dir(Path::new(path))?;
     let files: Result<Vec<String>> = dir_entries
         .map(|protos_file| {
             protos_file?
@@ -31,13 +29,39 @@ fn generate_proto_sources() -> Result<()> {
         })
         // Fail the entire operation if there was an error.
         .collect();
-    let proto_files: Vec<String> = files?
+    Ok(files?
         .into_iter()
         .filter(|string| string.ends_with(".proto"))
-        .collect();
+        .collect())
+}
+
+// Replace the committed protobuf files with the generated one.
+#[cfg(feature = "regenerate-protobuf")]
+fn generate_proto_sources() -> Result<()> {
+    use std::io::{Error, ErrorKind};
+    use std::path::Path;
+    use std::process::Command;
+
+    // Definitions in `protobuf` are added by this crate next to the ones of the `parsec-operations`
+    // submodule. They must not shadow an upstream file: extensions of upstream messages go in new
+    // `_ext` packages.
+    let local_path = String::from("protobuf");
+    let path = String::from("parsec-operations/protobuf");
+    let mut proto_files = proto_files_in(&local_path)?;
+    for file in proto_files_in(&path)? {
+        if let Some(name) = Path::new(&file).file_name() {
+            if Path::new(&local_path).join(name).exists() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{} shadows an upstream definition", file),
+                ));
+            }
+        }
+        proto_files.push(file);
+    }
     let files_slices: Vec<&str> = proto_files.iter().map(|file| &file[..]).collect();
 
-    prost_build::compile_protos(&files_slices, &[&path])?;
+    prost_build::compile_protos(&files_slices, &[&local_path, &path])?;
 
     // Copy all files generated in src/operations_protobuf/generated_ops
     let status = Command::new("bash")
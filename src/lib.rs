@@ -110,7 +110,7 @@
 //!use parsec_interface::operations_protobuf::ProtobufConverter;
 //!
 //!let converter = ProtobufConverter {};
-//!let result = NativeResult::PsaGenerateKey(Result {});
+//!let result = NativeResult::PsaGenerateKey(Result::default());
 //!let result_body = converter.result_to_body(result).unwrap();
 //!let response = Response {
 //!    header: ResponseHeader {
@@ -121,6 +121,7 @@
 //!        status: ResponseStatus::Success,
 //!    },
 //!    body: result_body,
+//!    auth: None,
 //!};
 //!// stream is a Write object
 //!response.write_to_stream(&mut stream).unwrap();
@@ -173,6 +174,7 @@
 //!    },
 //!    body: converter.operation_to_body(operation).unwrap(),
 //!    auth: RequestAuth::new(Vec::from("root")),
+//!    response_auth_nonce: None,
 //!};
 //!// stream is a Write object
 //!request.write_to_stream(&mut stream).unwrap();
@@ -221,6 +223,8 @@
 //! See the [Parsec Test client](https://github.com/parallaxsecond/parsec-client-test) as an example
 //! of a Rust client.
 
+#[cfg(feature = "noise")]
+pub mod noise;
 pub mod operations;
 pub mod operations_protobuf;
 pub mod requests;
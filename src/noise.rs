@@ -0,0 +1,448 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Encrypted and authenticated transport
+//!
+//! `NoiseStream` wraps a stream, for example a TCP or vsock connection, in a channel encrypted
+//! and authenticated with the [Noise](https://noiseprotocol.org/) `XX` handshake pattern. Both
+//! peers authenticate with a static Curve25519 key pair and can check the public key of the other
+//! peer. As it implements `Read` and `Write`, the `Request` and `Response` stream functions work
+//! unchanged over it.
+//!
+//! After the handshake, every `write` call sends one or more frames made of a little-endian `u16`
+//! length followed by a Noise transport message.
+//!
+//! ```
+//! use parsec_interface::noise::NoiseKeypair;
+//!
+//! let client_keypair = NoiseKeypair::generate().unwrap();
+//! assert_eq!(client_keypair.public.len(), 32);
+//! ```
+use crate::requests::{ResponseStatus, Result};
+use crate::secrecy::{ExposeSecret, Secret};
+use derivative::Derivative;
+use log::error;
+use snow::{Builder, HandshakeState, TransportState};
+use std::io::{self, Read, Write};
+
+/// Noise protocol used for the channel
+pub const NOISE_PARAMS: &str = "Noise_XX_25519_ChaChaPoly_BLAKE2s";
+
+const PROLOGUE: &[u8] = b"parsec wire protocol 1.0";
+const MAX_MESSAGE_LEN: usize = 65535;
+const TAG_LEN: usize = 16;
+const MAX_PAYLOAD_LEN: usize = MAX_MESSAGE_LEN - TAG_LEN;
+
+/// Static key pair of a peer
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct NoiseKeypair {
+    /// Private key
+    #[derivative(Debug = "ignore")]
+    pub private: Secret<Vec<u8>>,
+    /// Public key, to be shared with the other peers
+    pub public: Vec<u8>,
+}
+
+impl NoiseKeypair {
+    /// Generate a new random key pair
+    pub fn generate() -> Result<Self> {
+        let keypair = builder()?.generate_keypair().map_err(|e| {
+            error!("Failed to generate a Noise key pair ({}).", e);
+            ResponseStatus::ConnectionError
+        })?;
+        Ok(NoiseKeypair {
+            private: Secret::new(keypair.private),
+            public: keypair.public,
+        })
+    }
+}
+
+/// Stream encrypted and authenticated with Noise
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct NoiseStream<S> {
+    #[derivative(Debug = "ignore")]
+    stream: S,
+    #[derivative(Debug = "ignore")]
+    transport: TransportState,
+    #[derivative(Debug = "ignore")]
+    read_buffer: Vec<u8>,
+    read_pos: usize,
+    #[derivative(Debug = "ignore")]
+    write_buffer: Vec<u8>,
+}
+
+impl<S: Read + Write> NoiseStream<S> {
+    /// Run the handshake as the initiator of the connection, usually the client.
+    ///
+    /// If `remote_public_key` is given, the handshake fails unless the other peer authenticates
+    /// with the corresponding private key.
+    ///
+    /// # Errors
+    /// - if the handshake messages can not be exchanged, `ResponseStatus::ConnectionError` is
+    ///   returned.
+    /// - if the handshake fails or the remote public key does not match,
+    ///   `ResponseStatus::AuthenticationError` is returned.
+    pub fn connect(
+        stream: S,
+        keypair: &NoiseKeypair,
+        remote_public_key: Option<&[u8]>,
+    ) -> Result<Self> {
+        let handshake = builder()?
+            .local_private_key(keypair.private.expose_secret())
+            .build_initiator()
+            .map_err(handshake_error)?;
+        NoiseStream::handshake(stream, handshake, remote_public_key)
+    }
+
+    /// Run the handshake as the responder of the connection, usually the service.
+    ///
+    /// If `remote_public_key` is given, the handshake fails unless the other peer authenticates
+    /// with the corresponding private key. Otherwise, the key used by the peer can be checked
+    /// with `remote_public_key` after the handshake.
+    ///
+    /// # Errors
+    /// - if the handshake messages can not be exchanged, `ResponseStatus::ConnectionError` is
+    ///   returned.
+    /// - if the handshake fails or the remote public key does not match,
+    ///   `ResponseStatus::AuthenticationError` is returned.
+    pub fn accept(
+        stream: S,
+        keypair: &NoiseKeypair,
+        remote_public_key: Option<&[u8]>,
+    ) -> Result<Self> {
+        let handshake = builder()?
+            .local_private_key(keypair.private.expose_secret())
+            .build_responder()
+            .map_err(handshake_error)?;
+        NoiseStream::handshake(stream, handshake, remote_public_key)
+    }
+
+    fn handshake(
+        mut stream: S,
+        mut handshake: HandshakeState,
+        remote_public_key: Option<&[u8]>,
+    ) -> Result<Self> {
+        let mut message = vec![0; MAX_MESSAGE_LEN];
+        let mut payload = vec![0; MAX_MESSAGE_LEN];
+        while !handshake.is_handshake_finished() {
+            if handshake.is_my_turn() {
+                let len = handshake
+                    .write_message(&[], &mut message)
+                    .map_err(handshake_error)?;
+                write_frame(&mut stream, &message[..len])?;
+            } else {
+                let frame = read_frame(&mut stream)?.ok_or_else(|| {
+                    error!("Connection closed during the Noise handshake.");
+                    ResponseStatus::ConnectionError
+                })?;
+                let _ = handshake
+                    .read_message(&frame, &mut payload)
+                    .map_err(handshake_error)?;
+                // Abort as soon as the remote static key is known, before sending anything else.
+                if let (Some(expected), Some(remote)) =
+                    (remote_public_key, handshake.get_remote_static())
+                {
+                    if remote != expected {
+                        error!(
+                            "The remote peer did not authenticate with the expected public key."
+                        );
+                        return Err(ResponseStatus::AuthenticationError);
+                    }
+                }
+            }
+        }
+
+        Ok(NoiseStream {
+            stream,
+            transport: handshake.into_transport_mode().map_err(handshake_error)?,
+            read_buffer: Vec::new(),
+            read_pos: 0,
+            write_buffer: message,
+        })
+    }
+
+    /// Static public key the remote peer authenticated with
+    pub fn remote_public_key(&self) -> Option<&[u8]> {
+        self.transport.get_remote_static()
+    }
+
+    /// Get back the wrapped stream
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S: Read + Write> Read for NoiseStream<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        while self.read_pos == self.read_buffer.len() {
+            let frame = match read_frame(&mut self.stream).map_err(to_io_error)? {
+                Some(frame) => frame,
+                None => return Ok(0),
+            };
+            let mut payload = vec![0; frame.len()];
+            let len = self
+                .transport
+                .read_message(&frame, &mut payload)
+                .map_err(|e| {
+                    error!("Failed to decrypt a Noise message ({}).", e);
+                    io::Error::new(io::ErrorKind::InvalidData, e)
+                })?;
+            payload.truncate(len);
+            self.read_buffer = payload;
+            self.read_pos = 0;
+        }
+        let available = &self.read_buffer[self.read_pos..];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.read_pos += len;
+        Ok(len)
+    }
+}
+
+impl<S: Read + Write> Write for NoiseStream<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for chunk in buf.chunks(MAX_PAYLOAD_LEN) {
+            let len = self
+                .transport
+                .write_message(chunk, &mut self.write_buffer)
+                .map_err(|e| {
+                    error!("Failed to encrypt a Noise message ({}).", e);
+                    io::Error::new(io::ErrorKind::InvalidData, e)
+                })?;
+            write_frame(&mut self.stream, &self.write_buffer[..len]).map_err(to_io_error)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+fn builder() -> Result<Builder<'static>> {
+    let params = NOISE_PARAMS.parse().map_err(handshake_error)?;
+    Ok(Builder::new(params).prologue(PROLOGUE))
+}
+
+fn handshake_error(e: snow::Error) -> ResponseStatus {
+    error!("Noise handshake failed ({}).", e);
+    ResponseStatus::AuthenticationError
+}
+
+fn to_io_error(status: ResponseStatus) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, status)
+}
+
+fn write_frame(stream: &mut impl Write, message: &[u8]) -> Result<()> {
+    // Messages are never larger than MAX_MESSAGE_LEN, which fits in a u16.
+    let len = message.len() as u16;
+    stream.write_all(&len.to_le_bytes())?;
+    stream.write_all(message)?;
+    Ok(())
+}
+
+/// Read one frame, returning `None` if the stream is closed before its start
+fn read_frame(stream: &mut impl Read) -> Result<Option<Vec<u8>>> {
+    let mut len = [0; 2];
+    let mut read = 0;
+    while read < len.len() {
+        match stream.read(&mut len[read..]) {
+            Ok(0) if read == 0 => return Ok(None),
+            Ok(0) => {
+                error!("Connection closed in the middle of a Noise frame length.");
+                return Err(ResponseStatus::ConnectionError);
+            }
+            Ok(n) => read += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e.into()),
+        }
+    }
+    let mut frame = vec![0; usize::from(u16::from_le_bytes(len))];
+    stream.read_exact(&mut frame)?;
+    Ok(Some(frame))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::request::{RequestAuth, RequestBody, RequestHeader};
+    use crate::requests::response::ResponseBody;
+    use crate::requests::{AuthType, BodyType, Opcode, ProviderId, Request, Response};
+    use std::sync::mpsc::{channel, Receiver, Sender};
+    use std::thread;
+
+    /// One end of an in-memory bidirectional pipe
+    #[derive(Debug)]
+    struct Pipe {
+        tx: Sender<Vec<u8>>,
+        rx: Receiver<Vec<u8>>,
+        buffer: Vec<u8>,
+        written: Vec<u8>,
+    }
+
+    fn pipe() -> (Pipe, Pipe) {
+        let (tx_a, rx_a) = channel();
+        let (tx_b, rx_b) = channel();
+        let end = |tx, rx| Pipe {
+            tx,
+            rx,
+            buffer: Vec::new(),
+            written: Vec::new(),
+        };
+        (end(tx_a, rx_b), end(tx_b, rx_a))
+    }
+
+    impl Read for Pipe {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.buffer.is_empty() {
+                match self.rx.recv() {
+                    Ok(bytes) => self.buffer = bytes,
+                    Err(_) => return Ok(0),
+                }
+            }
+            let len = self.buffer.len().min(buf.len());
+            buf[..len].copy_from_slice(&self.buffer[..len]);
+            let _ = self.buffer.drain(..len);
+            Ok(len)
+        }
+    }
+
+    impl Write for Pipe {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.written.extend_from_slice(buf);
+            self.tx
+                .send(buf.to_vec())
+                .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn get_request() -> Request {
+        Request {
+            header: RequestHeader {
+                provider: ProviderId::MbedCrypto,
+                session: 7,
+                content_type: BodyType::Protobuf,
+                accept_type: BodyType::Protobuf,
+                auth_type: AuthType::Direct,
+                opcode: Opcode::PsaExportKey,
+            },
+            body: RequestBody::from_bytes(b"secret key name".to_vec()),
+            auth: RequestAuth::new(b"client token".to_vec()),
+            response_auth_nonce: None,
+        }
+    }
+
+    #[test]
+    fn request_response_over_noise() {
+        let client_keypair = NoiseKeypair::generate().unwrap();
+        let service_keypair = NoiseKeypair::generate().unwrap();
+        let client_public = client_keypair.public.clone();
+        let service_public = service_keypair.public.clone();
+        let (client_end, service_end) = pipe();
+
+        let service = thread::spawn(move || {
+            let mut stream =
+                NoiseStream::accept(service_end, &service_keypair, Some(&client_public)).unwrap();
+            let request = Request::read_from_stream(&mut stream, 1 << 20).unwrap();
+            assert_eq!(*request.body, b"secret key name".to_vec());
+            assert_eq!(request.auth.buffer.expose_secret(), b"client token");
+            let mut response =
+                Response::from_request_header(request.header, ResponseStatus::Success);
+            response.body = ResponseBody::from_bytes(vec![0x42; 100_000]);
+            response.write_to_stream(&mut stream).unwrap();
+            stream.into_inner().written
+        });
+
+        let mut stream =
+            NoiseStream::connect(client_end, &client_keypair, Some(&service_public)).unwrap();
+        assert_eq!(stream.remote_public_key(), Some(service_public.as_slice()));
+        get_request().write_to_stream(&mut stream).unwrap();
+        let response = Response::read_from_stream(&mut stream, 1 << 20).unwrap();
+        assert_eq!(response.header.opcode, Opcode::PsaExportKey);
+        assert_eq!(*response.body, vec![0x42; 100_000]);
+
+        // Nothing is sent in clear
+        let client_written = stream.into_inner().written;
+        let service_written = service.join().unwrap();
+        for written in [client_written, service_written].iter() {
+            assert!(!written
+                .windows(b"client token".len())
+                .any(|window| window == b"client token"));
+            assert!(!written.windows(16).any(|window| window == [0x42; 16]));
+        }
+    }
+
+    #[test]
+    fn unexpected_remote_key() {
+        let client_keypair = NoiseKeypair::generate().unwrap();
+        let service_keypair = NoiseKeypair::generate().unwrap();
+        let other_public = NoiseKeypair::generate().unwrap().public;
+        let (client_end, service_end) = pipe();
+
+        let service = thread::spawn(move || {
+            NoiseStream::accept(service_end, &service_keypair, None).map(|_| ())
+        });
+        assert_eq!(
+            NoiseStream::connect(client_end, &client_keypair, Some(&other_public)).unwrap_err(),
+            ResponseStatus::AuthenticationError
+        );
+        // The client drops the connection before sending its last handshake message.
+        assert_eq!(
+            service.join().unwrap().unwrap_err(),
+            ResponseStatus::ConnectionError
+        );
+    }
+
+    #[test]
+    fn truncated_frame_length() {
+        assert!(read_frame(&mut io::Cursor::new(Vec::new()))
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            read_frame(&mut io::Cursor::new(vec![0x04])).unwrap_err(),
+            ResponseStatus::ConnectionError
+        );
+        assert_eq!(
+            read_frame(&mut io::Cursor::new(vec![0x04, 0x00, 0x01])).unwrap_err(),
+            ResponseStatus::ConnectionError
+        );
+        assert_eq!(
+            read_frame(&mut io::Cursor::new(vec![0x02, 0x00, 0x01, 0x02]))
+                .unwrap()
+                .unwrap(),
+            vec![0x01, 0x02]
+        );
+    }
+
+    #[test]
+    fn tampered_message() {
+        let client_keypair = NoiseKeypair::generate().unwrap();
+        let service_keypair = NoiseKeypair::generate().unwrap();
+        let (client_end, service_end) = pipe();
+
+        let service = thread::spawn(move || {
+            let mut stream = NoiseStream::accept(service_end, &service_keypair, None).unwrap();
+            let mut bytes = [0; 4];
+            stream.read_exact(&mut bytes).unwrap_err().kind()
+        });
+
+        let mut stream = NoiseStream::connect(client_end, &client_keypair, None).unwrap();
+        let mut message = vec![0; MAX_MESSAGE_LEN];
+        let len = stream
+            .transport
+            .write_message(b"ping", &mut message)
+            .unwrap();
+        message[len - 1] ^= 0x01;
+        write_frame(&mut stream.stream, &message[..len]).unwrap();
+        assert_eq!(service.join().unwrap(), io::ErrorKind::InvalidData);
+    }
+}
@@ -25,6 +25,16 @@ pub enum Operation {
         /// Name of key to be used for attesting
         attesting_key_name: Option<String>,
     },
+    /// Attestation via TPM 2.0 Certify operation
+    Certify {
+        /// Name of key to be attested
+        attested_key_name: String,
+        /// Nonce provided by the verifier, included in the signed attestation data
+        #[derivative(Debug = "ignore")]
+        nonce: Zeroizing<Vec<u8>>,
+        /// Name of key to be used for attesting
+        attesting_key_name: Option<String>,
+    },
 }
 
 /// Native result of key attestation
@@ -38,4 +48,14 @@ pub enum Result {
         #[derivative(Debug = "ignore")]
         credential: Zeroizing<Vec<u8>>,
     },
+    /// Result of attestation via TPM 2.0 Certify operation
+    Certify {
+        /// Marshalled `TPMS_ATTEST` structure produced by the TPM, containing the name of the
+        /// attested key and the nonce
+        #[derivative(Debug = "ignore")]
+        attestation_data: Zeroizing<Vec<u8>>,
+        /// Marshalled `TPMT_SIGNATURE` of the attestation data by the attesting key
+        #[derivative(Debug = "ignore")]
+        signature: Zeroizing<Vec<u8>>,
+    },
 }
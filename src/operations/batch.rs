@@ -0,0 +1,136 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Batch operation
+//!
+//! Executes an ordered list of operations in a single request. All the operations of a batch are
+//! executed by the provider of the request, so only provider operations can be batched: core
+//! operations, including the admin ones, and other batches are rejected. The body of each
+//! operation is encoded with the content type of the request, and the body of each result with
+//! its accept type.
+//!
+//! The body length limit of `Opcode::Batch` applies to the whole batch, which contains the bodies
+//! of all its operations. When a protobuf batch request is read, the body of each operation is
+//! also checked against the body length limit of its opcode.
+use super::{NativeOperation, NativeResult};
+use crate::requests::{Opcode, ResponseStatus};
+use log::error;
+
+/// Native object for the batch operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// If `false`, the execution stops after the first operation which fails. Otherwise all the
+    /// operations are executed, whatever their status.
+    pub continue_on_error: bool,
+    /// The operations to execute, in order.
+    pub operations: Vec<NativeOperation>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation
+    ///
+    /// Returns `ResponseStatus::InvalidEncoding` if the batch contains another batch or a core
+    /// operation.
+    pub fn validate(&self) -> crate::requests::Result<()> {
+        for operation in &self.operations {
+            check_batchable(operation.opcode())?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that an operation can be part of a batch, which is only the case of provider operations
+///
+/// Returns `ResponseStatus::InvalidEncoding` for batches and core operations, including the admin
+/// ones.
+pub(crate) fn check_batchable(opcode: Opcode) -> crate::requests::Result<()> {
+    if opcode == Opcode::Batch {
+        error!("Batch operations can not be nested.");
+        Err(ResponseStatus::InvalidEncoding)
+    } else if opcode.is_core() || opcode.is_admin() {
+        error!("Core operation {:?} can not be batched.", opcode);
+        Err(ResponseStatus::InvalidEncoding)
+    } else {
+        Ok(())
+    }
+}
+
+/// Native object for the batch result.
+#[derive(Debug)]
+pub struct Result {
+    /// The result of each executed operation, in the order of the batch. If the batch does not
+    /// continue on errors, the results stop at the first failed operation.
+    pub results: Vec<crate::requests::Result<NativeResult>>,
+}
+
+impl Result {
+    /// Status of the first failed operation, if any
+    pub fn first_error(&self) -> Option<ResponseStatus> {
+        self.results
+            .iter()
+            .find_map(|result| result.as_ref().err().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{delete_client, list_keys, ping, psa_destroy_key, psa_generate_random};
+
+    #[test]
+    fn nested_batch_is_invalid() {
+        let mut op = Operation {
+            continue_on_error: false,
+            operations: vec![NativeOperation::PsaDestroyKey(psa_destroy_key::Operation {
+                key_name: String::from("key"),
+            })],
+        };
+        op.validate().unwrap();
+
+        op.operations.push(NativeOperation::Batch(Operation {
+            continue_on_error: true,
+            operations: Vec::new(),
+        }));
+        assert_eq!(op.validate().unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn core_operations_are_invalid() {
+        let core_operations = vec![
+            NativeOperation::Ping(ping::Operation),
+            NativeOperation::ListKeys(list_keys::Operation::default()),
+            NativeOperation::DeleteClient(delete_client::Operation {
+                client: String::from("other"),
+            }),
+        ];
+        for operation in core_operations {
+            let op = Operation {
+                continue_on_error: true,
+                operations: vec![operation],
+            };
+            assert_eq!(op.validate().unwrap_err(), ResponseStatus::InvalidEncoding);
+        }
+    }
+
+    #[test]
+    fn first_error() {
+        let mut result = Result {
+            results: vec![Ok(NativeResult::PsaGenerateRandom(
+                psa_generate_random::Result {
+                    random_bytes: vec![0x42; 4].into(),
+                },
+            ))],
+        };
+        assert_eq!(result.first_error(), None);
+
+        result
+            .results
+            .push(Err(ResponseStatus::PsaErrorNotPermitted));
+        result
+            .results
+            .push(Err(ResponseStatus::PsaErrorDoesNotExist));
+        assert_eq!(
+            result.first_error(),
+            Some(ResponseStatus::PsaErrorNotPermitted)
+        );
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # CanDoCryptoBatch operation
+//!
+//! Checks if the provider supports a list of attributes for the operations of given types, in a
+//! single request.
+
+use super::can_do_crypto;
+use crate::requests::ResponseStatus;
+
+/// Native object for the batched capability checking operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// The checks to perform, each one being equivalent to a `CanDoCrypto` operation.
+    pub checks: Vec<can_do_crypto::Operation>,
+}
+
+/// Native object for the batched capability checking result.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Result {
+    /// The status of each check, in the order of the operation. It is the status which the
+    /// `CanDoCrypto` operation would have returned: `Success` if the attributes are supported.
+    pub statuses: Vec<ResponseStatus>,
+}
+
+impl Result {
+    /// Check if all the checks of the batch succeeded
+    pub fn all_supported(&self) -> bool {
+        self.statuses
+            .iter()
+            .all(|status| *status == ResponseStatus::Success)
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Key references
+//!
+//! Operations using a public key can either reference a key stored in the service by its name,
+//! or carry the public key inline. Inline public keys avoid importing a peer's key just to use
+//! it once.
+//!
+//! Volatile keys are referenced by a handle chosen by the service instead of a name. The handle
+//! is returned by `PsaGenerateKey` or `PsaImportKey` and is used in place of the key name in the
+//! following operations. As each request may use its own connection, volatile keys are scoped to
+//! the authenticated client creating them, like the names of persistent keys: any request of the
+//! same client can use the handle, requests of other clients can not. Volatile keys are destroyed
+//! by `PsaCloseKey` or when the service stops, and never reach the persistent store.
+use super::psa_key_attributes::{Attributes, Lifetime};
+use super::utils_key_data::validate_key_data;
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+
+/// Prefix of the handles of volatile keys
+///
+/// Names of persistent keys can not start with it.
+pub const VOLATILE_KEY_HANDLE_PREFIX: &str = "#volatile/";
+
+/// Check if a key name is the handle of a volatile key
+pub fn is_volatile_key_handle(key_name: &str) -> bool {
+    key_name.starts_with(VOLATILE_KEY_HANDLE_PREFIX)
+}
+
+/// Check that the name of a new key is consistent with its lifetime
+///
+/// Volatile keys are created without a name, the service choosing their handle, and persistent
+/// keys can not have a name reserved for handles. The names of persistent keys are otherwise not
+/// checked. Other lifetimes are not supported.
+pub fn validate_new_key_name(key_name: &str, lifetime: Lifetime) -> Result<()> {
+    match lifetime {
+        Lifetime::Volatile if !key_name.is_empty() => {
+            error!("Volatile keys must be created without a name.");
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        }
+        Lifetime::Persistent if is_volatile_key_handle(key_name) => {
+            error!("Invalid name for a persistent key: \"{}\".", key_name);
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        }
+        Lifetime::Custom(lifetime) => {
+            error!("Key lifetime 0x{:08x} is not supported.", lifetime);
+            Err(ResponseStatus::PsaErrorNotSupported)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Public key carried inline in an operation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    /// Attributes of the public key. They are used instead of the attributes of a stored key
+    /// to validate the operation.
+    pub attributes: Attributes,
+    /// Public key data, in the format of `PsaExportPublicKey`.
+    pub data: Vec<u8>,
+}
+
+impl PublicKey {
+    /// Attributes of the public key, after checking that they describe a public key and that the
+    /// data is consistent with them
+    ///
+    /// Returns `ResponseStatus::PsaErrorInvalidArgument` if the key is not a valid public key.
+    pub fn checked_attributes(&self) -> Result<Attributes> {
+        if !self.attributes.key_type.is_public_key() {
+            error!("Only public keys can be carried inline in an operation.");
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        validate_key_data(&self.attributes, &self.data)?;
+        Ok(self.attributes)
+    }
+}
+
+/// Reference to the key used by an operation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyReference {
+    /// Name of a key stored in the service
+    Name(String),
+    /// Public key supplied by the caller
+    PublicKey(PublicKey),
+}
+
+impl KeyReference {
+    /// Name of the stored key, if the reference is a name
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            KeyReference::Name(name) => Some(name),
+            KeyReference::PublicKey(_) => None,
+        }
+    }
+
+    /// Attributes of the inline public key, after checking that they describe a public key and
+    /// that the data is consistent with them
+    ///
+    /// Returns `ResponseStatus::PsaErrorInvalidArgument` if the reference is a name, or if the
+    /// inline key is not a valid public key.
+    pub fn public_key_attributes(&self) -> Result<Attributes> {
+        match self {
+            KeyReference::Name(_) => {
+                error!("The operation references a stored key instead of carrying a public key.");
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            }
+            KeyReference::PublicKey(public_key) => public_key.checked_attributes(),
+        }
+    }
+}
+
+impl From<String> for KeyReference {
+    fn from(name: String) -> Self {
+        KeyReference::Name(name)
+    }
+}
+
+impl From<&str> for KeyReference {
+    fn from(name: &str) -> Self {
+        KeyReference::Name(name.to_string())
+    }
+}
+
+impl From<PublicKey> for KeyReference {
+    fn from(public_key: PublicKey) -> Self {
+        KeyReference::PublicKey(public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::Algorithm;
+    use crate::operations::psa_key_attributes::{EccFamily, Policy, Type, UsageFlags};
+
+    fn public_key(key_type: Type, data: Vec<u8>) -> PublicKey {
+        PublicKey {
+            attributes: Attributes {
+                lifetime: Lifetime::Volatile,
+                key_type,
+                bits: 256,
+                policy: Policy {
+                    usage_flags: UsageFlags::default(),
+                    permitted_algorithms: Algorithm::None,
+                },
+            },
+            data,
+        }
+    }
+
+    #[test]
+    fn new_key_names() {
+        validate_new_key_name("some key", Lifetime::Persistent).unwrap();
+        validate_new_key_name("", Lifetime::Volatile).unwrap();
+        // Empty names of persistent keys are left to the service to accept or not
+        validate_new_key_name("", Lifetime::Persistent).unwrap();
+        let invalid = vec![
+            ("#volatile/1", Lifetime::Persistent),
+            ("some key", Lifetime::Volatile),
+        ];
+        for (key_name, lifetime) in invalid {
+            assert_eq!(
+                validate_new_key_name(key_name, lifetime).unwrap_err(),
+                ResponseStatus::PsaErrorInvalidArgument
+            );
+        }
+        assert_eq!(
+            validate_new_key_name("some key", Lifetime::Custom(0x8000_0001)).unwrap_err(),
+            ResponseStatus::PsaErrorNotSupported
+        );
+    }
+
+    #[test]
+    fn inline_public_key() {
+        let curve_family = EccFamily::SecpR1;
+        let mut point = vec![0x04];
+        point.extend(vec![0x42; 64]);
+
+        let key = public_key(Type::EccPublicKey { curve_family }, point.clone());
+        assert_eq!(key.checked_attributes().unwrap().bits, 256);
+        let key = KeyReference::from(key);
+        assert_eq!(key.name(), None);
+        assert_eq!(key.public_key_attributes().unwrap().bits, 256);
+
+        let key = KeyReference::from("some key");
+        assert_eq!(key.name(), Some("some key"));
+        assert_eq!(
+            key.public_key_attributes().unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        let truncated = public_key(Type::EccPublicKey { curve_family }, point[..33].to_vec());
+        assert_eq!(
+            truncated.checked_attributes().unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        let key_pair = public_key(Type::EccKeyPair { curve_family }, vec![0x42; 32]);
+        assert_eq!(
+            key_pair.checked_attributes().unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
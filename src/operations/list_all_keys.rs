@@ -0,0 +1,46 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # ListAllKeys operation
+//!
+//! Lists the keys of all clients in Parsec, along with the name of the client owning them.
+use super::list_keys::KeyInfo;
+use crate::requests::ProviderId;
+
+/// Structure holding the information of a key and the name of the client application owning it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientKeyInfo {
+    /// The name of the client application owning the key.
+    pub client: String,
+    /// The information of the key.
+    pub key_info: KeyInfo,
+}
+
+/// Native object for the admin key listing operation.
+///
+/// All the filters are optional: the default operation lists all the keys of all the clients.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Operation {
+    /// Only list the keys owned by this client application.
+    pub client: Option<String>,
+    /// Only list the keys stored in this provider.
+    pub provider_id: Option<ProviderId>,
+}
+
+impl Operation {
+    /// Check if a key matches the filters of the operation
+    pub fn matches(&self, key: &ClientKeyInfo) -> bool {
+        self.client
+            .as_ref()
+            .map_or(true, |client| &key.client == client)
+            && self
+                .provider_id
+                .map_or(true, |id| key.key_info.provider_id == id)
+    }
+}
+
+/// Native object for the admin key listing result.
+#[derive(Debug)]
+pub struct Result {
+    /// A list of `ClientKeyInfo` structures.
+    pub keys: Vec<ClientKeyInfo>,
+}
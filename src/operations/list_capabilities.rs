@@ -0,0 +1,232 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # ListCapabilities operation
+//!
+//! Lists the key types, algorithms and usages supported by the providers. The capabilities can be
+//! cached by clients to answer `CanDoCrypto` queries locally.
+use super::can_do_crypto::{self, CheckType};
+use super::psa_algorithm::Algorithm;
+use super::psa_key_attributes::{Type, UsageFlags};
+use super::utils_usage_flags::usage_flags_contain;
+use crate::requests::{ProviderId, ResponseStatus};
+use log::error;
+
+/// Capabilities of a provider for one key type
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyTypeCapability {
+    /// The key type
+    pub key_type: Type,
+    /// Smallest supported size of the keys, in bits
+    pub min_bits: usize,
+    /// Largest supported size of the keys, in bits
+    pub max_bits: usize,
+    /// Algorithms which can be used with keys of this type
+    pub algorithms: Vec<Algorithm>,
+    /// Largest combinations of usage flags which can be permitted together on keys of this type
+    pub usage_flags: Vec<UsageFlags>,
+    /// Ways of obtaining and using keys of this type
+    pub check_types: Vec<CheckType>,
+}
+
+impl KeyTypeCapability {
+    /// Check if the capability supports the attributes for the given type of check
+    pub fn supports(&self, operation: &can_do_crypto::Operation) -> bool {
+        let attributes = &operation.attributes;
+        let bits_supported =
+            attributes.bits == 0 || (self.min_bits..=self.max_bits).contains(&attributes.bits);
+        // Using an existing key requires an algorithm, it is optional otherwise.
+        let algorithm_supported = match attributes.policy.permitted_algorithms {
+            Algorithm::None => operation.check_type != CheckType::Use,
+            algorithm => self.algorithms.contains(&algorithm),
+        };
+        let usage_supported = self
+            .usage_flags
+            .iter()
+            .any(|flags| usage_flags_contain(*flags, attributes.policy.usage_flags));
+
+        self.key_type == attributes.key_type
+            && self.check_types.contains(&operation.check_type)
+            && bits_supported
+            && algorithm_supported
+            && usage_supported
+    }
+}
+
+/// Capabilities of one provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderCapabilities {
+    /// The provider
+    pub provider_id: ProviderId,
+    /// Capabilities for each supported key type
+    pub key_types: Vec<KeyTypeCapability>,
+}
+
+impl ProviderCapabilities {
+    /// Answer a `CanDoCrypto` query from the capabilities, without contacting the service.
+    ///
+    /// Returns `ResponseStatus::PsaErrorNotSupported` if the provider does not support the
+    /// attributes for the type of check, as the `CanDoCrypto` operation would.
+    pub fn can_do_crypto(
+        &self,
+        operation: &can_do_crypto::Operation,
+    ) -> crate::requests::Result<()> {
+        if self
+            .key_types
+            .iter()
+            .any(|capability| capability.supports(operation))
+        {
+            Ok(())
+        } else {
+            error!(
+                "The {} does not support the key attributes for {:?}.",
+                self.provider_id, operation.check_type
+            );
+            Err(ResponseStatus::PsaErrorNotSupported)
+        }
+    }
+}
+
+/// Native object for the capabilities listing operation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Operation {
+    /// Only list the capabilities of this provider, all providers are listed otherwise.
+    pub provider_id: Option<ProviderId>,
+}
+
+/// Native object for the capabilities listing result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Result {
+    /// The capabilities of each provider.
+    pub providers: Vec<ProviderCapabilities>,
+}
+
+impl Result {
+    /// Capabilities of a provider, if listed
+    pub fn provider(&self, provider_id: ProviderId) -> Option<&ProviderCapabilities> {
+        self.providers
+            .iter()
+            .find(|provider| provider.provider_id == provider_id)
+    }
+
+    /// Answer a `CanDoCrypto` query for a provider from the capabilities, without contacting the
+    /// service.
+    ///
+    /// Returns `ResponseStatus::PsaErrorNotSupported` if the provider is not listed or does not
+    /// support the attributes for the type of check.
+    pub fn can_do_crypto(
+        &self,
+        provider_id: ProviderId,
+        operation: &can_do_crypto::Operation,
+    ) -> crate::requests::Result<()> {
+        match self.provider(provider_id) {
+            Some(provider) => provider.can_do_crypto(operation),
+            None => {
+                error!("The capabilities of the {} are not listed.", provider_id);
+                Err(ResponseStatus::PsaErrorNotSupported)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{AsymmetricSignature, Hash, SignHash};
+    use crate::operations::psa_key_attributes::{Attributes, EccFamily, Lifetime, Policy};
+
+    fn ecdsa() -> Algorithm {
+        Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+            hash_alg: SignHash::Specific(Hash::Sha256),
+        })
+    }
+
+    fn sign_flags() -> UsageFlags {
+        let mut flags = UsageFlags::default();
+        let _ = flags.set_sign_hash().set_verify_hash().set_export();
+        flags
+    }
+
+    fn capabilities() -> Result {
+        Result {
+            providers: vec![ProviderCapabilities {
+                provider_id: ProviderId::MbedCrypto,
+                key_types: vec![KeyTypeCapability {
+                    key_type: Type::EccKeyPair {
+                        curve_family: EccFamily::SecpR1,
+                    },
+                    min_bits: 256,
+                    max_bits: 384,
+                    algorithms: vec![ecdsa()],
+                    usage_flags: vec![sign_flags()],
+                    check_types: vec![CheckType::Use, CheckType::Generate],
+                }],
+            }],
+        }
+    }
+
+    fn query(check_type: CheckType, bits: usize, algorithm: Algorithm) -> can_do_crypto::Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        can_do_crypto::Operation {
+            check_type,
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::EccKeyPair {
+                    curve_family: EccFamily::SecpR1,
+                },
+                bits,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: algorithm,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn supported_queries() {
+        let capabilities = capabilities();
+        for query in [
+            query(CheckType::Use, 256, ecdsa()),
+            query(CheckType::Use, 0, ecdsa()),
+            query(CheckType::Generate, 384, Algorithm::None),
+        ]
+        .iter()
+        {
+            capabilities
+                .can_do_crypto(ProviderId::MbedCrypto, query)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn unsupported_queries() {
+        let capabilities = capabilities();
+        let mut wrong_usage = query(CheckType::Use, 256, ecdsa());
+        let _ = wrong_usage.attributes.policy.usage_flags.set_decrypt();
+        let mut wrong_type = query(CheckType::Generate, 256, ecdsa());
+        wrong_type.attributes.key_type = Type::RsaKeyPair;
+        let unsupported = [
+            query(CheckType::Import, 256, ecdsa()),
+            query(CheckType::Use, 521, ecdsa()),
+            query(CheckType::Use, 256, Algorithm::None),
+            query(CheckType::Generate, 256, Algorithm::Hash(Hash::Sha256)),
+            wrong_usage,
+            wrong_type,
+        ];
+        for query in unsupported.iter() {
+            assert_eq!(
+                capabilities
+                    .can_do_crypto(ProviderId::MbedCrypto, query)
+                    .unwrap_err(),
+                ResponseStatus::PsaErrorNotSupported
+            );
+        }
+        assert_eq!(
+            capabilities
+                .can_do_crypto(ProviderId::Pkcs11, &query(CheckType::Use, 256, ecdsa()))
+                .unwrap_err(),
+            ResponseStatus::PsaErrorNotSupported
+        );
+    }
+}
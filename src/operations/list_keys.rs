@@ -3,7 +3,8 @@
 //! # ListKeys operation
 //!
 //! Lists all keys belonging to the application.
-use super::psa_key_attributes::Attributes;
+use super::psa_key_attributes::{Attributes, Type, UsageFlags};
+use super::utils_usage_flags::usage_flags_contain;
 use crate::requests::ProviderId;
 
 /// Structure holding the basic information for a key in the application for client discovery.
@@ -18,12 +19,124 @@ pub struct KeyInfo {
 }
 
 /// Native object for key listing operation.
-#[derive(Copy, Clone, Debug)]
-pub struct Operation;
+///
+/// All the filters are optional: the default operation lists all the keys of the application.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Operation {
+    /// Only list the keys stored in this provider.
+    pub provider_id: Option<ProviderId>,
+    /// Only list the keys of this type.
+    pub key_type: Option<Type>,
+    /// Only list the keys whose policy allows at least all of these usages.
+    pub usage_flags: Option<UsageFlags>,
+    /// Only list the keys whose name starts with this prefix.
+    pub name_prefix: Option<String>,
+    /// Maximum number of keys to return in the result. The service may return fewer keys, and
+    /// chooses the page size if it is not set.
+    pub page_size: Option<u32>,
+    /// Token returned in the result of the previous request, to get the next page of keys. The
+    /// filters must be the same as in the previous request.
+    pub continuation_token: Option<String>,
+}
+
+impl Operation {
+    /// Check if a key matches the filters of the operation
+    pub fn matches(&self, key: &KeyInfo) -> bool {
+        self.provider_id.map_or(true, |id| key.provider_id == id)
+            && self
+                .key_type
+                .map_or(true, |key_type| key.attributes.key_type == key_type)
+            && self.usage_flags.map_or(true, |usage_flags| {
+                usage_flags_contain(key.attributes.policy.usage_flags, usage_flags)
+            })
+            && self
+                .name_prefix
+                .as_ref()
+                .map_or(true, |prefix| key.name.starts_with(prefix.as_str()))
+    }
+}
 
 /// Native object for key listing result.
 #[derive(Debug)]
 pub struct Result {
     /// A list of `KeyInfo` structures.
     pub keys: Vec<KeyInfo>,
+    /// Set if more keys match the filters of the operation: pass it in the next operation to get
+    /// the next page of keys.
+    pub continuation_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::Algorithm;
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy};
+
+    fn get_key_info() -> KeyInfo {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash().set_verify_hash().set_export();
+        KeyInfo {
+            provider_id: ProviderId::MbedCrypto,
+            name: String::from("tls/server-key"),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::EccKeyPair {
+                    curve_family: EccFamily::SecpR1,
+                },
+                bits: 256,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::None,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn default_matches_all() {
+        assert!(Operation::default().matches(&get_key_info()));
+    }
+
+    #[test]
+    fn filters_match() {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        let op = Operation {
+            provider_id: Some(ProviderId::MbedCrypto),
+            key_type: Some(Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            }),
+            usage_flags: Some(usage_flags),
+            name_prefix: Some(String::from("tls/")),
+            ..Default::default()
+        };
+        assert!(op.matches(&get_key_info()));
+    }
+
+    #[test]
+    fn filters_do_not_match() {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash().set_decrypt();
+        let ops = vec![
+            Operation {
+                provider_id: Some(ProviderId::Tpm),
+                ..Default::default()
+            },
+            Operation {
+                key_type: Some(Type::RsaKeyPair),
+                ..Default::default()
+            },
+            Operation {
+                usage_flags: Some(usage_flags),
+                ..Default::default()
+            },
+            Operation {
+                name_prefix: Some(String::from("ssh/")),
+                ..Default::default()
+            },
+        ];
+        for op in ops {
+            assert!(!op.matches(&get_key_info()), "operation: {:?}", op);
+        }
+    }
 }
@@ -32,18 +32,34 @@ pub mod list_providers;
 pub mod list_authenticators;
 pub mod list_keys;
 pub mod delete_client;
+pub mod list_all_keys;
 pub mod list_clients;
 pub mod psa_generate_random;
 pub mod psa_raw_key_agreement;
 pub mod can_do_crypto;
+pub mod psa_copy_key;
+pub mod psa_get_key_attributes;
+pub mod psa_close_key;
+pub mod list_capabilities;
+pub mod can_do_crypto_batch;
+pub mod batch;
 pub mod attest_key;
 pub mod prepare_key_attestation;
+pub mod key_reference;
 pub mod utils_deprecated_primitives;
+pub mod utils_compliance_profile;
+pub mod utils_key_data;
+pub mod utils_public_key_format;
+pub mod utils_signature_format;
+mod utils_base64;
+mod utils_der;
+mod utils_usage_flags;
 
 pub use psa_crypto::types::algorithm as psa_algorithm;
 pub use psa_crypto::types::key as psa_key_attributes;
 
-use crate::requests::{request::RequestBody, response::ResponseBody, BodyType, Opcode, Result};
+use crate::requests::response::{ErrorDetails, ResponseBody};
+use crate::requests::{request::RequestBody, BodyType, Opcode, ResponseStatus, Result};
 
 /// Container type for operation conversion values, holding a native operation object
 /// to be passed in/out of a converter.
@@ -61,6 +77,8 @@ pub enum NativeOperation {
     ListClients(list_clients::Operation),
     /// DeleteClient operation
     DeleteClient(delete_client::Operation),
+    /// ListAllKeys operation
+    ListAllKeys(list_all_keys::Operation),
     /// Ping operation
     Ping(ping::Operation),
     /// PsaGenerateKey operation
@@ -103,6 +121,18 @@ pub enum NativeOperation {
     PsaVerifyMessage(psa_verify_message::Operation),
     /// CanDoCrypto operation
     CanDoCrypto(can_do_crypto::Operation),
+    /// PsaCopyKey operation
+    PsaCopyKey(psa_copy_key::Operation),
+    /// PsaGetKeyAttributes operation
+    PsaGetKeyAttributes(psa_get_key_attributes::Operation),
+    /// PsaCloseKey operation
+    PsaCloseKey(psa_close_key::Operation),
+    /// ListCapabilities operation
+    ListCapabilities(list_capabilities::Operation),
+    /// CanDoCryptoBatch operation
+    CanDoCryptoBatch(can_do_crypto_batch::Operation),
+    /// Batch operation
+    Batch(batch::Operation),
     /// AttestKey operation
     AttestKey(attest_key::Operation),
     /// PrepareKeyAttestation operation
@@ -129,6 +159,7 @@ impl NativeOperation {
             NativeOperation::ListKeys(_) => Opcode::ListKeys,
             NativeOperation::ListClients(_) => Opcode::ListClients,
             NativeOperation::DeleteClient(_) => Opcode::DeleteClient,
+            NativeOperation::ListAllKeys(_) => Opcode::ListAllKeys,
             NativeOperation::PsaAsymmetricEncrypt(_) => Opcode::PsaAsymmetricEncrypt,
             NativeOperation::PsaAsymmetricDecrypt(_) => Opcode::PsaAsymmetricDecrypt,
             NativeOperation::PsaAeadEncrypt(_) => Opcode::PsaAeadEncrypt,
@@ -140,6 +171,12 @@ impl NativeOperation {
             NativeOperation::PsaSignMessage(_) => Opcode::PsaSignMessage,
             NativeOperation::PsaVerifyMessage(_) => Opcode::PsaVerifyMessage,
             NativeOperation::CanDoCrypto(_) => Opcode::CanDoCrypto,
+            NativeOperation::PsaCopyKey(_) => Opcode::PsaCopyKey,
+            NativeOperation::PsaGetKeyAttributes(_) => Opcode::PsaGetKeyAttributes,
+            NativeOperation::PsaCloseKey(_) => Opcode::PsaCloseKey,
+            NativeOperation::ListCapabilities(_) => Opcode::ListCapabilities,
+            NativeOperation::CanDoCryptoBatch(_) => Opcode::CanDoCryptoBatch,
+            NativeOperation::Batch(_) => Opcode::Batch,
             NativeOperation::AttestKey(_) => Opcode::AttestKey,
             NativeOperation::PrepareKeyAttestation(_) => Opcode::PrepareKeyAttestation,
         }
@@ -162,6 +199,8 @@ pub enum NativeResult {
     ListClients(list_clients::Result),
     /// DeleteClient result
     DeleteClient(delete_client::Result),
+    /// ListAllKeys result
+    ListAllKeys(list_all_keys::Result),
     /// Ping result
     Ping(ping::Result),
     /// PsaGenerateKey result
@@ -204,6 +243,18 @@ pub enum NativeResult {
     PsaVerifyMessage(psa_verify_message::Result),
     /// CanDoCrypto result
     CanDoCrypto(can_do_crypto::Result),
+    /// PsaCopyKey result
+    PsaCopyKey(psa_copy_key::Result),
+    /// PsaGetKeyAttributes result
+    PsaGetKeyAttributes(psa_get_key_attributes::Result),
+    /// PsaCloseKey result
+    PsaCloseKey(psa_close_key::Result),
+    /// ListCapabilities result
+    ListCapabilities(list_capabilities::Result),
+    /// CanDoCryptoBatch result
+    CanDoCryptoBatch(can_do_crypto_batch::Result),
+    /// Batch result
+    Batch(batch::Result),
     /// AttestKey result
     AttestKey(attest_key::Result),
     /// AttestKey result
@@ -230,6 +281,7 @@ impl NativeResult {
             NativeResult::ListKeys(_) => Opcode::ListKeys,
             NativeResult::ListClients(_) => Opcode::ListClients,
             NativeResult::DeleteClient(_) => Opcode::DeleteClient,
+            NativeResult::ListAllKeys(_) => Opcode::ListAllKeys,
             NativeResult::PsaAsymmetricEncrypt(_) => Opcode::PsaAsymmetricEncrypt,
             NativeResult::PsaAsymmetricDecrypt(_) => Opcode::PsaAsymmetricDecrypt,
             NativeResult::PsaAeadEncrypt(_) => Opcode::PsaAeadEncrypt,
@@ -241,6 +293,12 @@ impl NativeResult {
             NativeResult::PsaSignMessage(_) => Opcode::PsaSignMessage,
             NativeResult::PsaVerifyMessage(_) => Opcode::PsaVerifyMessage,
             NativeResult::CanDoCrypto(_) => Opcode::CanDoCrypto,
+            NativeResult::PsaCopyKey(_) => Opcode::PsaCopyKey,
+            NativeResult::PsaGetKeyAttributes(_) => Opcode::PsaGetKeyAttributes,
+            NativeResult::PsaCloseKey(_) => Opcode::PsaCloseKey,
+            NativeResult::ListCapabilities(_) => Opcode::ListCapabilities,
+            NativeResult::CanDoCryptoBatch(_) => Opcode::CanDoCryptoBatch,
+            NativeResult::Batch(_) => Opcode::Batch,
             NativeResult::AttestKey(_) => Opcode::AttestKey,
             NativeResult::PrepareKeyAttestation(_) => Opcode::PrepareKeyAttestation,
         }
@@ -276,6 +334,26 @@ pub trait Convert {
     /// # Errors
     /// - if serialization fails, `ResponseStatus::SerializingBodyFailed` is returned
     fn result_to_body(&self, result: NativeResult) -> Result<ResponseBody>;
+
+    /// Create a response body from the details of an error.
+    ///
+    /// The default implementation does not support error details and always fails.
+    ///
+    /// # Errors
+    /// - if serialization fails, `ResponseStatus::SerializingBodyFailed` is returned
+    fn error_details_to_body(&self, _details: ErrorDetails) -> Result<ResponseBody> {
+        Err(ResponseStatus::SerializingBodyFailed)
+    }
+
+    /// Create the details of an error from a response body.
+    ///
+    /// The default implementation does not support error details and always fails.
+    ///
+    /// # Errors
+    /// - if deserialization fails, `ResponseStatus::DeserializingBodyFailed` is returned
+    fn body_to_error_details(&self, _body: ResponseBody) -> Result<ErrorDetails> {
+        Err(ResponseStatus::DeserializingBodyFailed)
+    }
 }
 
 impl From<list_providers::Operation> for NativeOperation {
@@ -314,6 +392,12 @@ impl From<delete_client::Operation> for NativeOperation {
     }
 }
 
+impl From<list_all_keys::Operation> for NativeOperation {
+    fn from(op: list_all_keys::Operation) -> Self {
+        NativeOperation::ListAllKeys(op)
+    }
+}
+
 impl From<ping::Operation> for NativeOperation {
     fn from(op: ping::Operation) -> Self {
         NativeOperation::Ping(op)
@@ -439,6 +523,42 @@ impl From<can_do_crypto::Operation> for NativeOperation {
         NativeOperation::CanDoCrypto(op)
     }
 }
+
+impl From<psa_copy_key::Operation> for NativeOperation {
+    fn from(op: psa_copy_key::Operation) -> Self {
+        NativeOperation::PsaCopyKey(op)
+    }
+}
+
+impl From<psa_get_key_attributes::Operation> for NativeOperation {
+    fn from(op: psa_get_key_attributes::Operation) -> Self {
+        NativeOperation::PsaGetKeyAttributes(op)
+    }
+}
+
+impl From<psa_close_key::Operation> for NativeOperation {
+    fn from(op: psa_close_key::Operation) -> Self {
+        NativeOperation::PsaCloseKey(op)
+    }
+}
+
+impl From<list_capabilities::Operation> for NativeOperation {
+    fn from(op: list_capabilities::Operation) -> Self {
+        NativeOperation::ListCapabilities(op)
+    }
+}
+
+impl From<can_do_crypto_batch::Operation> for NativeOperation {
+    fn from(op: can_do_crypto_batch::Operation) -> Self {
+        NativeOperation::CanDoCryptoBatch(op)
+    }
+}
+
+impl From<batch::Operation> for NativeOperation {
+    fn from(op: batch::Operation) -> Self {
+        NativeOperation::Batch(op)
+    }
+}
 impl From<attest_key::Operation> for NativeOperation {
     fn from(op: attest_key::Operation) -> Self {
         NativeOperation::AttestKey(op)
@@ -486,6 +606,12 @@ impl From<delete_client::Result> for NativeResult {
     }
 }
 
+impl From<list_all_keys::Result> for NativeResult {
+    fn from(op: list_all_keys::Result) -> Self {
+        NativeResult::ListAllKeys(op)
+    }
+}
+
 impl From<ping::Result> for NativeResult {
     fn from(op: ping::Result) -> Self {
         NativeResult::Ping(op)
@@ -612,6 +738,42 @@ impl From<can_do_crypto::Result> for NativeResult {
     }
 }
 
+impl From<psa_copy_key::Result> for NativeResult {
+    fn from(op: psa_copy_key::Result) -> Self {
+        NativeResult::PsaCopyKey(op)
+    }
+}
+
+impl From<psa_get_key_attributes::Result> for NativeResult {
+    fn from(op: psa_get_key_attributes::Result) -> Self {
+        NativeResult::PsaGetKeyAttributes(op)
+    }
+}
+
+impl From<psa_close_key::Result> for NativeResult {
+    fn from(op: psa_close_key::Result) -> Self {
+        NativeResult::PsaCloseKey(op)
+    }
+}
+
+impl From<list_capabilities::Result> for NativeResult {
+    fn from(op: list_capabilities::Result) -> Self {
+        NativeResult::ListCapabilities(op)
+    }
+}
+
+impl From<can_do_crypto_batch::Result> for NativeResult {
+    fn from(op: can_do_crypto_batch::Result) -> Self {
+        NativeResult::CanDoCryptoBatch(op)
+    }
+}
+
+impl From<batch::Result> for NativeResult {
+    fn from(op: batch::Result) -> Self {
+        NativeResult::Batch(op)
+    }
+}
+
 impl From<attest_key::Result> for NativeResult {
     fn from(op: attest_key::Result) -> Self {
         NativeResult::AttestKey(op)
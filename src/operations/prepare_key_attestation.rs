@@ -17,6 +17,13 @@ pub enum Operation {
         /// Name of key to be used for attesting
         attesting_key_name: Option<String>,
     },
+    /// Get parameters for TPM 2.0 Certify operation
+    Certify {
+        /// Name of key to be attested
+        attested_key_name: String,
+        /// Name of key to be used for attesting
+        attesting_key_name: Option<String>,
+    },
 }
 
 /// Native result of retrieving key attestation parameters
@@ -36,4 +43,16 @@ pub enum Result {
         #[derivative(Debug = "ignore")]
         attesting_key_pub: Zeroizing<Vec<u8>>,
     },
+    /// Parameters for TPM 2.0 Certify operation
+    Certify {
+        /// TPM name of key to be attested, expected in the attestation data
+        #[derivative(Debug = "ignore")]
+        name: Zeroizing<Vec<u8>>,
+        /// TPM public key parameters of object to be attested
+        #[derivative(Debug = "ignore")]
+        public: Zeroizing<Vec<u8>>,
+        /// Public part of attesting key, used to verify the signature of the attestation data
+        #[derivative(Debug = "ignore")]
+        attesting_key_pub: Zeroizing<Vec<u8>>,
+    },
 }
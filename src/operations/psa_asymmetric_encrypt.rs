@@ -4,6 +4,7 @@
 //!
 //! Encrypt a short message with a public key.
 
+use super::key_reference::KeyReference;
 use super::psa_key_attributes::Attributes;
 use crate::operations::psa_algorithm::AsymmetricEncryption;
 use crate::requests::ResponseStatus;
@@ -13,8 +14,9 @@ use derivative::Derivative;
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct Operation {
-    /// Defines which key should be used for the encryption operation.
-    pub key_name: String,
+    /// Defines which key should be used for the encryption operation: either the name of a stored
+    /// key or a public key carried inline.
+    pub key: KeyReference,
     /// An asymmetric encryption algorithm that is compatible with the key type
     pub alg: AsymmetricEncryption,
     /// The short message to be encrypted.
@@ -45,6 +47,14 @@ impl Operation {
         }
         Ok(())
     }
+
+    /// Validate the contents of the operation against the attributes of its inline public key
+    ///
+    /// On top of the checks done by `validate`, this method checks that the operation carries a
+    /// public key whose data is consistent with its attributes.
+    pub fn validate_inline(&self) -> crate::requests::Result<()> {
+        self.validate(self.key.public_key_attributes()?)
+    }
 }
 
 /// Native object for asymmetric encrypt result.
@@ -79,7 +89,7 @@ mod tests {
     #[test]
     fn validate_success() {
         (Operation {
-            key_name: String::from("some key"),
+            key: KeyReference::from("some key"),
             alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
             plaintext: vec![0xff, 32].into(),
             salt: None,
@@ -94,7 +104,7 @@ mod tests {
         attrs.policy.usage_flags = UsageFlags::default();
         assert_eq!(
             (Operation {
-                key_name: String::from("some key"),
+                key: KeyReference::from("some key"),
                 alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
                 plaintext: vec![0xff, 32].into(),
                 salt: None,
@@ -109,7 +119,7 @@ mod tests {
     fn wrong_algorithm() {
         assert_eq!(
             (Operation {
-                key_name: String::from("some key"),
+                key: KeyReference::from("some key"),
                 alg: AsymmetricEncryption::RsaOaep {
                     hash_alg: Hash::Sha256,
                 },
@@ -126,7 +136,7 @@ mod tests {
     fn invalid_plaintext() {
         assert_eq!(
             (Operation {
-                key_name: String::from("some key"),
+                key: KeyReference::from("some key"),
                 alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
                 plaintext: vec![].into(),
                 salt: None,
@@ -141,7 +151,7 @@ mod tests {
     fn salt_with_rsapkcs1v15crypt() {
         assert_eq!(
             (Operation {
-                key_name: String::from("some key"),
+                key: KeyReference::from("some key"),
                 alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
                 plaintext: vec![0xff, 32].into(),
                 salt: Some(zeroize::Zeroizing::new(vec![0xff, 32])),
@@ -0,0 +1,62 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCloseKey operation
+//!
+//! Destroy volatile keys created by the client.
+
+use super::key_reference::is_volatile_key_handle;
+use crate::requests::ResponseStatus;
+use log::error;
+
+/// Native object for volatile key closing operations.
+#[derive(Clone, Debug, Default)]
+pub struct Operation {
+    /// Handle of the volatile key to close, as returned by `PsaGenerateKey` or `PsaImportKey`.
+    /// If it is not set, all the volatile keys of the client are closed.
+    pub key_handle: Option<String>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation
+    ///
+    /// This method checks that the key handle, if set, is the handle of a volatile key. Persistent
+    /// keys are destroyed with `PsaDestroyKey`.
+    pub fn validate(&self) -> crate::requests::Result<()> {
+        match &self.key_handle {
+            Some(key_handle) if !is_volatile_key_handle(key_handle) => {
+                error!("\"{}\" is not the handle of a volatile key.", key_handle);
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Native object for the result of volatile key closing operations.
+///
+/// True result of operation is returned in the response `status`.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate() {
+        Operation::default().validate().unwrap();
+        Operation {
+            key_handle: Some(String::from("#volatile/42")),
+        }
+        .validate()
+        .unwrap();
+        assert_eq!(
+            Operation {
+                key_handle: Some(String::from("persistent key")),
+            }
+            .validate()
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCopyKey operation
+//!
+//! Make a copy of a key, possibly with a more restrictive policy.
+
+use super::psa_key_attributes::Attributes;
+use super::utils_usage_flags::usage_flags_contain;
+use crate::operations::psa_algorithm::Algorithm;
+use crate::requests::{ResponseStatus, Result as RequestResult};
+use log::error;
+
+/// Native object for key copy operations.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// Name of the key to copy.
+    pub source_key_name: String,
+    /// Name of the new key.
+    pub target_key_name: String,
+    /// Attributes of the new key. The key type and size must be the ones of the source key or
+    /// left unset (set to 0 for the size). The policy must be at most as permissive as the
+    /// policy of the source key.
+    pub attributes: Attributes,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the source key
+    ///
+    /// This method checks that:
+    /// * the source key policy allows copying it
+    /// * the key type and size of the target attributes are the ones of the source key, the size
+    ///   being also allowed to be 0
+    /// * the target usage flags are a subset of the source usage flags
+    /// * the target permitted algorithm is allowed by the source policy
+    pub fn validate(&self, source_attributes: Attributes) -> RequestResult<()> {
+        if !source_attributes.policy.usage_flags.copy() {
+            error!("The policy of the source key does not allow copying it.");
+            return Err(ResponseStatus::PsaErrorNotPermitted);
+        }
+        if self.attributes.key_type != source_attributes.key_type
+            || (self.attributes.bits != 0 && self.attributes.bits != source_attributes.bits)
+        {
+            error!("The type and size of the copy must be the ones of the source key.");
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if !usage_flags_contain(
+            source_attributes.policy.usage_flags,
+            self.attributes.policy.usage_flags,
+        ) {
+            error!("The usage flags of the copy are not a subset of the ones of the source key.");
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if !algorithm_policy_contains(
+            source_attributes,
+            self.attributes.policy.permitted_algorithms,
+        ) {
+            error!("The permitted algorithm of the copy is not allowed by the source key policy.");
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Check that an algorithm policy is at most as permissive as the one of the attributes
+fn algorithm_policy_contains(attributes: Attributes, permitted_algorithm: Algorithm) -> bool {
+    permitted_algorithm == Algorithm::None
+        || permitted_algorithm == attributes.policy.permitted_algorithms
+        || attributes.is_alg_permitted(permitted_algorithm)
+}
+
+/// Native object for the result of key copy operations.
+///
+/// True result of operation is returned in the response `status`.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{AsymmetricSignature, Hash, SignHash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs(usage_flags: UsageFlags, hash_alg: SignHash) -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: AsymmetricSignature::Ecdsa { hash_alg }.into(),
+            },
+        }
+    }
+
+    fn get_source() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags
+            .set_copy()
+            .set_sign_hash()
+            .set_verify_hash()
+            .set_export();
+        get_attrs(usage_flags, SignHash::Any)
+    }
+
+    fn get_operation() -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        Operation {
+            source_key_name: String::from("source"),
+            target_key_name: String::from("sign-only"),
+            attributes: get_attrs(usage_flags, Hash::Sha256.into()),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_operation().validate(get_source()).unwrap();
+
+        let mut op = get_operation();
+        op.attributes.bits = 0;
+        op.attributes.policy.permitted_algorithms = Algorithm::None;
+        op.validate(get_source()).unwrap();
+
+        let mut op = get_operation();
+        op.attributes.policy = get_source().policy;
+        op.validate(get_source()).unwrap();
+    }
+
+    #[test]
+    fn no_copy_flag() {
+        let mut source = get_source();
+        source.policy.usage_flags = get_operation().attributes.policy.usage_flags;
+        assert_eq!(
+            get_operation().validate(source).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wider_usage() {
+        let mut op = get_operation();
+        let _ = op.attributes.policy.usage_flags.set_decrypt();
+        assert_eq!(
+            op.validate(get_source()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn incompatible_algorithm() {
+        let mut source = get_source();
+        source.policy.permitted_algorithms = AsymmetricSignature::Ecdsa {
+            hash_alg: Hash::Sha384.into(),
+        }
+        .into();
+        let mut op = get_operation();
+        assert_eq!(
+            op.validate(source).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        // A wildcard policy is wider than a specific one.
+        source.policy.permitted_algorithms = AsymmetricSignature::Ecdsa {
+            hash_alg: Hash::Sha256.into(),
+        }
+        .into();
+        op.attributes.policy.permitted_algorithms = get_source().policy.permitted_algorithms;
+        assert_eq!(
+            op.validate(source).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn different_key() {
+        let mut op = get_operation();
+        op.attributes.bits = 384;
+        assert_eq!(
+            op.validate(get_source()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        let mut op = get_operation();
+        op.attributes.key_type = Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        };
+        assert_eq!(
+            op.validate(get_source()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -4,7 +4,10 @@
 //!
 //! Generate a key or a key pair.
 
+use super::key_reference::validate_new_key_name;
 use super::psa_key_attributes::Attributes;
+use super::utils_compliance_profile::ComplianceProfile;
+use super::utils_deprecated_primitives::DeprecatedPrimitivesPolicy;
 
 /// Native object for creating a cryptographic key.
 #[derive(Clone, Debug)]
@@ -17,7 +20,119 @@ pub struct Operation {
 }
 
 /// Native object for the result of creating a cryptographic key.
-///
-/// The true result is returned in the `status` field of the response.
-#[derive(Copy, Clone, Debug)]
-pub struct Result;
+#[derive(Clone, Debug, Default)]
+pub struct Result {
+    /// Handle of the key if it is volatile, to use instead of its name in the following
+    /// operations. It is not set for persistent keys.
+    pub key_handle: Option<String>,
+}
+
+impl Operation {
+    /// Validate the key template of the operation
+    ///
+    /// This method checks that:
+    /// * the key name is consistent with the key lifetime: volatile keys are created without a
+    ///   name, see `key_reference::validate_new_key_name`
+    /// * unless `policy` allows them, neither the key type and size nor the permitted algorithms
+    ///   of the key are deprecated by the PSA Crypto API
+    pub fn validate(&self, policy: DeprecatedPrimitivesPolicy) -> crate::requests::Result<()> {
+        validate_new_key_name(&self.key_name, self.attributes.lifetime)?;
+        policy.check(self)
+    }
+
+    /// Validate the operation against a compliance profile
+    ///
+    /// This method checks the key name like `validate` does and returns
+    /// `ResponseStatus::DeprecatedPrimitive` if the key attributes violate any rule of the profile.
+    pub fn validate_with_profile(
+        &self,
+        profile: &ComplianceProfile,
+    ) -> crate::requests::Result<()> {
+        validate_new_key_name(&self.key_name, self.attributes.lifetime)?;
+        profile.check_attributes(&self.attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+    use crate::requests::ResponseStatus;
+
+    fn get_op(hash: Hash) -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+
+        Operation {
+            key_name: String::from("some key"),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::EccKeyPair {
+                    curve_family: EccFamily::SecpR1,
+                },
+                bits: 256,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::Ecdsa {
+                            hash_alg: hash.into(),
+                        },
+                    ),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(Hash::Sha256)
+            .validate(DeprecatedPrimitivesPolicy::default())
+            .unwrap();
+    }
+
+    #[test]
+    fn deprecated_algorithm() {
+        assert_eq!(
+            get_op(Hash::Sha1)
+                .validate(DeprecatedPrimitivesPolicy::Reject)
+                .unwrap_err(),
+            ResponseStatus::DeprecatedPrimitive
+        );
+    }
+
+    #[test]
+    fn deprecated_key_type() {
+        let mut op = get_op(Hash::Sha256);
+        op.attributes.key_type = Type::EccKeyPair {
+            curve_family: EccFamily::SecpR2,
+        };
+        op.attributes.bits = 160;
+        assert_eq!(
+            op.validate(DeprecatedPrimitivesPolicy::Reject).unwrap_err(),
+            ResponseStatus::DeprecatedPrimitive
+        );
+    }
+
+    #[test]
+    fn validate_with_profile() {
+        let op = get_op(Hash::Sha256);
+        op.validate_with_profile(&ComplianceProfile::fips())
+            .unwrap();
+        assert_eq!(
+            op.validate_with_profile(&ComplianceProfile::cnsa())
+                .unwrap_err(),
+            ResponseStatus::DeprecatedPrimitive
+        );
+        get_op(Hash::Sha1)
+            .validate_with_profile(&ComplianceProfile::permissive("legacy"))
+            .unwrap();
+    }
+
+    #[test]
+    fn deprecated_allowed_for_legacy() {
+        get_op(Hash::Sha1)
+            .validate(DeprecatedPrimitivesPolicy::Allow)
+            .unwrap();
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaGetKeyAttributes operation
+//!
+//! Get the attributes of a key, for example to validate an operation using it before sending it.
+
+use super::psa_key_attributes::Attributes;
+use crate::requests::ProviderId;
+
+/// Native object for key attributes retrieval operations.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// `key_name` identifies the key whose attributes are returned.
+    pub key_name: String,
+}
+
+/// Native object for the result of key attributes retrieval operations.
+///
+/// The attributes can be passed to the `validate` method of the operations using the key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Result {
+    /// The ID of the provider storing the key.
+    pub provider_id: ProviderId,
+    /// The attributes of the key.
+    pub attributes: Attributes,
+}
@@ -4,7 +4,11 @@
 //!
 //! Import a key in binary format.
 
+use super::key_reference::validate_new_key_name;
 use super::psa_key_attributes::Attributes;
+use super::utils_compliance_profile::ComplianceProfile;
+use super::utils_deprecated_primitives::DeprecatedPrimitivesPolicy;
+use crate::secrecy::ExposeSecret;
 use derivative::Derivative;
 
 /// Native object for cryptographic key importing operation.
@@ -26,7 +30,147 @@ pub struct Operation {
 }
 
 /// Native object for the result of a cryptographic key import operation.
-///
-/// The true result is sent in the `status` field of the response header.
-#[derive(Copy, Clone, Debug)]
-pub struct Result;
+#[derive(Clone, Debug, Default)]
+pub struct Result {
+    /// Handle of the key if it is volatile, to use instead of its name in the following
+    /// operations. It is not set for persistent keys.
+    pub key_handle: Option<String>,
+}
+
+impl Operation {
+    /// Validate the key template of the operation
+    ///
+    /// This method checks that:
+    /// * the key name is consistent with the key lifetime: volatile keys are created without a
+    ///   name, see `key_reference::validate_new_key_name`
+    /// * unless `policy` allows them, neither the key type and size nor the permitted algorithms
+    ///   of the key are deprecated by the PSA Crypto API
+    pub fn validate(&self, policy: DeprecatedPrimitivesPolicy) -> crate::requests::Result<()> {
+        validate_new_key_name(&self.key_name, self.attributes.lifetime)?;
+        policy.check(self)
+    }
+
+    /// Validate the operation against a compliance profile
+    ///
+    /// This method checks the key name like `validate` does and returns
+    /// `ResponseStatus::DeprecatedPrimitive` if the key attributes violate any rule of the profile.
+    pub fn validate_with_profile(
+        &self,
+        profile: &ComplianceProfile,
+    ) -> crate::requests::Result<()> {
+        validate_new_key_name(&self.key_name, self.attributes.lifetime)?;
+        profile.check_attributes(&self.attributes)
+    }
+
+    /// Validate the key data against the key attributes
+    ///
+    /// This method checks that the data is in the import format of the key type and that its
+    /// size matches the `bits` attribute, if set. It does not check that the key itself is valid,
+    /// for example that an ECC point is on the curve. See `utils_key_data::validate_key_data` for
+    /// the formats checked.
+    pub fn validate_data(&self) -> crate::requests::Result<()> {
+        super::utils_key_data::validate_key_data(&self.attributes, self.data.expose_secret())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+    use crate::requests::ResponseStatus;
+
+    fn get_op(hash: Hash) -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+
+        Operation {
+            key_name: String::from("some key"),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::EccKeyPair {
+                    curve_family: EccFamily::SecpR1,
+                },
+                bits: 256,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::Ecdsa {
+                            hash_alg: hash.into(),
+                        },
+                    ),
+                },
+            },
+            data: secrecy::Secret::new(vec![0xff; 32]),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(Hash::Sha256)
+            .validate(DeprecatedPrimitivesPolicy::default())
+            .unwrap();
+    }
+
+    #[test]
+    fn deprecated_algorithm() {
+        assert_eq!(
+            get_op(Hash::Sha1)
+                .validate(DeprecatedPrimitivesPolicy::Reject)
+                .unwrap_err(),
+            ResponseStatus::DeprecatedPrimitive
+        );
+    }
+
+    #[test]
+    fn deprecated_key_type() {
+        let mut op = get_op(Hash::Sha256);
+        op.attributes.key_type = Type::EccKeyPair {
+            curve_family: EccFamily::SecpR2,
+        };
+        op.attributes.bits = 160;
+        assert_eq!(
+            op.validate(DeprecatedPrimitivesPolicy::Reject).unwrap_err(),
+            ResponseStatus::DeprecatedPrimitive
+        );
+    }
+
+    #[test]
+    fn validate_with_profile() {
+        let op = get_op(Hash::Sha256);
+        op.validate_with_profile(&ComplianceProfile::fips())
+            .unwrap();
+        assert_eq!(
+            op.validate_with_profile(&ComplianceProfile::cnsa())
+                .unwrap_err(),
+            ResponseStatus::DeprecatedPrimitive
+        );
+        get_op(Hash::Sha1)
+            .validate_with_profile(&ComplianceProfile::permissive("legacy"))
+            .unwrap();
+    }
+
+    #[test]
+    fn validate_data_success() {
+        let mut op = get_op(Hash::Sha256);
+        op.data = secrecy::Secret::new(vec![0xff; 32]);
+        op.validate_data().unwrap();
+    }
+
+    #[test]
+    fn validate_data_wrong_size() {
+        let mut op = get_op(Hash::Sha256);
+        op.data = secrecy::Secret::new(vec![0xff; 48]);
+        assert_eq!(
+            op.validate_data().unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn deprecated_allowed_for_legacy() {
+        get_op(Hash::Sha1)
+            .validate(DeprecatedPrimitivesPolicy::Allow)
+            .unwrap();
+    }
+}
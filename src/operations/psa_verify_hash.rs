@@ -3,15 +3,18 @@
 //! # PsaVerifyHash operation
 //!
 //! Verify the signature of a hash or short message using a public key.
+use super::key_reference::KeyReference;
 use super::psa_key_attributes::Attributes;
+use super::utils_signature_format;
 use crate::operations::psa_algorithm::AsymmetricSignature;
 use crate::requests::ResponseStatus;
 
 /// Native object for asymmetric verification of signatures.
 #[derive(Debug)]
 pub struct Operation {
-    /// `key_name` specifies the key to be used for verification.
-    pub key_name: String,
+    /// `key` specifies the key to be used for verification: either the name of a stored key or a
+    /// public key carried inline.
+    pub key: KeyReference,
     /// An asymmetric signature algorithm that separates the hash and sign operations, that is
     /// compatible with the type of key.
     pub alg: AsymmetricSignature,
@@ -46,11 +49,37 @@ impl Operation {
 
         Ok(())
     }
+
+    /// Validate the contents of the operation against the attributes of its inline public key
+    ///
+    /// On top of the checks done by `validate`, this method checks that the operation carries a
+    /// public key whose data is consistent with its attributes.
+    pub fn validate_inline(&self) -> crate::requests::Result<()> {
+        self.validate(self.key.public_key_attributes()?)
+    }
+
+    /// Convert a DER encoded ECDSA signature to the raw encoding expected by the service
+    ///
+    /// This opt-in conversion is meant for signatures in the `Ecdsa-Sig-Value` format, for
+    /// example taken from X.509 certificates, and should be done before `validate`. For ECDSA
+    /// algorithms the signature is always parsed as DER, `ResponseStatus::PsaErrorInvalidArgument`
+    /// is returned if it is not DER encoded. Signatures of other algorithms are left unchanged.
+    pub fn normalize_der_signature(
+        &mut self,
+        key_attributes: Attributes,
+    ) -> crate::requests::Result<()> {
+        utils_signature_format::normalize_der_signature(
+            self.alg,
+            &key_attributes,
+            &mut self.signature,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::operations::key_reference::PublicKey;
     use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
     use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
 
@@ -75,7 +104,7 @@ mod tests {
     #[test]
     fn validate_success() {
         (Operation {
-            key_name: String::from("some key"),
+            key: KeyReference::from("some key"),
             alg: AsymmetricSignature::Ecdsa {
                 hash_alg: Hash::Sha256.into(),
             },
@@ -92,7 +121,7 @@ mod tests {
         attrs.policy.usage_flags = UsageFlags::default();
         assert_eq!(
             (Operation {
-                key_name: String::from("some key"),
+                key: KeyReference::from("some key"),
                 alg: AsymmetricSignature::Ecdsa {
                     hash_alg: Hash::Sha256.into(),
                 },
@@ -109,7 +138,7 @@ mod tests {
     fn wrong_algorithm() {
         assert_eq!(
             (Operation {
-                key_name: String::from("some key"),
+                key: KeyReference::from("some key"),
                 alg: AsymmetricSignature::Ecdsa {
                     hash_alg: Hash::Sha224.into(),
                 },
@@ -126,7 +155,7 @@ mod tests {
     fn wrong_scheme() {
         assert_eq!(
             (Operation {
-                key_name: String::from("some key"),
+                key: KeyReference::from("some key"),
                 alg: AsymmetricSignature::RsaPss {
                     hash_alg: Hash::Sha224.into(),
                 },
@@ -143,7 +172,7 @@ mod tests {
     fn invalid_hash() {
         assert_eq!(
             (Operation {
-                key_name: String::from("some key"),
+                key: KeyReference::from("some key"),
                 alg: AsymmetricSignature::Ecdsa {
                     hash_alg: Hash::Sha256.into(),
                 },
@@ -155,4 +184,41 @@ mod tests {
             ResponseStatus::PsaErrorInvalidArgument
         );
     }
+
+    #[test]
+    fn validate_inline() {
+        let mut attributes = get_attrs();
+        attributes.key_type = Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        };
+        let mut data = vec![0x04];
+        data.extend(vec![0x42; 64]);
+        let mut op = Operation {
+            key: PublicKey { attributes, data }.into(),
+            alg: AsymmetricSignature::Ecdsa {
+                hash_alg: Hash::Sha256.into(),
+            },
+            hash: vec![0xff; 32].into(),
+            signature: vec![0xa5; 64].into(),
+        };
+        op.validate_inline().unwrap();
+
+        // The operation must carry a public key
+        op.key = KeyReference::from("some key");
+        assert_eq!(
+            op.validate_inline().unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        // The inline key must be a public key
+        op.key = PublicKey {
+            attributes: get_attrs(),
+            data: vec![0x42; 32],
+        }
+        .into();
+        assert_eq!(
+            op.validate_inline().unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
 }
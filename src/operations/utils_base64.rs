@@ -0,0 +1,148 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Base64 helpers
+//!
+//! Encoding and decoding of the RFC 4648 base64 and base64url formats.
+
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+
+/// Alphabet of RFC 4648 base64
+pub(crate) const BASE64_STANDARD: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+/// Alphabet of RFC 4648 base64url
+pub(crate) const BASE64_URL: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode data in base64 with the given alphabet, with or without padding
+pub(crate) fn base64_encode(data: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut encoded = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let block = chunk.iter().enumerate().fold(0_u32, |block, (i, &byte)| {
+            block | u32::from(byte) << (16 - 8 * i)
+        });
+        for i in 0..=chunk.len() {
+            encoded.push(char::from(
+                alphabet[(block >> (18 - 6 * i) & 0x3f) as usize],
+            ));
+        }
+        if pad {
+            for _ in chunk.len()..3 {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decode base64 data with the given alphabet, with padding if `pad` is set or without it
+/// otherwise
+///
+/// Only the canonical encoding of the data is accepted: the padding must match the length of the
+/// data and the unused bits of the last character must be zero.
+pub(crate) fn base64_decode(encoded: &str, alphabet: &[u8; 64], pad: bool) -> Result<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    let unpadded = encoded.len() - encoded.iter().rev().take_while(|&&c| c == b'=').count();
+    let padding = encoded.len() - unpadded;
+    let encoded = &encoded[..unpadded];
+    if encoded.len() % 4 == 1 {
+        return Err(invalid("Invalid base64 length."));
+    }
+    let expected_padding = if pad { (4 - encoded.len() % 4) % 4 } else { 0 };
+    if padding != expected_padding {
+        return Err(invalid("Invalid base64 padding."));
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() * 3 / 4);
+    for chunk in encoded.chunks(4) {
+        let mut block = 0_u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let value = alphabet
+                .iter()
+                .position(|a| a == c)
+                .ok_or_else(|| invalid("Invalid base64 character."))?;
+            block |= (value as u32) << (18 - 6 * i);
+        }
+        let len = chunk.len() - 1;
+        if block & (0x00ff_ffff >> (8 * len)) != 0 {
+            return Err(invalid("Non-zero trailing bits in base64 data."));
+        }
+        for i in 0..len {
+            decoded.push((block >> (16 - 8 * i)) as u8);
+        }
+    }
+    Ok(decoded)
+}
+
+fn invalid(reason: &str) -> ResponseStatus {
+    error!("{}", reason);
+    ResponseStatus::PsaErrorInvalidArgument
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64() {
+        let vectors: Vec<(&[u8], &str)> = vec![
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+        ];
+        for (data, encoded) in vectors {
+            assert_eq!(base64_encode(data, BASE64_STANDARD, true), encoded);
+            assert_eq!(base64_decode(encoded, BASE64_STANDARD, true).unwrap(), data);
+            let unpadded = base64_encode(data, BASE64_URL, false);
+            assert_eq!(unpadded, encoded.trim_end_matches('='));
+            assert_eq!(base64_decode(&unpadded, BASE64_URL, false).unwrap(), data);
+        }
+        assert_eq!(base64_encode(&[0xfb, 0xff], BASE64_URL, false), "-_8");
+        assert_eq!(
+            base64_decode("-_8", BASE64_URL, false).unwrap(),
+            [0xfb, 0xff]
+        );
+    }
+
+    #[test]
+    fn non_canonical_trailing_bits() {
+        for encoded in ["Zh==", "Zm9=", "Zm9vYh==", "Zm9vYmF="] {
+            assert_eq!(
+                base64_decode(encoded, BASE64_STANDARD, true).unwrap_err(),
+                ResponseStatus::PsaErrorInvalidArgument,
+                "{}",
+                encoded
+            );
+        }
+        assert_eq!(
+            base64_decode("-_9", BASE64_URL, false).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_padding() {
+        for encoded in [
+            "Zg", "Zg=", "Zg===", "Zm8", "Zm8==", "Zm9v=", "Zm9v====", "=", "Z===",
+        ] {
+            assert_eq!(
+                base64_decode(encoded, BASE64_STANDARD, true).unwrap_err(),
+                ResponseStatus::PsaErrorInvalidArgument,
+                "{}",
+                encoded
+            );
+        }
+        for encoded in ["Zg==", "Zm8=", "Zg=", "Zm9v="] {
+            assert_eq!(
+                base64_decode(encoded, BASE64_URL, false).unwrap_err(),
+                ResponseStatus::PsaErrorInvalidArgument,
+                "{}",
+                encoded
+            );
+        }
+        assert!(base64_decode("", BASE64_URL, false).unwrap().is_empty());
+    }
+}
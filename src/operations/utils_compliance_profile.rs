@@ -0,0 +1,792 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//!
+//! # Configurable cryptographic compliance profiles
+//!
+//! A `ComplianceProfile` is a named list of rules restricting the algorithms, key types and key
+//! sizes that can be used. Profiles implement `Serialize` and `Deserialize` so they can be loaded
+//! from any format supported by `serde`, for example TOML or JSON:
+//!
+//! ```toml
+//! name = "example"
+//!
+//! [[rules]]
+//! id = "no-sha1"
+//! reason = "SHA-1 is not collision resistant"
+//! matcher = { kind = "deny_hash", hash = "Sha1" }
+//!
+//! [[rules]]
+//! id = "rsa-min-3072"
+//! reason = "RSA keys must be at least 3072 bits"
+//! matcher = { kind = "min_key_bits", key_type = "RsaPublicKey", min_bits = 3072 }
+//!
+//! [[rules]]
+//! id = "aes-256"
+//! reason = "Only AES-256 is allowed for symmetric keys"
+//! matcher = { kind = "allow_key_types", keys = [{ key_type = "Aes", bits = 256 }] }
+//! ```
+//!
+//! Matchers starting with `deny` or `min` name the primitives violating a rule, while matchers
+//! starting with `allow` name the only primitives satisfying it.
+//!
+//! Evaluating a profile returns the list of rules that were violated, as `Finding`s. The profile
+//! returned by `ComplianceProfile::default()` contains the primitives deprecated by the PSA Crypto
+//! API, `ComplianceProfile::fips()` and `ComplianceProfile::cnsa()` are built-in profiles for
+//! common regulatory baselines.
+//!
+//! Key creation operations are checked against a profile with their `validate_with_profile`
+//! method.
+
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+use psa_crypto::types::algorithm::*;
+use psa_crypto::types::key::{Attributes, DhFamily, EccFamily, Type};
+use serde::{Deserialize, Serialize};
+
+/// A named set of compliance rules
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceProfile {
+    /// Name of the profile
+    pub name: String,
+    /// Rules of the profile, all of them are evaluated
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+/// A single compliance rule
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    /// Identifier of the rule, reported in findings
+    pub id: String,
+    /// Human-readable reason for the rule, reported in findings
+    pub reason: String,
+    /// Primitives violating the rule
+    pub matcher: Matcher,
+}
+
+/// Description of the primitives violating a rule
+///
+/// Asymmetric key types should be given as public key types: the rules also apply to the
+/// corresponding key pairs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Matcher {
+    /// Algorithms using the hash, directly or as part of a composite algorithm
+    DenyHash {
+        /// Denied hash
+        hash: Hash,
+    },
+    /// Algorithms using any hash which is not part of the list
+    AllowHashes {
+        /// Allowed hashes
+        hashes: Vec<Hash>,
+    },
+    /// Exactly the algorithm given
+    DenyAlgorithm {
+        /// Denied algorithm
+        algorithm: Algorithm,
+    },
+    /// Keys of this type, of any size if `bits` is not set
+    DenyKeyType {
+        /// Denied key type
+        key_type: Type,
+        /// Denied key size
+        #[serde(default)]
+        bits: Option<usize>,
+    },
+    /// Keys of this type smaller than the minimum size
+    MinKeyBits {
+        /// Key type on which the rule applies
+        key_type: Type,
+        /// Minimum key size, in bits
+        min_bits: usize,
+    },
+    /// Keys not matching any entry of the list
+    AllowKeyTypes {
+        /// Allowed key types and sizes
+        keys: Vec<AllowedKey>,
+    },
+    /// Algorithms which are not part of the list
+    ///
+    /// `Algorithm::None`, permitting no algorithm, never violates the rule.
+    AllowAlgorithms {
+        /// Allowed algorithms
+        algorithms: Vec<Algorithm>,
+    },
+}
+
+/// Key type and size allowed by a `Matcher::AllowKeyTypes` rule
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowedKey {
+    /// Allowed key type
+    pub key_type: Type,
+    /// Allowed key size, any size is allowed if not set
+    #[serde(default)]
+    pub bits: Option<usize>,
+}
+
+impl AllowedKey {
+    /// Allow keys of this type and of any size
+    pub fn any_size(key_type: Type) -> Self {
+        AllowedKey {
+            key_type,
+            bits: None,
+        }
+    }
+
+    /// Allow keys of this type and of one of the sizes given
+    pub fn sizes(key_type: Type, sizes: &[usize]) -> Vec<Self> {
+        sizes
+            .iter()
+            .map(|&bits| AllowedKey {
+                key_type,
+                bits: Some(bits),
+            })
+            .collect()
+    }
+}
+
+/// Violation of a compliance rule
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    /// Identifier of the violated rule
+    pub rule_id: String,
+    /// Reason of the violated rule
+    pub reason: String,
+}
+
+impl ComplianceProfile {
+    /// Create a profile without any rules, allowing all primitives
+    pub fn permissive(name: &str) -> Self {
+        ComplianceProfile {
+            name: name.to_string(),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Profile restricting primitives to the ones approved by FIPS 140-3
+    ///
+    /// This profile allows the SHA-2 and SHA-3 hashes, AES keys, RSA keys of at least 2048 bits,
+    /// the NIST P-224, P-256, P-384 and P-521 curves, the RFC 7919 Diffie-Hellman groups and HMAC,
+    /// derivation and raw data keys. All other hashes and key types are denied.
+    pub fn fips() -> Self {
+        let mut keys = vec![
+            AllowedKey::any_size(Type::RawData),
+            AllowedKey::any_size(Type::Hmac),
+            AllowedKey::any_size(Type::Derive),
+            AllowedKey::any_size(Type::RsaPublicKey),
+            AllowedKey::any_size(Type::DhPublicKey {
+                group_family: DhFamily::Rfc7919,
+            }),
+        ];
+        keys.extend(AllowedKey::sizes(Type::Aes, &[128, 192, 256]));
+        keys.extend(AllowedKey::sizes(
+            Type::EccPublicKey {
+                curve_family: EccFamily::SecpR1,
+            },
+            &[224, 256, 384, 521],
+        ));
+        ComplianceProfile {
+            name: String::from("FIPS 140-3"),
+            rules: vec![
+                rule(
+                    "fips-hash",
+                    "Only the SHA-2 and SHA-3 hashes are approved by FIPS 140-3",
+                    Matcher::AllowHashes {
+                        hashes: vec![
+                            Hash::Sha224,
+                            Hash::Sha256,
+                            Hash::Sha384,
+                            Hash::Sha512,
+                            Hash::Sha512_224,
+                            Hash::Sha512_256,
+                            Hash::Sha3_224,
+                            Hash::Sha3_256,
+                            Hash::Sha3_384,
+                            Hash::Sha3_512,
+                        ],
+                    },
+                ),
+                rule(
+                    "fips-key-type",
+                    "The key type or size is not approved by FIPS 140-3",
+                    Matcher::AllowKeyTypes { keys },
+                ),
+                rule(
+                    "fips-rsa-min-2048",
+                    "RSA keys must be at least 2048 bits",
+                    Matcher::MinKeyBits {
+                        key_type: Type::RsaPublicKey,
+                        min_bits: 2048,
+                    },
+                ),
+            ],
+        }
+    }
+
+    /// Profile restricting primitives to the ones approved by the NSA Commercial National
+    /// Security Algorithm Suite 2.0
+    ///
+    /// This profile only allows SHA-384 and SHA-512, AES-256 keys, RSA keys of at least 3072 bits,
+    /// the P-384 curve and HMAC and derivation keys. The allowed algorithms are AES-GCM, ECDH,
+    /// ECDSA, RSA PSS, PKCS#1 v1.5 signatures and OAEP, HMAC and HKDF, with these hashes only.
+    pub fn cnsa() -> Self {
+        let mut algorithms = vec![
+            Algorithm::Aead(Aead::AeadWithDefaultLengthTag(
+                AeadWithDefaultLengthTag::Gcm,
+            )),
+            Algorithm::KeyAgreement(KeyAgreement::Raw(RawKeyAgreement::Ecdh)),
+        ];
+        for &hash in [Hash::Sha384, Hash::Sha512].iter() {
+            algorithms.extend(vec![
+                Algorithm::Hash(hash),
+                Algorithm::Mac(Mac::FullLength(FullLengthMac::Hmac { hash_alg: hash })),
+                Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: hash.into(),
+                }),
+                Algorithm::AsymmetricSignature(AsymmetricSignature::DeterministicEcdsa {
+                    hash_alg: hash.into(),
+                }),
+                Algorithm::AsymmetricSignature(AsymmetricSignature::RsaPss {
+                    hash_alg: hash.into(),
+                }),
+                Algorithm::AsymmetricSignature(AsymmetricSignature::RsaPkcs1v15Sign {
+                    hash_alg: hash.into(),
+                }),
+                Algorithm::AsymmetricEncryption(AsymmetricEncryption::RsaOaep { hash_alg: hash }),
+                Algorithm::KeyDerivation(KeyDerivation::Hkdf { hash_alg: hash }),
+                Algorithm::KeyAgreement(KeyAgreement::WithKeyDerivation {
+                    ka_alg: RawKeyAgreement::Ecdh,
+                    kdf_alg: KeyDerivation::Hkdf { hash_alg: hash },
+                }),
+            ]);
+        }
+        let mut keys = vec![
+            AllowedKey::any_size(Type::Hmac),
+            AllowedKey::any_size(Type::Derive),
+            AllowedKey::any_size(Type::RsaPublicKey),
+        ];
+        keys.extend(AllowedKey::sizes(Type::Aes, &[256]));
+        keys.extend(AllowedKey::sizes(
+            Type::EccPublicKey {
+                curve_family: EccFamily::SecpR1,
+            },
+            &[384],
+        ));
+        ComplianceProfile {
+            name: String::from("CNSA 2.0"),
+            rules: vec![
+                rule(
+                    "cnsa-algorithm",
+                    "The algorithm is not approved by CNSA 2.0",
+                    Matcher::AllowAlgorithms { algorithms },
+                ),
+                rule(
+                    "cnsa-key-type",
+                    "Only AES-256, P-384, RSA, HMAC and derivation keys are approved",
+                    Matcher::AllowKeyTypes { keys },
+                ),
+                rule(
+                    "cnsa-rsa-min-3072",
+                    "RSA keys must be at least 3072 bits",
+                    Matcher::MinKeyBits {
+                        key_type: Type::RsaPublicKey,
+                        min_bits: 3072,
+                    },
+                ),
+            ],
+        }
+    }
+
+    /// Evaluate an algorithm against the profile
+    pub fn evaluate_algorithm(&self, alg: Algorithm) -> Vec<Finding> {
+        self.findings(|matcher| matcher.matches_algorithm(alg))
+    }
+
+    /// Evaluate a key type and size against the profile
+    pub fn evaluate_key(&self, key_type: Type, bits: usize) -> Vec<Finding> {
+        self.findings(|matcher| matcher.matches_key(key_type, bits))
+    }
+
+    /// Evaluate the key type, size and permitted algorithms of key attributes against the profile
+    pub fn evaluate_attributes(&self, attributes: &Attributes) -> Vec<Finding> {
+        let alg = attributes.policy.permitted_algorithms;
+        self.findings(|matcher| {
+            matcher.matches_key(attributes.key_type, attributes.bits)
+                || matcher.matches_algorithm(alg)
+        })
+    }
+
+    /// Check that key attributes do not violate any rule of the profile
+    ///
+    /// Returns `ResponseStatus::DeprecatedPrimitive` otherwise.
+    pub fn check_attributes(&self, attributes: &Attributes) -> Result<()> {
+        let findings = self.evaluate_attributes(attributes);
+        if findings.is_empty() {
+            return Ok(());
+        }
+        for finding in findings {
+            error!(
+                "Key attributes violate rule {} of the {} compliance profile: {}",
+                finding.rule_id, self.name, finding.reason
+            );
+        }
+        Err(ResponseStatus::DeprecatedPrimitive)
+    }
+
+    fn findings(&self, violates: impl Fn(&Matcher) -> bool) -> Vec<Finding> {
+        self.rules
+            .iter()
+            .filter(|rule| violates(&rule.matcher))
+            .map(|rule| Finding {
+                rule_id: rule.id.clone(),
+                reason: rule.reason.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Default for ComplianceProfile {
+    /// Profile denying the primitives deprecated by PSA Crypto API 1.0.0
+    fn default() -> Self {
+        super::utils_deprecated_primitives::deprecated_primitives_profile()
+    }
+}
+
+fn rule(id: &str, reason: &str, matcher: Matcher) -> Rule {
+    Rule {
+        id: id.to_string(),
+        reason: reason.to_string(),
+        matcher,
+    }
+}
+
+impl Matcher {
+    pub(crate) fn matches_algorithm(&self, alg: Algorithm) -> bool {
+        match self {
+            Matcher::DenyHash { hash } => algorithm_hashes(alg).contains(hash),
+            Matcher::AllowHashes { hashes } => algorithm_hashes(alg)
+                .iter()
+                .any(|hash| !hashes.contains(hash)),
+            Matcher::DenyAlgorithm { algorithm } => *algorithm == alg,
+            Matcher::AllowAlgorithms { algorithms } => {
+                alg != Algorithm::None && !algorithms.contains(&alg)
+            }
+            Matcher::DenyKeyType { .. }
+            | Matcher::MinKeyBits { .. }
+            | Matcher::AllowKeyTypes { .. } => false,
+        }
+    }
+
+    pub(crate) fn matches_key(&self, key_type: Type, bits: usize) -> bool {
+        let key_type = public_key_type(key_type);
+        match self {
+            Matcher::DenyKeyType {
+                key_type: denied_type,
+                bits: denied_bits,
+            } => {
+                public_key_type(*denied_type) == key_type
+                    && denied_bits.map_or(true, |denied_bits| denied_bits == bits)
+            }
+            Matcher::MinKeyBits {
+                key_type: min_type,
+                min_bits,
+            } => public_key_type(*min_type) == key_type && bits < *min_bits,
+            Matcher::AllowKeyTypes { keys } => !keys.iter().any(|allowed| {
+                public_key_type(allowed.key_type) == key_type
+                    && allowed
+                        .bits
+                        .map_or(true, |allowed_bits| allowed_bits == bits)
+            }),
+            Matcher::DenyHash { .. }
+            | Matcher::AllowHashes { .. }
+            | Matcher::DenyAlgorithm { .. }
+            | Matcher::AllowAlgorithms { .. } => false,
+        }
+    }
+}
+
+/// Return the public key type corresponding to a key pair type, or the type itself otherwise
+pub(crate) fn public_key_type(key_type: Type) -> Type {
+    match key_type {
+        Type::RsaKeyPair => Type::RsaPublicKey,
+        Type::EccKeyPair { curve_family } => Type::EccPublicKey { curve_family },
+        Type::DhKeyPair { group_family } => Type::DhPublicKey { group_family },
+        key_type => key_type,
+    }
+}
+
+/// Return the hashes used by an algorithm, directly or as part of a composite algorithm
+fn algorithm_hashes(alg: Algorithm) -> Vec<Hash> {
+    fn sign_hash(hash_alg: SignHash) -> Vec<Hash> {
+        match hash_alg {
+            SignHash::Specific(hash) => vec![hash],
+            SignHash::Any => vec![],
+        }
+    }
+    fn full_length_mac(mac: FullLengthMac) -> Vec<Hash> {
+        match mac {
+            FullLengthMac::Hmac { hash_alg } => vec![hash_alg],
+            _ => vec![],
+        }
+    }
+    fn key_derivation(kdf: KeyDerivation) -> Vec<Hash> {
+        match kdf {
+            KeyDerivation::Hkdf { hash_alg }
+            | KeyDerivation::Tls12Prf { hash_alg }
+            | KeyDerivation::Tls12PskToMs { hash_alg } => vec![hash_alg],
+        }
+    }
+    match alg {
+        Algorithm::Hash(hash) => vec![hash],
+        Algorithm::Mac(Mac::FullLength(mac))
+        | Algorithm::Mac(Mac::Truncated { mac_alg: mac, .. }) => full_length_mac(mac),
+        Algorithm::AsymmetricSignature(asymm_sig) => match asymm_sig {
+            AsymmetricSignature::RsaPkcs1v15Sign { hash_alg }
+            | AsymmetricSignature::RsaPss { hash_alg }
+            | AsymmetricSignature::Ecdsa { hash_alg }
+            | AsymmetricSignature::DeterministicEcdsa { hash_alg } => sign_hash(hash_alg),
+            _ => vec![],
+        },
+        Algorithm::AsymmetricEncryption(AsymmetricEncryption::RsaOaep { hash_alg }) => {
+            vec![hash_alg]
+        }
+        Algorithm::KeyAgreement(KeyAgreement::WithKeyDerivation { kdf_alg, .. }) => {
+            key_derivation(kdf_alg)
+        }
+        Algorithm::KeyDerivation(kdf) => key_derivation(kdf),
+        Algorithm::None
+        | Algorithm::Cipher(_)
+        | Algorithm::Aead(_)
+        | Algorithm::AsymmetricEncryption(_)
+        | Algorithm::KeyAgreement(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use psa_crypto::types::key::{Lifetime, Policy, UsageFlags};
+
+    fn get_attrs(key_type: Type, bits: usize, permitted_algorithms: Algorithm) -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type,
+            bits,
+            policy: Policy {
+                usage_flags: UsageFlags::default(),
+                permitted_algorithms,
+            },
+        }
+    }
+
+    fn rule_ids(findings: Vec<Finding>) -> Vec<String> {
+        findings
+            .into_iter()
+            .map(|finding| finding.rule_id)
+            .collect()
+    }
+
+    const EXAMPLE_TOML: &str = r#"
+name = "example"
+
+[[rules]]
+id = "sha-384"
+reason = "Only SHA-384 is allowed"
+matcher = { kind = "allow_hashes", hashes = ["Sha384"] }
+
+[[rules]]
+id = "rsa-min-3072"
+reason = "RSA keys must be at least 3072 bits"
+matcher = { kind = "min_key_bits", key_type = "RsaPublicKey", min_bits = 3072 }
+
+[[rules]]
+id = "keys"
+reason = "Only RSA and P-384 keys are allowed"
+matcher = { kind = "allow_key_types", keys = [{ key_type = "RsaPublicKey" }, { key_type = { EccPublicKey = { curve_family = "SecpR1" } }, bits = 384 }] }
+
+[[rules]]
+id = "algorithms"
+reason = "Only ECDSA with SHA-384 is allowed"
+matcher = { kind = "allow_algorithms", algorithms = [{ AsymmetricSignature = { Ecdsa = { hash_alg = { Specific = "Sha384" } } } }] }
+
+[[rules]]
+id = "no-pkcs1v15-crypt"
+reason = "RSA PKCS#1 v1.5 encryption is not allowed"
+matcher = { kind = "deny_algorithm", algorithm = { AsymmetricEncryption = "RsaPkcs1v15Crypt" } }
+"#;
+
+    fn p384_ecdsa() -> Algorithm {
+        Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+            hash_alg: Hash::Sha384.into(),
+        })
+    }
+
+    fn secp_r1() -> Type {
+        Type::EccKeyPair {
+            curve_family: EccFamily::SecpR1,
+        }
+    }
+
+    #[test]
+    fn load_toml() {
+        let profile: ComplianceProfile = toml::from_str(EXAMPLE_TOML).unwrap();
+        assert_eq!(profile.name, "example");
+        assert_eq!(
+            profile.rules[2].matcher,
+            Matcher::AllowKeyTypes {
+                keys: vec![
+                    AllowedKey::any_size(Type::RsaPublicKey),
+                    AllowedKey {
+                        key_type: Type::EccPublicKey {
+                            curve_family: EccFamily::SecpR1,
+                        },
+                        bits: Some(384),
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            profile.rules[3].matcher,
+            Matcher::AllowAlgorithms {
+                algorithms: vec![p384_ecdsa()],
+            }
+        );
+
+        assert!(profile.evaluate_algorithm(p384_ecdsa()).is_empty());
+        assert!(profile.evaluate_algorithm(Algorithm::None).is_empty());
+        assert_eq!(
+            rule_ids(profile.evaluate_algorithm(Algorithm::Hash(Hash::Sha256))),
+            vec!["sha-384", "algorithms"]
+        );
+        assert_eq!(
+            rule_ids(profile.evaluate_algorithm(Algorithm::AsymmetricEncryption(
+                AsymmetricEncryption::RsaPkcs1v15Crypt
+            ))),
+            vec!["algorithms", "no-pkcs1v15-crypt"]
+        );
+        assert_eq!(
+            rule_ids(profile.evaluate_key(Type::RsaKeyPair, 2048)),
+            vec!["rsa-min-3072"]
+        );
+        assert!(profile.evaluate_key(Type::RsaKeyPair, 4096).is_empty());
+        assert!(profile.evaluate_key(secp_r1(), 384).is_empty());
+        assert_eq!(rule_ids(profile.evaluate_key(secp_r1(), 256)), vec!["keys"]);
+        assert_eq!(rule_ids(profile.evaluate_key(Type::Aes, 256)), vec!["keys"]);
+    }
+
+    #[test]
+    fn fips_profile() {
+        let profile = ComplianceProfile::fips();
+        assert_eq!(
+            rule_ids(profile.evaluate_algorithm(Algorithm::AsymmetricSignature(
+                AsymmetricSignature::RsaPss {
+                    hash_alg: Hash::Sha1.into(),
+                }
+            ))),
+            vec!["fips-hash"]
+        );
+        for hash in [Hash::Md5, Hash::Ripemd160, Hash::Sha1] {
+            assert_eq!(
+                rule_ids(profile.evaluate_algorithm(Algorithm::Hash(hash))),
+                vec!["fips-hash"]
+            );
+        }
+        assert!(profile
+            .evaluate_algorithm(Algorithm::Hash(Hash::Sha3_256))
+            .is_empty());
+
+        for (key_type, bits) in [
+            (Type::Chacha20, 256),
+            (Type::Des, 192),
+            (secp_r1(), 192),
+            (
+                Type::EccKeyPair {
+                    curve_family: EccFamily::SecpK1,
+                },
+                256,
+            ),
+            (
+                Type::EccKeyPair {
+                    curve_family: EccFamily::BrainpoolPR1,
+                },
+                256,
+            ),
+        ] {
+            assert_eq!(
+                rule_ids(profile.evaluate_key(key_type, bits)),
+                vec!["fips-key-type"],
+                "key: {:?} {}",
+                key_type,
+                bits
+            );
+        }
+        assert_eq!(
+            rule_ids(profile.evaluate_key(Type::RsaKeyPair, 1024)),
+            vec!["fips-rsa-min-2048"]
+        );
+        assert!(profile.evaluate_key(Type::RsaKeyPair, 2048).is_empty());
+        assert!(profile.evaluate_key(Type::Aes, 128).is_empty());
+        for bits in [224, 256, 384, 521] {
+            assert!(profile.evaluate_key(secp_r1(), bits).is_empty());
+        }
+    }
+
+    #[test]
+    fn cnsa_profile() {
+        let profile = ComplianceProfile::cnsa();
+        for (key_type, bits) in [
+            (secp_r1(), 192),
+            (secp_r1(), 224),
+            (secp_r1(), 256),
+            (secp_r1(), 521),
+            (
+                Type::EccKeyPair {
+                    curve_family: EccFamily::BrainpoolPR1,
+                },
+                384,
+            ),
+            (
+                Type::EccKeyPair {
+                    curve_family: EccFamily::SecpK1,
+                },
+                256,
+            ),
+            (Type::Aes, 128),
+            (Type::Aes, 192),
+            (Type::Chacha20, 256),
+        ] {
+            assert_eq!(
+                rule_ids(profile.evaluate_key(key_type, bits)),
+                vec!["cnsa-key-type"],
+                "key: {:?} {}",
+                key_type,
+                bits
+            );
+        }
+        assert_eq!(
+            rule_ids(profile.evaluate_key(Type::RsaKeyPair, 2048)),
+            vec!["cnsa-rsa-min-3072"]
+        );
+        assert!(profile.evaluate_key(Type::RsaKeyPair, 3072).is_empty());
+        assert!(profile.evaluate_key(secp_r1(), 384).is_empty());
+        assert!(profile.evaluate_key(Type::Aes, 256).is_empty());
+
+        for hash in [Hash::Sha384, Hash::Sha512] {
+            assert!(profile.evaluate_algorithm(Algorithm::Hash(hash)).is_empty());
+        }
+        assert!(profile.evaluate_algorithm(p384_ecdsa()).is_empty());
+        let denied = vec![
+            Algorithm::Hash(Hash::Sha256),
+            Algorithm::Hash(Hash::Sha3_384),
+            Algorithm::Mac(Mac::Truncated {
+                mac_alg: FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                },
+                mac_length: 16,
+            }),
+            Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                hash_alg: Hash::Sha256.into(),
+            }),
+            Algorithm::AsymmetricSignature(AsymmetricSignature::EcdsaAny),
+            Algorithm::AsymmetricEncryption(AsymmetricEncryption::RsaPkcs1v15Crypt),
+            Algorithm::KeyAgreement(KeyAgreement::Raw(RawKeyAgreement::Ffdh)),
+            Algorithm::Aead(Aead::AeadWithDefaultLengthTag(
+                AeadWithDefaultLengthTag::Chacha20Poly1305,
+            )),
+        ];
+        for alg in denied {
+            assert_eq!(
+                rule_ids(profile.evaluate_algorithm(alg)),
+                vec!["cnsa-algorithm"],
+                "algorithm: {:?}",
+                alg
+            );
+        }
+    }
+
+    #[test]
+    fn composite_algorithm_hashes() {
+        let profile = ComplianceProfile {
+            name: String::from("sha-384"),
+            rules: vec![rule(
+                "sha-384",
+                "Only SHA-384 is allowed",
+                Matcher::AllowHashes {
+                    hashes: vec![Hash::Sha384],
+                },
+            )],
+        };
+        let algs = vec![
+            Algorithm::Mac(Mac::Truncated {
+                mac_alg: FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                },
+                mac_length: 16,
+            }),
+            Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                hash_alg: Hash::Sha256.into(),
+            }),
+            Algorithm::AsymmetricEncryption(AsymmetricEncryption::RsaOaep {
+                hash_alg: Hash::Sha256,
+            }),
+            Algorithm::KeyAgreement(KeyAgreement::WithKeyDerivation {
+                ka_alg: RawKeyAgreement::Ecdh,
+                kdf_alg: KeyDerivation::Hkdf {
+                    hash_alg: Hash::Sha256,
+                },
+            }),
+        ];
+        for alg in algs {
+            assert_eq!(
+                rule_ids(profile.evaluate_algorithm(alg)),
+                vec!["sha-384"],
+                "algorithm: {:?}",
+                alg
+            );
+        }
+        assert!(profile
+            .evaluate_algorithm(Algorithm::AsymmetricSignature(
+                AsymmetricSignature::EcdsaAny
+            ))
+            .is_empty());
+    }
+
+    #[test]
+    fn evaluate_attributes() {
+        let profile = ComplianceProfile::cnsa();
+        let attrs = get_attrs(
+            Type::RsaKeyPair,
+            2048,
+            Algorithm::AsymmetricSignature(AsymmetricSignature::RsaPss {
+                hash_alg: Hash::Sha256.into(),
+            }),
+        );
+        assert_eq!(
+            rule_ids(profile.evaluate_attributes(&attrs)),
+            vec!["cnsa-algorithm", "cnsa-rsa-min-3072"]
+        );
+        assert_eq!(
+            profile.check_attributes(&attrs),
+            Err(ResponseStatus::DeprecatedPrimitive)
+        );
+
+        let attrs = get_attrs(
+            Type::RsaKeyPair,
+            3072,
+            Algorithm::AsymmetricSignature(AsymmetricSignature::RsaPss {
+                hash_alg: Hash::Sha384.into(),
+            }),
+        );
+        assert_eq!(profile.check_attributes(&attrs), Ok(()));
+    }
+
+    #[test]
+    fn default_profile_findings() {
+        let profile = ComplianceProfile::default();
+        let findings =
+            profile.evaluate_attributes(&get_attrs(Type::Des, 56, Algorithm::Hash(Hash::Sha1)));
+        assert_eq!(findings.len(), 2);
+        assert!(findings.iter().all(|finding| !finding.reason.is_empty()));
+    }
+}
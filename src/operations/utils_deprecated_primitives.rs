@@ -4,18 +4,60 @@
 //! # Utilities for checking deprecated primitives
 //! # by PSA Crypto API 1.0.0
 
+use super::utils_compliance_profile::{ComplianceProfile, Matcher, Rule};
 use crate::requests::{ResponseStatus, Result};
 use psa_crypto::types::algorithm::*;
 use psa_crypto::types::key;
 use psa_crypto::types::key::Type;
 
+const DEPRECATED_HASHES: [Hash; 4] = [Hash::Md2, Hash::Md4, Hash::Md5, Hash::Sha1];
+
+/// List of deprecated keys (type, size), if size is None, then the key type is deprecated
+///
+/// Asymmetric keys are listed by their public key type, key pairs of the same family are also
+/// deprecated.
+const DEPRECATED_KEYS: [(Type, Option<usize>); 7] = [
+    (Type::Des, None),
+    (Type::Arc4, None),
+    (
+        Type::EccPublicKey {
+            curve_family: key::EccFamily::BrainpoolPR1,
+        },
+        Some(160),
+    ),
+    (
+        Type::EccPublicKey {
+            curve_family: key::EccFamily::SectR2,
+        },
+        None,
+    ),
+    (
+        Type::EccPublicKey {
+            curve_family: key::EccFamily::SectR1,
+        },
+        Some(163),
+    ),
+    (
+        Type::EccPublicKey {
+            curve_family: key::EccFamily::SectK1,
+        },
+        Some(163),
+    ),
+    (
+        Type::EccPublicKey {
+            curve_family: key::EccFamily::SecpR2,
+        },
+        None,
+    ),
+];
+
 fn get_deprecated_hashes() -> Vec<Hash> {
-    vec![Hash::Md2, Hash::Md4, Hash::Md5, Hash::Sha1]
+    DEPRECATED_HASHES.to_vec()
 }
 
 /// Check if hash is deprecated by PSA Crypto API
 pub fn is_hash_deprecated(hash: Hash) -> bool {
-    get_deprecated_hashes().contains(&hash)
+    is_algorithm_deprecated(hash.into())
 }
 
 /// Check if signhash is deprecated by PSA Crypto API
@@ -28,127 +70,128 @@ pub fn is_signhash_deprecated(signhash: SignHash) -> bool {
 
 /// Check if any part of the mac is deprecated by PSA Crypto API
 pub fn is_mac_deprecated(mac: Mac) -> bool {
-    pub fn is_full_length_mac_deprecated(full_length_mac: FullLengthMac) -> bool {
-        match full_length_mac {
-            FullLengthMac::Hmac { hash_alg } => is_hash_deprecated(hash_alg),
-            _ => false,
-        }
-    }
-    match mac {
-        Mac::FullLength(full_length_mac) => is_full_length_mac_deprecated(full_length_mac),
-        Mac::Truncated { mac_alg, .. } => is_full_length_mac_deprecated(mac_alg),
-    }
+    is_algorithm_deprecated(mac.into())
 }
 
 /// Check if any part of the cipher is deprecated by PSA Crypto API
-pub fn is_cipher_deprecated(_cipher: Cipher) -> bool {
-    false
+pub fn is_cipher_deprecated(cipher: Cipher) -> bool {
+    is_algorithm_deprecated(cipher.into())
 }
 
 /// Check if any part of the aead is deprecated by PSA Crypto API
-pub fn is_aead_deprecated(_aead: Aead) -> bool {
-    false
+pub fn is_aead_deprecated(aead: Aead) -> bool {
+    is_algorithm_deprecated(aead.into())
 }
 
 /// Check if any part of the asymmetric signature is deprecated by PSA Crypto API
 pub fn is_asymmetric_signature_deprecated(asymm_sig: AsymmetricSignature) -> bool {
-    match asymm_sig {
-        AsymmetricSignature::RsaPkcs1v15Sign { hash_alg }
-        | AsymmetricSignature::RsaPss { hash_alg }
-        | AsymmetricSignature::Ecdsa { hash_alg }
-        | AsymmetricSignature::DeterministicEcdsa { hash_alg } => is_signhash_deprecated(hash_alg),
-        _ => false,
-    }
+    is_algorithm_deprecated(asymm_sig.into())
 }
 
 /// Check if any part of the asymmetric encryption is deprecated by PSA Crypto API
 pub fn is_asymmetric_encryption_deprecated(asymm_enc: AsymmetricEncryption) -> bool {
-    match asymm_enc {
-        AsymmetricEncryption::RsaOaep { hash_alg } => is_hash_deprecated(hash_alg),
-        _ => false,
-    }
+    is_algorithm_deprecated(asymm_enc.into())
 }
 
 /// Check if any part of the key agreement is deprecated by PSA Crypto API
 pub fn is_key_agreement_deprecated(key_agreement: KeyAgreement) -> bool {
-    match key_agreement {
-        KeyAgreement::WithKeyDerivation { kdf_alg, .. } => is_key_derivation_deprecated(kdf_alg),
-        _ => false,
-    }
+    is_algorithm_deprecated(key_agreement.into())
 }
 
 /// Check if any part of the key derivation is deprecated by PSA Crypto API
 pub fn is_key_derivation_deprecated(keyderv: KeyDerivation) -> bool {
-    match keyderv {
-        KeyDerivation::Hkdf { hash_alg }
-        | KeyDerivation::Tls12Prf { hash_alg }
-        | KeyDerivation::Tls12PskToMs { hash_alg } => is_hash_deprecated(hash_alg),
-    }
+    is_algorithm_deprecated(keyderv.into())
 }
 
 /// Check if any part of the algorithm is deprecated by PSA Crypto API
 pub fn is_algorithm_deprecated(alg: Algorithm) -> bool {
-    match alg {
-        Algorithm::None => false,
-        Algorithm::Hash(hash) => is_hash_deprecated(hash),
-        Algorithm::Mac(mac) => is_mac_deprecated(mac),
-        Algorithm::Cipher(cipher) => is_cipher_deprecated(cipher),
-        Algorithm::Aead(aead) => is_aead_deprecated(aead),
-        Algorithm::AsymmetricSignature(asymm_sig) => is_asymmetric_signature_deprecated(asymm_sig),
-        Algorithm::AsymmetricEncryption(asymm_enc) => {
-            is_asymmetric_encryption_deprecated(asymm_enc)
-        }
-        Algorithm::KeyAgreement(key_agreement) => is_key_agreement_deprecated(key_agreement),
-        Algorithm::KeyDerivation(keyderv) => is_key_derivation_deprecated(keyderv),
-    }
+    DEPRECATED_HASHES
+        .iter()
+        .any(|&hash| Matcher::DenyHash { hash }.matches_algorithm(alg))
 }
 
-/// Return a list of deprecated keys (type, size) if size is None, then the key type is deprecated
 fn get_deprecated_keys() -> Vec<(Type, Option<usize>)> {
-    vec![
-        (Type::Des, None),
-        (Type::Arc4, None),
-        (
-            Type::EccPublicKey {
-                curve_family: key::EccFamily::BrainpoolPR1,
-            },
-            Some(160),
-        ),
-        (
-            Type::EccPublicKey {
-                curve_family: key::EccFamily::SectR2,
-            },
-            None,
-        ),
-        (
-            Type::EccPublicKey {
-                curve_family: key::EccFamily::SectR1,
-            },
-            Some(163),
-        ),
-        (
-            Type::EccPublicKey {
-                curve_family: key::EccFamily::SectK1,
-            },
-            Some(163),
-        ),
-        (
-            Type::EccPublicKey {
-                curve_family: key::EccFamily::SecpR2,
-            },
-            None,
-        ),
-    ]
+    DEPRECATED_KEYS.to_vec()
 }
 
 /// Check if the key or the key type is deprecated by PSA Crypto API
+///
+/// Key pairs are deprecated if their corresponding public key is.
 pub fn is_key_deprecated(key_type: Type, key_size: usize) -> bool {
-    for (ktype, ksize) in get_deprecated_keys() {
-        if ktype == key_type && (ksize.is_none() || ksize == Some(key_size)) {
-            return true;
+    DEPRECATED_KEYS.iter().any(|&(deprecated_type, bits)| {
+        Matcher::DenyKeyType {
+            key_type: deprecated_type,
+            bits,
+        }
+        .matches_key(key_type, key_size)
+    })
+}
+
+/// Return the compliance profile denying the primitives deprecated by PSA Crypto API
+///
+/// This is the profile returned by `ComplianceProfile::default()`.
+pub fn deprecated_primitives_profile() -> ComplianceProfile {
+    let mut rules = Vec::new();
+    for hash in get_deprecated_hashes() {
+        rules.push(Rule {
+            id: format!("psa-deprecated-hash-{:?}", hash).to_lowercase(),
+            reason: format!("{:?} is deprecated by the PSA Crypto API", hash),
+            matcher: Matcher::DenyHash { hash },
+        });
+    }
+    for (key_type, bits) in get_deprecated_keys() {
+        let name = match key_type {
+            Type::EccPublicKey { curve_family } => format!("ecc-{:?}", curve_family),
+            key_type => format!("{:?}", key_type),
+        }
+        .to_lowercase();
+        let (id, reason) = match bits {
+            Some(bits) => (
+                format!("psa-deprecated-key-{}-{}", name, bits),
+                format!(
+                    "{} bits {} is deprecated by the PSA Crypto API",
+                    bits, key_type
+                ),
+            ),
+            None => (
+                format!("psa-deprecated-key-{}", name),
+                format!("{} is deprecated by the PSA Crypto API", key_type),
+            ),
+        };
+        rules.push(Rule {
+            id,
+            reason,
+            matcher: Matcher::DenyKeyType { key_type, bits },
+        });
+    }
+    ComplianceProfile {
+        name: String::from("psa-deprecated-primitives"),
+        rules,
+    }
+}
+
+/// Policy applied to deprecated primitives when validating key creation operations
+///
+/// Key creation operations can also be validated against any `ComplianceProfile` with their
+/// `validate_with_profile` method.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum DeprecatedPrimitivesPolicy {
+    /// Key templates containing any deprecated primitive are rejected with
+    /// `ResponseStatus::DeprecatedPrimitive`
+    #[default]
+    Reject,
+    /// Deprecated primitives are accepted, for interoperability with legacy systems
+    Allow,
+}
+
+impl DeprecatedPrimitivesPolicy {
+    /// Check the key template of an operation against this policy
+    pub fn check(self, op: &impl CheckDeprecated) -> Result<()> {
+        match self {
+            DeprecatedPrimitivesPolicy::Reject => op.check_deprecated(),
+            DeprecatedPrimitivesPolicy::Allow => Ok(()),
         }
     }
-    false
 }
 
 /// Checks if any part of the key template is deprecated by PSA Crypto API
@@ -184,6 +227,7 @@ impl CheckDeprecated for crate::operations::psa_generate_key::Operation {
 
 #[cfg(test)]
 mod tests {
+    use super::super::utils_compliance_profile::public_key_type;
     use super::*;
     fn get_selection_non_deprecated_hashes() -> Vec<Hash> {
         vec![Hash::Sha256, Hash::Sha3_512, Hash::Sha384, Hash::Ripemd160]
@@ -666,4 +710,127 @@ mod tests {
         import_key_op.attributes.policy.permitted_algorithms = test_algorithm;
         assert_eq!(import_key_op.check_deprecated(), Ok(()));
     }
+
+    fn get_key_sizes(ksize: Option<usize>) -> Vec<usize> {
+        match ksize {
+            Some(ksize) => vec![ksize],
+            // Arbitrary selection of sizes for key types deprecated whatever their size
+            None => vec![40, 64, 128, 160, 192, 256],
+        }
+    }
+
+    fn get_key_pair_type(key_type: Type) -> Option<Type> {
+        match key_type {
+            Type::EccPublicKey { curve_family } => Some(Type::EccKeyPair { curve_family }),
+            Type::DhPublicKey { group_family } => Some(Type::DhKeyPair { group_family }),
+            Type::RsaPublicKey => Some(Type::RsaKeyPair),
+            _ => None,
+        }
+    }
+
+    fn validate_with_key(
+        key_type: Type,
+        bits: usize,
+        policy: DeprecatedPrimitivesPolicy,
+    ) -> (Result<()>, Result<()>) {
+        use psa_crypto::types::key::{Attributes, Lifetime, Policy, UsageFlags};
+        let attributes = Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type,
+            bits,
+            policy: Policy {
+                usage_flags: UsageFlags::default(),
+                permitted_algorithms: Algorithm::None,
+            },
+        };
+        let generate_key_op = crate::operations::psa_generate_key::Operation {
+            key_name: "dummy".to_string(),
+            attributes,
+        };
+        let import_key_op = crate::operations::psa_import_key::Operation {
+            key_name: "dummy".to_string(),
+            attributes,
+            data: secrecy::Secret::new(vec![]),
+        };
+        (
+            generate_key_op.validate(policy),
+            import_key_op.validate(policy),
+        )
+    }
+
+    #[test]
+    fn deprecated_key_pairs() {
+        for (ktype, _) in get_deprecated_keys() {
+            if let Some(key_pair_type) = get_key_pair_type(ktype) {
+                assert_eq!(public_key_type(key_pair_type), ktype);
+            }
+        }
+        assert!(is_key_deprecated(
+            Type::EccKeyPair {
+                curve_family: key::EccFamily::SecpR2,
+            },
+            160
+        ));
+        assert!(!is_key_deprecated(
+            Type::EccKeyPair {
+                curve_family: key::EccFamily::SecpR1,
+            },
+            256
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_every_deprecated_key() {
+        for (ktype, ksize) in get_deprecated_keys() {
+            let key_types = std::iter::once(ktype).chain(get_key_pair_type(ktype));
+            for key_type in key_types {
+                for bits in get_key_sizes(ksize) {
+                    assert_eq!(
+                        validate_with_key(key_type, bits, DeprecatedPrimitivesPolicy::default()),
+                        (
+                            Err(ResponseStatus::DeprecatedPrimitive),
+                            Err(ResponseStatus::DeprecatedPrimitive)
+                        ),
+                        "key: ({:?} : {:?})",
+                        key_type,
+                        bits
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validate_allows_every_deprecated_key_for_legacy() {
+        for (ktype, ksize) in get_deprecated_keys() {
+            let key_types = std::iter::once(ktype).chain(get_key_pair_type(ktype));
+            for key_type in key_types {
+                for bits in get_key_sizes(ksize) {
+                    assert_eq!(
+                        validate_with_key(key_type, bits, DeprecatedPrimitivesPolicy::Allow),
+                        (Ok(()), Ok(())),
+                        "key: ({:?} : {:?})",
+                        key_type,
+                        bits
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validate_accepts_non_deprecated_size() {
+        // Only some sizes of these curve families are deprecated
+        for (ktype, ksize) in get_deprecated_keys() {
+            if let Some(ksize) = ksize {
+                assert_eq!(
+                    validate_with_key(ktype, ksize + 1, DeprecatedPrimitivesPolicy::Reject),
+                    (Ok(()), Ok(())),
+                    "key: ({:?} : {:?})",
+                    ktype,
+                    ksize + 1
+                );
+            }
+        }
+    }
 }
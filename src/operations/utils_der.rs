@@ -0,0 +1,261 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Minimal DER helpers
+//!
+//! Only the subset of DER needed to handle key and signature formats is supported: definite
+//! lengths, SEQUENCE, positive INTEGER, BIT STRING, NULL and OBJECT IDENTIFIER values.
+
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+
+pub(crate) const TAG_INTEGER: u8 = 0x02;
+pub(crate) const TAG_BIT_STRING: u8 = 0x03;
+pub(crate) const TAG_NULL: u8 = 0x05;
+pub(crate) const TAG_OID: u8 = 0x06;
+pub(crate) const TAG_SEQUENCE: u8 = 0x30;
+
+/// Reader over a buffer of concatenated DER values
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct DerReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        DerReader { data }
+    }
+
+    /// Check that all values have been read
+    pub(crate) fn finish(&self) -> Result<()> {
+        if !self.data.is_empty() {
+            error!("Unexpected {} bytes after the DER value.", self.data.len());
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Read the next value, returning its tag and its content
+    pub(crate) fn read_tlv(&mut self) -> Result<(u8, &'a [u8])> {
+        let (&tag, rest) = self.data.split_first().ok_or_else(|| {
+            error!("Missing DER value.");
+            ResponseStatus::PsaErrorInvalidArgument
+        })?;
+        let (&first, mut rest) = rest.split_first().ok_or_else(|| {
+            error!("Missing DER length.");
+            ResponseStatus::PsaErrorInvalidArgument
+        })?;
+        let len = if first < 0x80 {
+            usize::from(first)
+        } else {
+            let len_len = usize::from(first & 0x7f);
+            if len_len == 0 || len_len > (usize::BITS / 8) as usize || rest.len() < len_len {
+                error!("Invalid DER length encoding.");
+                return Err(ResponseStatus::PsaErrorInvalidArgument);
+            }
+            let (len_bytes, content) = rest.split_at(len_len);
+            rest = content;
+            let len = len_bytes
+                .iter()
+                .fold(0_usize, |len, &byte| (len << 8) | usize::from(byte));
+            // DER requires the shortest length encoding.
+            if len < 0x80 || len_bytes[0] == 0 {
+                error!("Non-minimal DER length encoding.");
+                return Err(ResponseStatus::PsaErrorInvalidArgument);
+            }
+            len
+        };
+        if rest.len() < len {
+            error!("DER value is truncated.");
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        let (content, rest) = rest.split_at(len);
+        self.data = rest;
+        Ok((tag, content))
+    }
+
+    /// Read the next value, which must have the given tag
+    pub(crate) fn read_expected(&mut self, expected: u8) -> Result<&'a [u8]> {
+        let (tag, content) = self.read_tlv()?;
+        if tag != expected {
+            error!("Expected DER tag 0x{:02x}, found 0x{:02x}.", expected, tag);
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        Ok(content)
+    }
+
+    /// Read a SEQUENCE, returning a reader over its content
+    pub(crate) fn read_sequence(&mut self) -> Result<DerReader<'a>> {
+        Ok(DerReader::new(self.read_expected(TAG_SEQUENCE)?))
+    }
+
+    /// Read a non-negative INTEGER, returning its big-endian magnitude without leading zeros
+    pub(crate) fn read_unsigned_integer(&mut self) -> Result<&'a [u8]> {
+        let content = self.read_expected(TAG_INTEGER)?;
+        match content {
+            [] => {
+                error!("Empty DER integer.");
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            }
+            [first, ..] if first & 0x80 != 0 => {
+                error!("Negative DER integer.");
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            }
+            [0, second, ..] if second & 0x80 == 0 => {
+                error!("Non-minimal DER integer encoding.");
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            }
+            [0, rest @ ..] => Ok(rest),
+            _ => Ok(content),
+        }
+    }
+
+    /// Read a BIT STRING without unused bits, returning its bytes
+    pub(crate) fn read_bit_string(&mut self) -> Result<&'a [u8]> {
+        match self.read_expected(TAG_BIT_STRING)? {
+            [0, bytes @ ..] => Ok(bytes),
+            _ => {
+                error!("Only BIT STRING values made of whole bytes are supported.");
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            }
+        }
+    }
+
+    /// Return true if there are no more values to read
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+/// Encode a value from its tag and content
+pub(crate) fn encode_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut der = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        der.push(len as u8);
+    } else {
+        let len_bytes: Vec<u8> = len
+            .to_be_bytes()
+            .iter()
+            .copied()
+            .skip_while(|&byte| byte == 0)
+            .collect();
+        der.push(0x80 | len_bytes.len() as u8);
+        der.extend(len_bytes);
+    }
+    der.extend_from_slice(content);
+    der
+}
+
+/// Encode a non-negative INTEGER from its big-endian magnitude
+pub(crate) fn encode_unsigned_integer(magnitude: &[u8]) -> Vec<u8> {
+    let magnitude: Vec<u8> = magnitude
+        .iter()
+        .copied()
+        .skip_while(|&byte| byte == 0)
+        .collect();
+    let mut content = Vec::with_capacity(magnitude.len() + 1);
+    if magnitude.first().map_or(true, |&byte| byte & 0x80 != 0) {
+        content.push(0);
+    }
+    content.extend(magnitude);
+    encode_tlv(TAG_INTEGER, &content)
+}
+
+/// Encode a BIT STRING without unused bits
+pub(crate) fn encode_bit_string(bytes: &[u8]) -> Vec<u8> {
+    let mut content = Vec::with_capacity(bytes.len() + 1);
+    content.push(0);
+    content.extend_from_slice(bytes);
+    encode_tlv(TAG_BIT_STRING, &content)
+}
+
+/// Number of significant bits of a big-endian unsigned integer
+pub(crate) fn bit_length(magnitude: &[u8]) -> usize {
+    match magnitude.iter().position(|&byte| byte != 0) {
+        Some(index) => (magnitude.len() - index) * 8 - magnitude[index].leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_sequence_of_integers() {
+        let data = [0x30, 0x08, 0x02, 0x01, 0x05, 0x02, 0x03, 0x00, 0x80, 0x01];
+        let mut reader = DerReader::new(&data);
+        let mut sequence = reader.read_sequence().unwrap();
+        reader.finish().unwrap();
+        assert_eq!(sequence.read_unsigned_integer().unwrap(), &[0x05]);
+        assert_eq!(sequence.read_unsigned_integer().unwrap(), &[0x80, 0x01]);
+        sequence.finish().unwrap();
+    }
+
+    #[test]
+    fn long_length() {
+        let mut data = vec![0x02, 0x81, 0x81, 0x00];
+        data.extend(vec![0xff; 0x80]);
+        let magnitude = DerReader::new(&data).read_unsigned_integer().unwrap();
+        assert_eq!(magnitude.len(), 0x80);
+        assert_eq!(bit_length(magnitude), 1024);
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        let invalid: Vec<&[u8]> = vec![
+            // Truncated
+            &[0x02, 0x02, 0x01],
+            // Non-minimal length
+            &[0x02, 0x81, 0x01, 0x01],
+            // Indefinite length
+            &[0x02, 0x80, 0x01, 0x00, 0x00],
+            // Negative
+            &[0x02, 0x01, 0x80],
+            // Non-minimal integer
+            &[0x02, 0x02, 0x00, 0x01],
+            // Empty integer
+            &[0x02, 0x00],
+            // Wrong tag
+            &[0x04, 0x01, 0x01],
+        ];
+        for data in invalid {
+            assert_eq!(
+                DerReader::new(data).read_unsigned_integer().unwrap_err(),
+                ResponseStatus::PsaErrorInvalidArgument,
+                "data: {:?}",
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn encode_read_round_trip() {
+        let magnitudes: Vec<&[u8]> = vec![&[0x00], &[0x7f], &[0x80], &[0x00, 0x00, 0x01, 0x00]];
+        for magnitude in magnitudes {
+            let der = encode_unsigned_integer(magnitude);
+            let mut reader = DerReader::new(&der);
+            assert_eq!(
+                bit_length(reader.read_unsigned_integer().unwrap()),
+                bit_length(magnitude)
+            );
+            assert!(reader.is_empty());
+        }
+
+        let content = vec![0xab; 0x1234];
+        let der = encode_tlv(TAG_SEQUENCE, &encode_bit_string(&content));
+        assert_eq!(&der[..4], &[0x30, 0x82, 0x12, 0x39]);
+        let mut reader = DerReader::new(&der);
+        let mut sequence = reader.read_sequence().unwrap();
+        assert_eq!(sequence.read_bit_string().unwrap(), content.as_slice());
+        sequence.finish().unwrap();
+    }
+
+    #[test]
+    fn bit_lengths() {
+        assert_eq!(bit_length(&[]), 0);
+        assert_eq!(bit_length(&[0x00, 0x00]), 0);
+        assert_eq!(bit_length(&[0x01]), 1);
+        assert_eq!(bit_length(&[0x00, 0x80, 0x00]), 16);
+    }
+}
@@ -0,0 +1,443 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//!
+//! # Utilities for checking key data
+//!
+//! Check that key material is in the import format defined by PSA Crypto API 1.0.0 and that it is
+//! consistent with the attributes of the key. Only the format is checked, not the mathematical
+//! validity of the key. A `bits` attribute of 0 means that the key size is
+//! determined from the data.
+
+use super::utils_der::{bit_length, DerReader};
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+use psa_crypto::types::key::{Attributes, DhFamily, EccFamily, Type};
+
+/// Sizes in bits of the curves of an ECC family
+pub fn ecc_curve_bits(curve_family: EccFamily) -> &'static [usize] {
+    match curve_family {
+        EccFamily::SecpK1 => &[192, 225, 256],
+        EccFamily::SecpR1 => &[192, 224, 256, 384, 521],
+        EccFamily::SecpR2 => &[160],
+        EccFamily::SectK1 => &[163, 233, 239, 283, 409, 571],
+        EccFamily::SectR1 => &[163, 233, 283, 409, 571],
+        EccFamily::SectR2 => &[163],
+        EccFamily::BrainpoolPR1 => &[160, 192, 224, 256, 320, 384, 512],
+        EccFamily::Frp => &[256],
+        EccFamily::Montgomery => &[255, 448],
+    }
+}
+
+/// Sizes in bits of the groups of a Diffie-Hellman family
+pub fn dh_group_bits(group_family: DhFamily) -> &'static [usize] {
+    match group_family {
+        DhFamily::Rfc7919 => &[2048, 3072, 4096, 6144, 8192],
+    }
+}
+
+/// Validate key data in the PSA import format against the key attributes
+///
+/// The formats checked are:
+/// * the DER encoding of RSAPublicKey or RSAPrivateKey for RSA keys
+/// * uncompressed or compressed points for Weierstrass ECC public keys and raw values for
+///   Montgomery ones
+/// * private values of the size of the curve for ECC key pairs
+/// * big-endian values of the size of the group for Diffie-Hellman keys
+/// * lengths allowed by the algorithm for symmetric keys
+///
+/// This is a format check only, it is not a validation of the key itself: ECC points are checked
+/// for their prefix and length but not for being on the curve, private values and RSA parameters
+/// are not checked for being in range or consistent with each other. Providers still need to
+/// validate the key material, for example with `psa_import_key`, before using it.
+///
+/// Returns `ResponseStatus::PsaErrorInvalidArgument` if the data is malformed or not consistent
+/// with the attributes.
+pub fn validate_key_data(attributes: &Attributes, data: &[u8]) -> Result<()> {
+    let key_type = attributes.key_type;
+    let bits = attributes.bits;
+    match key_type {
+        Type::RawData | Type::Hmac | Type::Derive => {
+            check_symmetric(key_type, bits, data, |_| true)
+        }
+        Type::Aes | Type::Camellia => {
+            check_symmetric(key_type, bits, data, |len| [16, 24, 32].contains(&len))
+        }
+        Type::Des => check_symmetric(key_type, bits, data, |len| [8, 16, 24].contains(&len)),
+        Type::Arc4 => check_symmetric(key_type, bits, data, |len| (5..=256).contains(&len)),
+        Type::Chacha20 => check_symmetric(key_type, bits, data, |len| len == 32),
+        Type::RsaPublicKey => check_rsa_public_key(bits, data),
+        Type::RsaKeyPair => check_rsa_key_pair(bits, data),
+        Type::EccKeyPair { curve_family } => {
+            check_sizes(key_type, bits, ecc_curve_bits(curve_family), data, |bits| {
+                data.len() == byte_length(bits) && data.iter().any(|&byte| byte != 0)
+            })
+        }
+        Type::EccPublicKey {
+            curve_family: EccFamily::Montgomery,
+        } => check_sizes(
+            key_type,
+            bits,
+            ecc_curve_bits(EccFamily::Montgomery),
+            data,
+            |bits| data.len() == byte_length(bits),
+        ),
+        Type::EccPublicKey { curve_family } => {
+            check_sizes(key_type, bits, ecc_curve_bits(curve_family), data, |bits| {
+                let coordinate_len = byte_length(bits);
+                match data.first() {
+                    Some(0x04) => data.len() == 1 + 2 * coordinate_len,
+                    Some(0x02) | Some(0x03) => data.len() == 1 + coordinate_len,
+                    _ => false,
+                }
+            })
+        }
+        Type::DhKeyPair { group_family } | Type::DhPublicKey { group_family } => {
+            check_sizes(key_type, bits, dh_group_bits(group_family), data, |bits| {
+                data.len() == byte_length(bits)
+            })
+        }
+    }
+}
+
+fn byte_length(bits: usize) -> usize {
+    (bits + 7) / 8
+}
+
+fn invalid_data(key_type: Type, bits: usize, data: &[u8]) -> ResponseStatus {
+    error!(
+        "Key data of {} bytes is not valid for a {} of {} bits.",
+        data.len(),
+        key_type,
+        bits
+    );
+    ResponseStatus::PsaErrorInvalidArgument
+}
+
+fn check_symmetric(
+    key_type: Type,
+    bits: usize,
+    data: &[u8],
+    valid_len: impl Fn(usize) -> bool,
+) -> Result<()> {
+    if !valid_len(data.len()) || (bits != 0 && bits != data.len() * 8) {
+        return Err(invalid_data(key_type, bits, data));
+    }
+    Ok(())
+}
+
+/// Check the data against the given key size, or against all supported sizes if `bits` is 0
+fn check_sizes(
+    key_type: Type,
+    bits: usize,
+    supported: &[usize],
+    data: &[u8],
+    valid_for: impl Fn(usize) -> bool,
+) -> Result<()> {
+    if bits != 0 && !supported.contains(&bits) {
+        error!("{} bits is not a supported size for a {}.", bits, key_type);
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+    if supported
+        .iter()
+        .filter(|&&size| bits == 0 || size == bits)
+        .any(|&size| valid_for(size))
+    {
+        Ok(())
+    } else {
+        Err(invalid_data(key_type, bits, data))
+    }
+}
+
+fn check_rsa_modulus(bits: usize, modulus: &[u8]) -> Result<()> {
+    let modulus_bits = bit_length(modulus);
+    if bits != 0 && modulus_bits != bits {
+        error!(
+            "The RSA modulus is {} bits long instead of the {} bits of the key attributes.",
+            modulus_bits, bits
+        );
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+    Ok(())
+}
+
+fn check_rsa_public_exponent(exponent: &[u8]) -> Result<()> {
+    if bit_length(exponent) < 2 || exponent.last().map_or(true, |byte| byte & 1 == 0) {
+        error!("The RSA public exponent must be odd and greater than 1.");
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+    Ok(())
+}
+
+/// RSAPublicKey ::= SEQUENCE { modulus INTEGER, publicExponent INTEGER }
+fn check_rsa_public_key(bits: usize, data: &[u8]) -> Result<()> {
+    let mut reader = DerReader::new(data);
+    let mut key = reader.read_sequence()?;
+    reader.finish()?;
+    check_rsa_modulus(bits, key.read_unsigned_integer()?)?;
+    check_rsa_public_exponent(key.read_unsigned_integer()?)?;
+    key.finish()
+}
+
+/// RSAPrivateKey ::= SEQUENCE { version INTEGER, modulus INTEGER, publicExponent INTEGER,
+/// privateExponent INTEGER, prime1 INTEGER, prime2 INTEGER, exponent1 INTEGER,
+/// exponent2 INTEGER, coefficient INTEGER }
+fn check_rsa_key_pair(bits: usize, data: &[u8]) -> Result<()> {
+    let mut reader = DerReader::new(data);
+    let mut key = reader.read_sequence()?;
+    reader.finish()?;
+    if !key.read_unsigned_integer()?.is_empty() {
+        error!("Only version 0 of RSAPrivateKey (two primes) is supported.");
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+    check_rsa_modulus(bits, key.read_unsigned_integer()?)?;
+    check_rsa_public_exponent(key.read_unsigned_integer()?)?;
+    // privateExponent, prime1, prime2, exponent1, exponent2 and coefficient
+    for _ in 0..6 {
+        let _ = key.read_unsigned_integer()?;
+    }
+    key.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use psa_crypto::types::algorithm::Algorithm;
+    use psa_crypto::types::key::{Lifetime, Policy, UsageFlags};
+
+    fn attrs(key_type: Type, bits: usize) -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type,
+            bits,
+            policy: Policy {
+                usage_flags: UsageFlags::default(),
+                permitted_algorithms: Algorithm::None,
+            },
+        }
+    }
+
+    fn der_integer(magnitude: &[u8]) -> Vec<u8> {
+        let mut content = magnitude.to_vec();
+        if content.first().map_or(true, |byte| byte & 0x80 != 0) {
+            content.insert(0, 0);
+        }
+        der_tlv(0x02, &content)
+    }
+
+    fn der_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+        let mut der = vec![tag];
+        if content.len() < 0x80 {
+            der.push(content.len() as u8);
+        } else if content.len() < 0x100 {
+            der.extend([0x81, content.len() as u8]);
+        } else {
+            der.extend([0x82, (content.len() >> 8) as u8, content.len() as u8]);
+        }
+        der.extend(content);
+        der
+    }
+
+    fn rsa_public_key(modulus_bytes: usize) -> Vec<u8> {
+        let mut content = der_integer(&vec![0xc5; modulus_bytes]);
+        content.extend(der_integer(&[0x01, 0x00, 0x01]));
+        der_tlv(0x30, &content)
+    }
+
+    fn rsa_key_pair(modulus_bytes: usize, version: u8) -> Vec<u8> {
+        let mut content = der_integer(&[version]);
+        content.extend(der_integer(&vec![0xc5; modulus_bytes]));
+        content.extend(der_integer(&[0x01, 0x00, 0x01]));
+        for len in &[modulus_bytes, modulus_bytes / 2, modulus_bytes / 2] {
+            content.extend(der_integer(&vec![0x5a; *len]));
+        }
+        for len in &[modulus_bytes / 2; 3] {
+            content.extend(der_integer(&vec![0x3c; *len]));
+        }
+        der_tlv(0x30, &content)
+    }
+
+    fn secp_r1() -> Type {
+        Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        }
+    }
+
+    #[test]
+    fn rsa_public_key_valid() {
+        validate_key_data(&attrs(Type::RsaPublicKey, 2048), &rsa_public_key(256)).unwrap();
+        validate_key_data(&attrs(Type::RsaPublicKey, 0), &rsa_public_key(384)).unwrap();
+    }
+
+    #[test]
+    fn rsa_public_key_wrong_size() {
+        assert_eq!(
+            validate_key_data(&attrs(Type::RsaPublicKey, 2048), &rsa_public_key(128)),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+    }
+
+    #[test]
+    fn rsa_public_key_malformed() {
+        let mut data = rsa_public_key(256);
+        data.push(0);
+        assert_eq!(
+            validate_key_data(&attrs(Type::RsaPublicKey, 2048), &data),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+        let data = rsa_public_key(256);
+        assert_eq!(
+            validate_key_data(&attrs(Type::RsaPublicKey, 2048), &data[..data.len() - 1]),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+        // A key pair is not a valid public key
+        assert_eq!(
+            validate_key_data(&attrs(Type::RsaPublicKey, 2048), &rsa_key_pair(256, 0)),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+    }
+
+    #[test]
+    fn rsa_key_pair_valid() {
+        validate_key_data(&attrs(Type::RsaKeyPair, 1024), &rsa_key_pair(128, 0)).unwrap();
+    }
+
+    #[test]
+    fn rsa_key_pair_invalid() {
+        assert_eq!(
+            validate_key_data(&attrs(Type::RsaKeyPair, 2048), &rsa_key_pair(128, 0)),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+        assert_eq!(
+            validate_key_data(&attrs(Type::RsaKeyPair, 1024), &rsa_key_pair(128, 1)),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+        assert_eq!(
+            validate_key_data(&attrs(Type::RsaKeyPair, 1024), &rsa_public_key(128)),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+    }
+
+    #[test]
+    fn ecc_public_key_points() {
+        let mut uncompressed = vec![0x04];
+        uncompressed.extend(vec![0x11; 64]);
+        validate_key_data(&attrs(secp_r1(), 256), &uncompressed).unwrap();
+        validate_key_data(&attrs(secp_r1(), 0), &uncompressed).unwrap();
+
+        let mut compressed = vec![0x03];
+        compressed.extend(vec![0x11; 32]);
+        validate_key_data(&attrs(secp_r1(), 256), &compressed).unwrap();
+
+        // P-521 coordinates are 66 bytes long
+        let mut uncompressed = vec![0x04];
+        uncompressed.extend(vec![0x01; 132]);
+        validate_key_data(&attrs(secp_r1(), 521), &uncompressed).unwrap();
+    }
+
+    #[test]
+    fn ecc_public_key_invalid_points() {
+        let mut wrong_prefix = vec![0x05];
+        wrong_prefix.extend(vec![0x11; 64]);
+        let mut wrong_length = vec![0x04];
+        wrong_length.extend(vec![0x11; 63]);
+        let mut wrong_curve = vec![0x04];
+        wrong_curve.extend(vec![0x11; 96]);
+        for data in &[wrong_prefix, wrong_length, wrong_curve, vec![]] {
+            assert_eq!(
+                validate_key_data(&attrs(secp_r1(), 256), data),
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            );
+        }
+    }
+
+    #[test]
+    fn ecc_unsupported_curve_size() {
+        let mut uncompressed = vec![0x04];
+        uncompressed.extend(vec![0x11; 64]);
+        assert_eq!(
+            validate_key_data(&attrs(secp_r1(), 255), &uncompressed),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+    }
+
+    #[test]
+    fn ecc_montgomery() {
+        let x25519 = Type::EccPublicKey {
+            curve_family: EccFamily::Montgomery,
+        };
+        validate_key_data(&attrs(x25519, 255), &[0x42; 32]).unwrap();
+        validate_key_data(&attrs(x25519, 448), &[0x42; 56]).unwrap();
+        assert_eq!(
+            validate_key_data(&attrs(x25519, 255), &[0x42; 56]),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+        let key_pair = Type::EccKeyPair {
+            curve_family: EccFamily::Montgomery,
+        };
+        validate_key_data(&attrs(key_pair, 0), &[0x42; 32]).unwrap();
+    }
+
+    #[test]
+    fn ecc_key_pair() {
+        let key_pair = Type::EccKeyPair {
+            curve_family: EccFamily::SecpR1,
+        };
+        validate_key_data(&attrs(key_pair, 256), &[0x42; 32]).unwrap();
+        validate_key_data(&attrs(key_pair, 521), &[0x01; 66]).unwrap();
+        for data in &[vec![0x42; 31], vec![0x00; 32], vec![0x42; 65]] {
+            assert_eq!(
+                validate_key_data(&attrs(key_pair, 256), data),
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            );
+        }
+    }
+
+    #[test]
+    fn symmetric_lengths() {
+        validate_key_data(&attrs(Type::Aes, 128), &[0; 16]).unwrap();
+        validate_key_data(&attrs(Type::Aes, 0), &[0; 32]).unwrap();
+        validate_key_data(&attrs(Type::Des, 192), &[0; 24]).unwrap();
+        validate_key_data(&attrs(Type::Chacha20, 256), &[0; 32]).unwrap();
+        validate_key_data(&attrs(Type::Hmac, 0), &[0; 100]).unwrap();
+        validate_key_data(&attrs(Type::RawData, 8), &[0; 1]).unwrap();
+        let invalid = vec![
+            (Type::Aes, 128, 20),
+            (Type::Aes, 256, 16),
+            (Type::Aes, 0, 20),
+            (Type::Des, 0, 12),
+            (Type::Chacha20, 0, 16),
+            (Type::Arc4, 0, 4),
+            (Type::Hmac, 128, 32),
+        ];
+        for (key_type, bits, len) in invalid {
+            assert_eq!(
+                validate_key_data(&attrs(key_type, bits), &vec![0; len]),
+                Err(ResponseStatus::PsaErrorInvalidArgument),
+                "key: ({:?} : {:?} : {:?})",
+                key_type,
+                bits,
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn dh_values() {
+        let key_pair = Type::DhKeyPair {
+            group_family: DhFamily::Rfc7919,
+        };
+        let public_key = Type::DhPublicKey {
+            group_family: DhFamily::Rfc7919,
+        };
+        validate_key_data(&attrs(key_pair, 2048), &[0x42; 256]).unwrap();
+        validate_key_data(&attrs(public_key, 0), &[0x42; 384]).unwrap();
+        assert_eq!(
+            validate_key_data(&attrs(public_key, 2048), &[0x42; 384]),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+        assert_eq!(
+            validate_key_data(&attrs(public_key, 1024), &[0x42; 128]),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+    }
+}
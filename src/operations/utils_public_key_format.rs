@@ -0,0 +1,649 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//!
+//! # Public key format conversions
+//!
+//! `PsaExportPublicKey` returns public keys in the PSA format: the DER encoding of RSAPublicKey
+//! for RSA keys and the raw point for ECC keys. This module converts them to and from the
+//! formats expected by other systems:
+//! * SubjectPublicKeyInfo (SPKI) DER, as used in X.509 certificates, and its PEM armor
+//! * JSON Web Key (JWK), as defined in RFC 7517, RFC 7518 and RFC 8037
+//!
+//! The keys parsed back are in the PSA format accepted by `PsaImportKey`.
+//!
+//! RSA keys, ECC keys on the named curves listed in this module and X25519/X448 keys are
+//! supported. Other key types return `ResponseStatus::PsaErrorNotSupported`.
+
+use super::utils_base64::{base64_decode, base64_encode, BASE64_STANDARD, BASE64_URL};
+use super::utils_der::{
+    bit_length, encode_bit_string, encode_tlv, encode_unsigned_integer, DerReader, TAG_NULL,
+    TAG_OID, TAG_SEQUENCE,
+};
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+use psa_crypto::types::key::{Attributes, EccFamily, Type};
+use serde::{Deserialize, Serialize};
+
+const OID_RSA_ENCRYPTION: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01];
+const OID_EC_PUBLIC_KEY: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+
+/// A named curve: its PSA family and size, object identifier and JWK name
+struct NamedCurve {
+    family: EccFamily,
+    bits: usize,
+    oid: &'static [u8],
+    jwk_name: Option<&'static str>,
+}
+
+const NAMED_CURVES: &[NamedCurve] = &[
+    NamedCurve {
+        family: EccFamily::SecpR1,
+        bits: 192,
+        oid: &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x01],
+        jwk_name: None,
+    },
+    NamedCurve {
+        family: EccFamily::SecpR1,
+        bits: 224,
+        oid: &[0x2b, 0x81, 0x04, 0x00, 0x21],
+        jwk_name: None,
+    },
+    NamedCurve {
+        family: EccFamily::SecpR1,
+        bits: 256,
+        oid: &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07],
+        jwk_name: Some("P-256"),
+    },
+    NamedCurve {
+        family: EccFamily::SecpR1,
+        bits: 384,
+        oid: &[0x2b, 0x81, 0x04, 0x00, 0x22],
+        jwk_name: Some("P-384"),
+    },
+    NamedCurve {
+        family: EccFamily::SecpR1,
+        bits: 521,
+        oid: &[0x2b, 0x81, 0x04, 0x00, 0x23],
+        jwk_name: Some("P-521"),
+    },
+    NamedCurve {
+        family: EccFamily::SecpK1,
+        bits: 256,
+        oid: &[0x2b, 0x81, 0x04, 0x00, 0x0a],
+        jwk_name: Some("secp256k1"),
+    },
+    NamedCurve {
+        family: EccFamily::BrainpoolPR1,
+        bits: 256,
+        oid: &[0x2b, 0x24, 0x03, 0x03, 0x02, 0x08, 0x01, 0x01, 0x07],
+        jwk_name: None,
+    },
+    NamedCurve {
+        family: EccFamily::BrainpoolPR1,
+        bits: 384,
+        oid: &[0x2b, 0x24, 0x03, 0x03, 0x02, 0x08, 0x01, 0x01, 0x0b],
+        jwk_name: None,
+    },
+    NamedCurve {
+        family: EccFamily::BrainpoolPR1,
+        bits: 512,
+        oid: &[0x2b, 0x24, 0x03, 0x03, 0x02, 0x08, 0x01, 0x01, 0x0d],
+        jwk_name: None,
+    },
+    // The Montgomery curves have their own algorithm identifiers, without parameters.
+    NamedCurve {
+        family: EccFamily::Montgomery,
+        bits: 255,
+        oid: &[0x2b, 0x65, 0x6e],
+        jwk_name: Some("X25519"),
+    },
+    NamedCurve {
+        family: EccFamily::Montgomery,
+        bits: 448,
+        oid: &[0x2b, 0x65, 0x6f],
+        jwk_name: Some("X448"),
+    },
+];
+
+/// A public key in the PSA format, with the type and size needed to import it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKeyData {
+    /// Type of the key, always a public key type
+    pub key_type: Type,
+    /// Size of the key in bits
+    pub bits: usize,
+    /// Key data in the PSA import format
+    pub data: Vec<u8>,
+}
+
+/// JSON Web Key representation of a public key
+///
+/// All binary members are encoded with unpadded base64url.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Jwk {
+    /// Key type: "RSA", "EC" or "OKP"
+    pub kty: String,
+    /// Curve name, for "EC" and "OKP" keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    /// RSA modulus
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    /// RSA public exponent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+    /// X coordinate for "EC" keys, public key for "OKP" keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    /// Y coordinate for "EC" keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+}
+
+fn not_supported(key_type: Type, bits: usize) -> ResponseStatus {
+    error!(
+        "Conversion of a {} of {} bits is not supported.",
+        key_type, bits
+    );
+    ResponseStatus::PsaErrorNotSupported
+}
+
+fn invalid(reason: &str) -> ResponseStatus {
+    error!("{}", reason);
+    ResponseStatus::PsaErrorInvalidArgument
+}
+
+fn named_curve(family: EccFamily, bits: usize) -> Option<&'static NamedCurve> {
+    NAMED_CURVES
+        .iter()
+        .find(|curve| curve.family == family && curve.bits == bits)
+}
+
+/// Return the curve family of a key type, or None for RSA keys
+fn curve_family(key_type: Type, bits: usize) -> Result<Option<EccFamily>> {
+    match key_type {
+        Type::RsaPublicKey | Type::RsaKeyPair => Ok(None),
+        Type::EccPublicKey { curve_family } | Type::EccKeyPair { curve_family } => {
+            Ok(Some(curve_family))
+        }
+        _ => Err(not_supported(key_type, bits)),
+    }
+}
+
+/// Parse RSAPublicKey, returning the magnitudes of the modulus and of the public exponent
+fn parse_rsa_public_key(data: &[u8]) -> Result<(&[u8], &[u8])> {
+    let mut reader = DerReader::new(data);
+    let mut key = reader.read_sequence()?;
+    reader.finish()?;
+    let modulus = key.read_unsigned_integer()?;
+    let exponent = key.read_unsigned_integer()?;
+    key.finish()?;
+    Ok((modulus, exponent))
+}
+
+fn encode_rsa_public_key(modulus: &[u8], exponent: &[u8]) -> Vec<u8> {
+    let mut content = encode_unsigned_integer(modulus);
+    content.extend(encode_unsigned_integer(exponent));
+    encode_tlv(TAG_SEQUENCE, &content)
+}
+
+/// Check that the size of an RSA modulus matches the key size, if it is given
+fn check_rsa_modulus(modulus: &[u8], bits: usize) -> Result<()> {
+    if bits != 0 && bit_length(modulus) != bits {
+        return Err(invalid("The RSA modulus size does not match the key size."));
+    }
+    Ok(())
+}
+
+/// Length in bytes of a coordinate of a point of the curve
+fn coordinate_len(curve: &NamedCurve) -> usize {
+    (curve.bits + 7) / 8
+}
+
+/// Check that a point is consistent with the curve size
+fn check_point(curve: &NamedCurve, point: &[u8]) -> Result<()> {
+    let coordinate_len = coordinate_len(curve);
+    let valid = match (curve.family, point.first()) {
+        (EccFamily::Montgomery, _) => point.len() == coordinate_len,
+        (_, Some(0x04)) => point.len() == 1 + 2 * coordinate_len,
+        (_, Some(0x02)) | (_, Some(0x03)) => point.len() == 1 + coordinate_len,
+        _ => false,
+    };
+    if !valid {
+        return Err(invalid("The ECC point is not valid for the curve."));
+    }
+    Ok(())
+}
+
+/// Convert a public key in the PSA export format to the DER encoding of SubjectPublicKeyInfo
+///
+/// `attributes` are the attributes of the exported key, which can be a public key or a key pair.
+pub fn to_spki_der(attributes: &Attributes, data: &[u8]) -> Result<Vec<u8>> {
+    let (key_type, bits) = (attributes.key_type, attributes.bits);
+    let (algorithm, public_key) = match curve_family(key_type, bits)? {
+        None => {
+            let (modulus, exponent) = parse_rsa_public_key(data)?;
+            check_rsa_modulus(modulus, bits)?;
+            let mut algorithm = encode_tlv(TAG_OID, OID_RSA_ENCRYPTION);
+            algorithm.extend(encode_tlv(TAG_NULL, &[]));
+            (algorithm, encode_rsa_public_key(modulus, exponent))
+        }
+        Some(family) => {
+            let curve = named_curve(family, bits).ok_or_else(|| not_supported(key_type, bits))?;
+            check_point(curve, data)?;
+            let algorithm = if family == EccFamily::Montgomery {
+                encode_tlv(TAG_OID, curve.oid)
+            } else {
+                let mut algorithm = encode_tlv(TAG_OID, OID_EC_PUBLIC_KEY);
+                algorithm.extend(encode_tlv(TAG_OID, curve.oid));
+                algorithm
+            };
+            (algorithm, data.to_vec())
+        }
+    };
+    let mut content = encode_tlv(TAG_SEQUENCE, &algorithm);
+    content.extend(encode_bit_string(&public_key));
+    Ok(encode_tlv(TAG_SEQUENCE, &content))
+}
+
+/// Parse the DER encoding of SubjectPublicKeyInfo into a public key in the PSA import format
+pub fn from_spki_der(der: &[u8]) -> Result<PublicKeyData> {
+    let mut reader = DerReader::new(der);
+    let mut spki = reader.read_sequence()?;
+    reader.finish()?;
+    let mut algorithm = spki.read_sequence()?;
+    let public_key = spki.read_bit_string()?;
+    spki.finish()?;
+
+    let oid = algorithm.read_expected(TAG_OID)?;
+    if oid == OID_RSA_ENCRYPTION {
+        // The parameters must be NULL but some encoders omit them.
+        if !algorithm.is_empty() && !algorithm.read_expected(TAG_NULL)?.is_empty() {
+            return Err(invalid("Invalid RSA algorithm parameters."));
+        }
+        algorithm.finish()?;
+        let (modulus, _) = parse_rsa_public_key(public_key)?;
+        return Ok(PublicKeyData {
+            key_type: Type::RsaPublicKey,
+            bits: bit_length(modulus),
+            data: public_key.to_vec(),
+        });
+    }
+
+    let curve_oid = if oid == OID_EC_PUBLIC_KEY {
+        let curve_oid = algorithm.read_expected(TAG_OID)?;
+        algorithm.finish()?;
+        curve_oid
+    } else {
+        algorithm.finish()?;
+        oid
+    };
+    let curve = NAMED_CURVES
+        .iter()
+        .find(|curve| {
+            curve.oid == curve_oid
+                && ((oid == OID_EC_PUBLIC_KEY) != (curve.family == EccFamily::Montgomery))
+        })
+        .ok_or_else(|| {
+            error!("The algorithm or curve of the SubjectPublicKeyInfo is not supported.");
+            ResponseStatus::PsaErrorNotSupported
+        })?;
+    check_point(curve, public_key)?;
+    Ok(PublicKeyData {
+        key_type: Type::EccPublicKey {
+            curve_family: curve.family,
+        },
+        bits: curve.bits,
+        data: public_key.to_vec(),
+    })
+}
+
+const PEM_BEGIN: &str = "-----BEGIN PUBLIC KEY-----";
+const PEM_END: &str = "-----END PUBLIC KEY-----";
+
+/// Convert a public key in the PSA export format to a PEM "PUBLIC KEY" block
+pub fn to_pem(attributes: &Attributes, data: &[u8]) -> Result<String> {
+    let encoded = base64_encode(&to_spki_der(attributes, data)?, BASE64_STANDARD, true);
+    let mut pem = String::from(PEM_BEGIN);
+    pem.push('\n');
+    for line in encoded.as_bytes().chunks(64) {
+        pem.extend(line.iter().map(|&c| char::from(c)));
+        pem.push('\n');
+    }
+    pem.push_str(PEM_END);
+    pem.push('\n');
+    Ok(pem)
+}
+
+/// Parse a PEM "PUBLIC KEY" block into a public key in the PSA import format
+pub fn from_pem(pem: &str) -> Result<PublicKeyData> {
+    let start = pem
+        .find(PEM_BEGIN)
+        .ok_or_else(|| invalid("Missing PEM public key header."))?
+        + PEM_BEGIN.len();
+    let end = pem[start..]
+        .find(PEM_END)
+        .ok_or_else(|| invalid("Missing PEM public key footer."))?
+        + start;
+    let encoded: String = pem[start..end]
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect();
+    from_spki_der(&base64_decode(&encoded, BASE64_STANDARD, true)?)
+}
+
+/// Convert a public key in the PSA export format to a JSON Web Key
+///
+/// Only the curves registered for JWK are supported and ECC points must be uncompressed.
+pub fn to_jwk(attributes: &Attributes, data: &[u8]) -> Result<Jwk> {
+    let (key_type, bits) = (attributes.key_type, attributes.bits);
+    match curve_family(key_type, bits)? {
+        None => {
+            let (modulus, exponent) = parse_rsa_public_key(data)?;
+            check_rsa_modulus(modulus, bits)?;
+            Ok(Jwk {
+                kty: String::from("RSA"),
+                n: Some(base64_encode(modulus, BASE64_URL, false)),
+                e: Some(base64_encode(exponent, BASE64_URL, false)),
+                ..Default::default()
+            })
+        }
+        Some(family) => {
+            let curve = named_curve(family, bits).ok_or_else(|| not_supported(key_type, bits))?;
+            let crv = curve
+                .jwk_name
+                .ok_or_else(|| not_supported(key_type, bits))?;
+            check_point(curve, data)?;
+            if family == EccFamily::Montgomery {
+                return Ok(Jwk {
+                    kty: String::from("OKP"),
+                    crv: Some(String::from(crv)),
+                    x: Some(base64_encode(data, BASE64_URL, false)),
+                    ..Default::default()
+                });
+            }
+            if data[0] != 0x04 {
+                return Err(invalid("Only uncompressed points can be converted to JWK."));
+            }
+            let (x, y) = data[1..].split_at((data.len() - 1) / 2);
+            Ok(Jwk {
+                kty: String::from("EC"),
+                crv: Some(String::from(crv)),
+                x: Some(base64_encode(x, BASE64_URL, false)),
+                y: Some(base64_encode(y, BASE64_URL, false)),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+/// Parse a JSON Web Key into a public key in the PSA import format
+pub fn from_jwk(jwk: &Jwk) -> Result<PublicKeyData> {
+    fn member(value: &Option<String>, name: &str) -> Result<Vec<u8>> {
+        let value = value.as_ref().ok_or_else(|| {
+            error!("Missing JWK member {}.", name);
+            ResponseStatus::PsaErrorInvalidArgument
+        })?;
+        base64_decode(value, BASE64_URL, false)
+    }
+
+    if jwk.kty == "RSA" {
+        let modulus = member(&jwk.n, "n")?;
+        let data = encode_rsa_public_key(&modulus, &member(&jwk.e, "e")?);
+        return Ok(PublicKeyData {
+            key_type: Type::RsaPublicKey,
+            bits: bit_length(&modulus),
+            data,
+        });
+    }
+
+    let crv = jwk
+        .crv
+        .as_deref()
+        .ok_or_else(|| invalid("Missing JWK member crv."))?;
+    let curve = NAMED_CURVES
+        .iter()
+        .find(|curve| curve.jwk_name == Some(crv))
+        .ok_or_else(|| {
+            error!("The JWK curve {} is not supported.", crv);
+            ResponseStatus::PsaErrorNotSupported
+        })?;
+    let data = match (jwk.kty.as_str(), curve.family) {
+        ("OKP", EccFamily::Montgomery) => member(&jwk.x, "x")?,
+        ("EC", family) if family != EccFamily::Montgomery => {
+            let (x, y) = (member(&jwk.x, "x")?, member(&jwk.y, "y")?);
+            if x.len() != coordinate_len(curve) || y.len() != coordinate_len(curve) {
+                return Err(invalid("The JWK coordinates are not valid for the curve."));
+            }
+            let mut data = vec![0x04];
+            data.extend(x);
+            data.extend(y);
+            data
+        }
+        _ => {
+            error!("The JWK key type {} is not supported.", jwk.kty);
+            return Err(ResponseStatus::PsaErrorNotSupported);
+        }
+    };
+    check_point(curve, &data)?;
+    Ok(PublicKeyData {
+        key_type: Type::EccPublicKey {
+            curve_family: curve.family,
+        },
+        bits: curve.bits,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use psa_crypto::types::algorithm::Algorithm;
+    use psa_crypto::types::key::{Lifetime, Policy, UsageFlags};
+
+    fn attrs(key_type: Type, bits: usize) -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type,
+            bits,
+            policy: Policy {
+                usage_flags: UsageFlags::default(),
+                permitted_algorithms: Algorithm::None,
+            },
+        }
+    }
+
+    // Test vector from RFC 8410, section 10.1
+    const X25519_PEM: &str = "-----BEGIN PUBLIC KEY-----
+MCowBQYDK2VuAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=
+-----END PUBLIC KEY-----
+";
+    const X25519_KEY: [u8; 32] = [
+        0x19, 0xbf, 0x44, 0x09, 0x69, 0x84, 0xcd, 0xfe, 0x85, 0x41, 0xba, 0xc1, 0x67, 0xdc, 0x3b,
+        0x96, 0xc8, 0x50, 0x86, 0xaa, 0x30, 0xb6, 0xb6, 0xcb, 0x0c, 0x5c, 0x38, 0xad, 0x70, 0x31,
+        0x66, 0xe1,
+    ];
+
+    fn montgomery() -> Type {
+        Type::EccPublicKey {
+            curve_family: EccFamily::Montgomery,
+        }
+    }
+
+    fn p256_point() -> Vec<u8> {
+        let mut point = vec![0x04];
+        point.extend((0..64).map(|i| i as u8));
+        point
+    }
+
+    fn rsa_public_key() -> Vec<u8> {
+        let mut modulus = vec![0xc5; 256];
+        modulus[255] = 0x01;
+        encode_rsa_public_key(&modulus, &[0x01, 0x00, 0x01])
+    }
+
+    #[test]
+    fn x25519_rfc_8410() {
+        let attributes = attrs(montgomery(), 255);
+        assert_eq!(to_pem(&attributes, &X25519_KEY).unwrap(), X25519_PEM);
+        assert_eq!(
+            from_pem(X25519_PEM).unwrap(),
+            PublicKeyData {
+                key_type: montgomery(),
+                bits: 255,
+                data: X25519_KEY.to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn rsa_round_trips() {
+        let data = rsa_public_key();
+        let attributes = attrs(Type::RsaKeyPair, 2048);
+        let expected = PublicKeyData {
+            key_type: Type::RsaPublicKey,
+            bits: 2048,
+            data: data.clone(),
+        };
+
+        let der = to_spki_der(&attributes, &data).unwrap();
+        assert_eq!(from_spki_der(&der).unwrap(), expected);
+        assert_eq!(
+            from_pem(&to_pem(&attributes, &data).unwrap()).unwrap(),
+            expected
+        );
+        let jwk = to_jwk(&attributes, &data).unwrap();
+        assert_eq!(jwk.kty, "RSA");
+        assert_eq!(jwk.e.as_deref(), Some("AQAB"));
+        assert_eq!(from_jwk(&jwk).unwrap(), expected);
+    }
+
+    #[test]
+    fn ecc_round_trips() {
+        let data = p256_point();
+        let attributes = attrs(
+            Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            256,
+        );
+        let expected = PublicKeyData {
+            key_type: Type::EccPublicKey {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            data: data.clone(),
+        };
+
+        let der = to_spki_der(&attributes, &data).unwrap();
+        // SEQUENCE { SEQUENCE { id-ecPublicKey, prime256v1 }, BIT STRING }
+        assert_eq!(
+            &der[..27],
+            &[
+                0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06,
+                0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04
+            ]
+        );
+        assert_eq!(from_spki_der(&der).unwrap(), expected);
+        let jwk = to_jwk(&attributes, &data).unwrap();
+        assert_eq!(jwk.crv.as_deref(), Some("P-256"));
+        assert_eq!(
+            jwk.x.as_deref(),
+            Some("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8")
+        );
+        assert_eq!(from_jwk(&jwk).unwrap(), expected);
+    }
+
+    #[test]
+    fn jwk_coordinate_lengths() {
+        let point = p256_point();
+        let jwk = |x: &[u8], y: &[u8]| Jwk {
+            kty: String::from("EC"),
+            crv: Some(String::from("P-256")),
+            x: Some(base64_encode(x, BASE64_URL, false)),
+            y: Some(base64_encode(y, BASE64_URL, false)),
+            ..Default::default()
+        };
+        let _ = from_jwk(&jwk(&point[1..33], &point[33..])).unwrap();
+        for (x, y) in [(&point[1..32], &point[32..]), (&point[1..34], &point[34..])] {
+            assert_eq!(
+                from_jwk(&jwk(x, y)).unwrap_err(),
+                ResponseStatus::PsaErrorInvalidArgument
+            );
+        }
+    }
+
+    #[test]
+    fn okp_jwk() {
+        let jwk = to_jwk(&attrs(montgomery(), 255), &X25519_KEY).unwrap();
+        assert_eq!(jwk.kty, "OKP");
+        assert_eq!(jwk.crv.as_deref(), Some("X25519"));
+        assert_eq!(jwk.y, None);
+        assert_eq!(from_jwk(&jwk).unwrap().data, X25519_KEY.to_vec());
+    }
+
+    #[test]
+    fn unsupported_keys() {
+        assert_eq!(
+            to_spki_der(&attrs(Type::Aes, 128), &[0; 16]).unwrap_err(),
+            ResponseStatus::PsaErrorNotSupported
+        );
+        let sect = Type::EccPublicKey {
+            curve_family: EccFamily::SectK1,
+        };
+        assert_eq!(
+            to_spki_der(&attrs(sect, 163), &[0x04; 43]).unwrap_err(),
+            ResponseStatus::PsaErrorNotSupported
+        );
+        // P-192 has no JWK name
+        let mut point = vec![0x04];
+        point.extend(vec![0x11; 48]);
+        let p192 = attrs(
+            Type::EccPublicKey {
+                curve_family: EccFamily::SecpR1,
+            },
+            192,
+        );
+        let _ = to_spki_der(&p192, &point).unwrap();
+        assert_eq!(
+            to_jwk(&p192, &point).unwrap_err(),
+            ResponseStatus::PsaErrorNotSupported
+        );
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let p256 = attrs(
+            Type::EccPublicKey {
+                curve_family: EccFamily::SecpR1,
+            },
+            256,
+        );
+        assert_eq!(
+            to_spki_der(&p256, &p256_point()[..64]).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        assert_eq!(
+            to_spki_der(&attrs(Type::RsaPublicKey, 1024), &rsa_public_key()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        assert_eq!(
+            from_pem("-----BEGIN PUBLIC KEY-----\nM!==\n-----END PUBLIC KEY-----\n").unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        assert_eq!(
+            from_pem("no PEM here").unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        assert_eq!(
+            to_jwk(&attrs(Type::RsaPublicKey, 3072), &rsa_public_key()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        let mut der = to_spki_der(&p256, &p256_point()).unwrap();
+        der.push(0);
+        assert_eq!(
+            from_spki_der(&der).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//!
+//! # ECDSA signature format conversions
+//!
+//! The PSA Crypto API encodes ECDSA signatures as the concatenation of `r` and `s`, each of them
+//! a big-endian integer of the size of the curve. This module converts them to and from:
+//! * the DER encoding of `Ecdsa-Sig-Value`, used by X.509 and OpenSSL
+//! * the JOSE encoding, which is the raw encoding in base64url, as used by JWS (RFC 7518)
+//!
+//! The size of the raw encoding is computed from the attributes of the key.
+
+use super::utils_base64::{base64_decode, base64_encode, BASE64_URL};
+use super::utils_der::{encode_tlv, encode_unsigned_integer, DerReader, TAG_SEQUENCE};
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+use psa_crypto::types::algorithm::AsymmetricSignature;
+use psa_crypto::types::key::{Attributes, EccFamily, Type};
+use zeroize::Zeroizing;
+
+/// Size in bytes of a raw ECDSA signature made with the key
+///
+/// Returns `ResponseStatus::PsaErrorInvalidArgument` if the key is not an ECDSA key of a known
+/// size.
+pub fn ecdsa_signature_size(attributes: &Attributes) -> Result<usize> {
+    match attributes.key_type {
+        Type::EccKeyPair { curve_family } | Type::EccPublicKey { curve_family }
+            if curve_family != EccFamily::Montgomery && attributes.bits != 0 =>
+        {
+            Ok(2 * ((attributes.bits + 7) / 8))
+        }
+        key_type => {
+            error!(
+                "A {} of {} bits can not be used for ECDSA signatures.",
+                key_type, attributes.bits
+            );
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        }
+    }
+}
+
+fn check_raw_size(attributes: &Attributes, raw: &[u8]) -> Result<usize> {
+    let size = ecdsa_signature_size(attributes)?;
+    if raw.len() != size {
+        error!(
+            "The raw ECDSA signature is {} bytes long instead of {}.",
+            raw.len(),
+            size
+        );
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+    Ok(size)
+}
+
+/// Convert a raw `r||s` ECDSA signature to the DER encoding of `Ecdsa-Sig-Value`
+pub fn ecdsa_raw_to_der(attributes: &Attributes, raw: &[u8]) -> Result<Vec<u8>> {
+    let size = check_raw_size(attributes, raw)?;
+    let (r, s) = raw.split_at(size / 2);
+    let mut content = encode_unsigned_integer(r);
+    content.extend(encode_unsigned_integer(s));
+    Ok(encode_tlv(TAG_SEQUENCE, &content))
+}
+
+/// Convert the DER encoding of `Ecdsa-Sig-Value` to a raw `r||s` ECDSA signature
+///
+/// `Ecdsa-Sig-Value ::= SEQUENCE { r INTEGER, s INTEGER }`
+pub fn ecdsa_der_to_raw(attributes: &Attributes, der: &[u8]) -> Result<Vec<u8>> {
+    let size = ecdsa_signature_size(attributes)?;
+    let mut reader = DerReader::new(der);
+    let mut signature = reader.read_sequence()?;
+    reader.finish()?;
+    let r = signature.read_unsigned_integer()?;
+    let s = signature.read_unsigned_integer()?;
+    signature.finish()?;
+
+    let mut raw = vec![0; size];
+    for (value, half) in [r, s].iter().zip(raw.chunks_mut(size / 2)) {
+        if value.len() > half.len() {
+            error!("An ECDSA signature component is larger than the curve size.");
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        let offset = half.len() - value.len();
+        half[offset..].copy_from_slice(value);
+    }
+    Ok(raw)
+}
+
+/// Convert a raw `r||s` ECDSA signature to the JOSE encoding
+pub fn ecdsa_raw_to_jose(attributes: &Attributes, raw: &[u8]) -> Result<String> {
+    let _ = check_raw_size(attributes, raw)?;
+    Ok(base64_encode(raw, BASE64_URL, false))
+}
+
+/// Convert a JOSE encoded ECDSA signature to the raw `r||s` encoding
+pub fn ecdsa_jose_to_raw(attributes: &Attributes, jose: &str) -> Result<Vec<u8>> {
+    let raw = base64_decode(jose, BASE64_URL, false)?;
+    let _ = check_raw_size(attributes, &raw)?;
+    Ok(raw)
+}
+
+/// Convert, in place, a DER encoded ECDSA signature to the raw encoding
+///
+/// The signature is always parsed as the DER encoding of `Ecdsa-Sig-Value` and
+/// `ResponseStatus::PsaErrorInvalidArgument` is returned if it is not, including when it is
+/// already in the raw encoding. Signatures of algorithms other than ECDSA are left unchanged.
+pub(crate) fn normalize_der_signature(
+    alg: AsymmetricSignature,
+    attributes: &Attributes,
+    signature: &mut Zeroizing<Vec<u8>>,
+) -> Result<()> {
+    if !alg.is_ecc_alg() {
+        return Ok(());
+    }
+    *signature = Zeroizing::new(ecdsa_der_to_raw(attributes, signature)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use psa_crypto::types::algorithm::{Algorithm, Hash};
+    use psa_crypto::types::key::{Lifetime, Policy, UsageFlags};
+
+    fn attrs(key_type: Type, bits: usize) -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type,
+            bits,
+            policy: Policy {
+                usage_flags: UsageFlags::default(),
+                permitted_algorithms: Algorithm::None,
+            },
+        }
+    }
+
+    fn p256() -> Attributes {
+        attrs(
+            Type::EccPublicKey {
+                curve_family: EccFamily::SecpR1,
+            },
+            256,
+        )
+    }
+
+    #[test]
+    fn signature_sizes() {
+        assert_eq!(ecdsa_signature_size(&p256()).unwrap(), 64);
+        let p521 = attrs(
+            Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            521,
+        );
+        assert_eq!(ecdsa_signature_size(&p521).unwrap(), 132);
+        let invalid = vec![
+            attrs(Type::RsaKeyPair, 2048),
+            attrs(
+                Type::EccKeyPair {
+                    curve_family: EccFamily::Montgomery,
+                },
+                255,
+            ),
+            attrs(
+                Type::EccKeyPair {
+                    curve_family: EccFamily::SecpR1,
+                },
+                0,
+            ),
+        ];
+        for attributes in invalid {
+            assert_eq!(
+                ecdsa_signature_size(&attributes).unwrap_err(),
+                ResponseStatus::PsaErrorInvalidArgument
+            );
+        }
+    }
+
+    #[test]
+    fn der_round_trip() {
+        let mut raw = vec![0x80; 32];
+        raw.extend(vec![0x01; 32]);
+        let der = ecdsa_raw_to_der(&p256(), &raw).unwrap();
+        // r needs a leading zero, s does not
+        assert_eq!(&der[..5], &[0x30, 0x45, 0x02, 0x21, 0x00]);
+        assert_eq!(der.len(), 0x47);
+        assert_eq!(ecdsa_der_to_raw(&p256(), &der).unwrap(), raw);
+    }
+
+    #[test]
+    fn der_short_components() {
+        // Components with leading zeros are shorter in DER
+        let mut raw = vec![0x00; 30];
+        raw.extend(vec![0x11; 2]);
+        raw.extend(vec![0x00; 31]);
+        raw.push(0x7f);
+        let der = ecdsa_raw_to_der(&p256(), &raw).unwrap();
+        assert_eq!(
+            der,
+            vec![0x30, 0x07, 0x02, 0x02, 0x11, 0x11, 0x02, 0x01, 0x7f]
+        );
+        assert_eq!(ecdsa_der_to_raw(&p256(), &der).unwrap(), raw);
+    }
+
+    #[test]
+    fn der_invalid() {
+        let mut too_large = vec![0x30, 0x26, 0x02, 0x21];
+        too_large.extend(vec![0x11; 33]);
+        too_large.extend(vec![0x02, 0x01, 0x01]);
+        let invalid = vec![too_large, vec![0x30, 0x03, 0x02, 0x01, 0x01], vec![]];
+        for der in invalid {
+            assert_eq!(
+                ecdsa_der_to_raw(&p256(), &der).unwrap_err(),
+                ResponseStatus::PsaErrorInvalidArgument
+            );
+        }
+        assert_eq!(
+            ecdsa_raw_to_der(&p256(), &[0x11; 63]).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn jose_round_trip() {
+        let raw: Vec<u8> = (0..64).collect();
+        let jose = ecdsa_raw_to_jose(&p256(), &raw).unwrap();
+        assert_eq!(jose.len(), 86);
+        assert!(!jose.contains('='));
+        assert_eq!(ecdsa_jose_to_raw(&p256(), &jose).unwrap(), raw);
+        assert_eq!(
+            ecdsa_jose_to_raw(&p256(), "AAEC").unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn normalize_der() {
+        let ecdsa = AsymmetricSignature::Ecdsa {
+            hash_alg: Hash::Sha256.into(),
+        };
+        let mut raw = vec![0x00; 31];
+        raw.push(0x42);
+        raw.extend(vec![0x81; 32]);
+        let mut signature = Zeroizing::new(ecdsa_raw_to_der(&p256(), &raw).unwrap());
+        normalize_der_signature(ecdsa, &p256(), &mut signature).unwrap();
+        assert_eq!(*signature, raw);
+        // The signature is always parsed as DER, even if it has the size of a raw signature
+        assert_eq!(
+            normalize_der_signature(ecdsa, &p256(), &mut signature).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        let mut der_of_raw_size = Zeroizing::new(vec![0x30; 64]);
+        assert_eq!(
+            normalize_der_signature(ecdsa, &p256(), &mut der_of_raw_size).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn normalize_invalid_der() {
+        let mut signature = Zeroizing::new(vec![0x30; 70]);
+        assert_eq!(
+            normalize_der_signature(
+                AsymmetricSignature::DeterministicEcdsa {
+                    hash_alg: Hash::Sha256.into(),
+                },
+                &p256(),
+                &mut signature
+            )
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn normalize_other_algorithms() {
+        let mut signature = Zeroizing::new(vec![0x30; 70]);
+        normalize_der_signature(
+            AsymmetricSignature::RsaPss {
+                hash_alg: Hash::Sha256.into(),
+            },
+            &attrs(Type::RsaPublicKey, 2048),
+            &mut signature,
+        )
+        .unwrap();
+        assert_eq!(*signature, vec![0x30; 70]);
+    }
+}
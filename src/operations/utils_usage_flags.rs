@@ -0,0 +1,20 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Usage flags helpers
+
+use super::psa_key_attributes::UsageFlags;
+
+/// Check that all the usages of `required` are also set in `flags`
+pub(crate) fn usage_flags_contain(flags: UsageFlags, required: UsageFlags) -> bool {
+    let implies = |set: bool, required: bool| set || !required;
+    implies(flags.export(), required.export())
+        && implies(flags.copy(), required.copy())
+        && implies(flags.cache(), required.cache())
+        && implies(flags.encrypt(), required.encrypt())
+        && implies(flags.decrypt(), required.decrypt())
+        && implies(flags.sign_message(), required.sign_message())
+        && implies(flags.verify_message(), required.verify_message())
+        && implies(flags.sign_hash(), required.sign_hash())
+        && implies(flags.verify_hash(), required.verify_hash())
+        && implies(flags.derive(), required.derive())
+}
@@ -1,6 +1,6 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::attest_key::{
+use super::generated_ops::attest_key_ext::{
     attestation_mechanism_params, attestation_output, AttestationMechanismParams,
     AttestationOutput, Operation as OperationProto, Result as ResultProto,
 };
@@ -31,6 +31,21 @@ impl TryFrom<Operation> for OperationProto {
                     )),
                 }),
             }),
+            Operation::Certify {
+                attested_key_name,
+                nonce,
+                attesting_key_name,
+            } => Ok(OperationProto {
+                attested_key_name,
+                attesting_key_name: attesting_key_name.unwrap_or_default(),
+                parameters: Some(AttestationMechanismParams {
+                    mechanism: Some(attestation_mechanism_params::Mechanism::Certify(
+                        attestation_mechanism_params::Certify {
+                            nonce: nonce.to_vec(),
+                        },
+                    )),
+                }),
+            }),
         }
     }
 }
@@ -39,6 +54,11 @@ impl TryFrom<OperationProto> for Operation {
     type Error = ResponseStatus;
 
     fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let attesting_key_name = if proto_op.attesting_key_name.is_empty() {
+            None
+        } else {
+            Some(proto_op.attesting_key_name)
+        };
         match proto_op.parameters {
             Some(AttestationMechanismParams {
                 mechanism:
@@ -50,14 +70,20 @@ impl TryFrom<OperationProto> for Operation {
                     )),
             }) => Ok(Operation::ActivateCredential {
                 attested_key_name: proto_op.attested_key_name,
-                attesting_key_name: if proto_op.attesting_key_name.is_empty() {
-                    None
-                } else {
-                    Some(proto_op.attesting_key_name)
-                },
+                attesting_key_name,
                 credential_blob: credential_blob.into(),
                 secret: secret.into(),
             }),
+            Some(AttestationMechanismParams {
+                mechanism:
+                    Some(attestation_mechanism_params::Mechanism::Certify(
+                        attestation_mechanism_params::Certify { nonce },
+                    )),
+            }) => Ok(Operation::Certify {
+                attested_key_name: proto_op.attested_key_name,
+                nonce: nonce.into(),
+                attesting_key_name,
+            }),
             _ => {
                 error!("The encoding of the operation does not follow the expected pattern");
                 Err(ResponseStatus::InvalidEncoding)
@@ -80,6 +106,19 @@ impl TryFrom<Result> for ResultProto {
                     )),
                 }),
             }),
+            Result::Certify {
+                attestation_data,
+                signature,
+            } => Ok(ResultProto {
+                output: Some(AttestationOutput {
+                    mechanism: Some(attestation_output::Mechanism::Certify(
+                        attestation_output::Certify {
+                            attestation_data: attestation_data.to_vec(),
+                            signature: signature.to_vec(),
+                        },
+                    )),
+                }),
+            }),
         }
     }
 }
@@ -100,6 +139,19 @@ impl TryFrom<ResultProto> for Result {
             } => Ok(Result::ActivateCredential {
                 credential: credential.into(),
             }),
+            ResultProto {
+                output:
+                    Some(AttestationOutput {
+                        mechanism:
+                            Some(attestation_output::Mechanism::Certify(attestation_output::Certify {
+                                attestation_data,
+                                signature,
+                            })),
+                    }),
+            } => Ok(Result::Certify {
+                attestation_data: attestation_data.into(),
+                signature: signature.into(),
+            }),
             _ => {
                 error!("The encoding of the result does not follow the expected pattern");
                 Err(ResponseStatus::InvalidEncoding)
@@ -110,7 +162,7 @@ impl TryFrom<ResultProto> for Result {
 
 #[cfg(test)]
 mod test {
-    use super::super::generated_ops::attest_key::{
+    use super::super::generated_ops::attest_key_ext::{
         attestation_mechanism_params, attestation_output, AttestationMechanismParams,
         AttestationOutput, Operation as OperationProto, Result as ResultProto,
     };
@@ -144,12 +196,20 @@ mod test {
         };
 
         let op: Operation = proto.try_into().expect("Failed conversion");
-        let Operation::ActivateCredential {
-            attested_key_name,
-            attesting_key_name,
-            credential_blob,
-            secret,
-        } = op;
+        let (attested_key_name, attesting_key_name, credential_blob, secret) = match op {
+            Operation::ActivateCredential {
+                attested_key_name,
+                attesting_key_name,
+                credential_blob,
+                secret,
+            } => (
+                attested_key_name,
+                attesting_key_name,
+                credential_blob,
+                secret,
+            ),
+            _ => panic!("Expected ActivateCredential"),
+        };
         assert_eq!(attested_key_name, op_attested_key_name);
         assert_eq!(
             attesting_key_name.unwrap_or_default(),
@@ -179,9 +239,12 @@ mod test {
         };
 
         let op: Operation = proto.try_into().expect("Failed conversion");
-        let Operation::ActivateCredential {
-            attesting_key_name, ..
-        } = op;
+        let attesting_key_name = match op {
+            Operation::ActivateCredential {
+                attesting_key_name, ..
+            } => attesting_key_name,
+            _ => panic!("Expected ActivateCredential"),
+        };
         assert!(attesting_key_name.is_none());
     }
 
@@ -278,7 +341,10 @@ mod test {
 
         let resp: Result = proto.try_into().expect("Failed conversion");
 
-        let Result::ActivateCredential { credential } = resp;
+        let credential = match resp {
+            Result::ActivateCredential { credential } => credential,
+            _ => panic!("Expected ActivateCredential"),
+        };
 
         assert_eq!(credential.to_vec(), resp_credential);
     }
@@ -323,4 +389,72 @@ mod test {
             .body_to_result(body, Opcode::AttestKey)
             .expect("Failed to convert to operation");
     }
+
+    #[test]
+    fn attest_key_certify_op_e2e() {
+        let op = Operation::Certify {
+            attested_key_name: String::from("attested key name"),
+            nonce: vec![0x33; 16].into(),
+            attesting_key_name: None,
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed conversion");
+        match &proto.parameters {
+            Some(AttestationMechanismParams {
+                mechanism:
+                    Some(attestation_mechanism_params::Mechanism::Certify(
+                        attestation_mechanism_params::Certify { nonce },
+                    )),
+            }) => assert_eq!(nonce, &vec![0x33; 16]),
+            _ => panic!("Expected Certify"),
+        }
+        assert_eq!(proto.attesting_key_name, String::new());
+
+        let op: Operation = proto.try_into().expect("Failed conversion");
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::AttestKey(op))
+            .expect("Failed to convert to body");
+
+        match CONVERTER
+            .body_to_operation(body, Opcode::AttestKey)
+            .expect("Failed to convert to operation")
+        {
+            NativeOperation::AttestKey(Operation::Certify {
+                attested_key_name,
+                nonce,
+                attesting_key_name,
+            }) => {
+                assert_eq!(attested_key_name, "attested key name");
+                assert_eq!(nonce.to_vec(), vec![0x33; 16]);
+                assert!(attesting_key_name.is_none());
+            }
+            _ => panic!("Expected Certify"),
+        }
+    }
+
+    #[test]
+    fn attest_key_certify_resp_e2e() {
+        let resp = Result::Certify {
+            attestation_data: vec![0xcc; 64].into(),
+            signature: vec![0xdd; 72].into(),
+        };
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::AttestKey(resp))
+            .expect("Failed to convert to body");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::AttestKey)
+            .expect("Failed to convert to result")
+        {
+            NativeResult::AttestKey(Result::Certify {
+                attestation_data,
+                signature,
+            }) => {
+                assert_eq!(attestation_data.to_vec(), vec![0xcc; 64]);
+                assert_eq!(signature.to_vec(), vec![0xdd; 72]);
+            }
+            _ => panic!("Expected Certify"),
+        }
+    }
 }
@@ -0,0 +1,446 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! The bodies of the operations and results of a batch are converted with the converter of the
+//! batch itself, which is why the conversions are functions taking it instead of `TryFrom`
+//! implementations.
+use super::convert_can_do_crypto_batch::u32_to_response_status;
+use super::generated_ops::batch::{
+    Operation as OperationProto, OperationEntry, Result as ResultProto, ResultEntry,
+};
+use super::generated_ops::ClearProtoMessage;
+use crate::operations::batch::{check_batchable, Operation, Result};
+use crate::operations::Convert;
+use crate::requests::request::RequestBody;
+use crate::requests::response::ResponseBody;
+use crate::requests::{Opcode, ResponseStatus};
+use log::error;
+use num::FromPrimitive;
+use prost::Message;
+
+fn u32_to_opcode(opcode: u32) -> std::result::Result<Opcode, ResponseStatus> {
+    match FromPrimitive::from_u32(opcode) {
+        Some(opcode) => {
+            check_batchable(opcode)?;
+            Ok(opcode)
+        }
+        None => {
+            error!("Invalid opcode in batch: {}", opcode);
+            Err(ResponseStatus::OpcodeDoesNotExist)
+        }
+    }
+}
+
+fn decode_entries<T: Message + Default + ClearProtoMessage, U>(
+    body: &[u8],
+    convert: impl FnOnce(&mut T) -> std::result::Result<U, ResponseStatus>,
+) -> std::result::Result<U, ResponseStatus> {
+    let mut proto = T::default();
+    if proto.merge(body).is_err() {
+        return Err(ResponseStatus::DeserializingBodyFailed);
+    }
+    let native = convert(&mut proto);
+    proto.clear_message();
+    native
+}
+
+fn encode_entries<T: Message + ClearProtoMessage>(
+    mut proto: T,
+) -> std::result::Result<Vec<u8>, ResponseStatus> {
+    let mut bytes = Vec::new();
+    let encoded = proto.encode(&mut bytes);
+    proto.clear_message();
+    match encoded {
+        Ok(()) => Ok(bytes),
+        Err(_) => Err(ResponseStatus::SerializingBodyFailed),
+    }
+}
+
+/// Raw opcodes of the operations of a batch request body, without decoding their bodies.
+pub(crate) fn body_to_opcodes(body: &[u8]) -> std::result::Result<Vec<u32>, ResponseStatus> {
+    decode_entries(body, |proto: &mut OperationProto| {
+        Ok(proto.operations.iter().map(|entry| entry.opcode).collect())
+    })
+}
+
+/// Raw opcodes and body lengths of the operations of a batch request body, without decoding their
+/// bodies.
+pub(crate) fn body_to_entry_lens(
+    body: &[u8],
+) -> std::result::Result<Vec<(u32, usize)>, ResponseStatus> {
+    decode_entries(body, |proto: &mut OperationProto| {
+        Ok(proto
+            .operations
+            .iter()
+            .map(|entry| (entry.opcode, entry.body.len()))
+            .collect())
+    })
+}
+
+/// Create a native batch operation from a request body.
+pub(super) fn body_to_operation(
+    converter: &impl Convert,
+    body: &[u8],
+) -> std::result::Result<Operation, ResponseStatus> {
+    decode_entries(body, |proto: &mut OperationProto| {
+        let operations = proto
+            .operations
+            .iter_mut()
+            .map(|entry| {
+                converter.body_to_operation(
+                    RequestBody::from_bytes(std::mem::take(&mut entry.body)),
+                    u32_to_opcode(entry.opcode)?,
+                )
+            })
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Operation {
+            continue_on_error: proto.continue_on_error,
+            operations,
+        })
+    })
+}
+
+/// Create a request body from a native batch operation.
+pub(super) fn operation_to_body(
+    converter: &impl Convert,
+    operation: Operation,
+) -> std::result::Result<Vec<u8>, ResponseStatus> {
+    operation.validate()?;
+    let mut proto = OperationProto {
+        continue_on_error: operation.continue_on_error,
+        operations: Vec::with_capacity(operation.operations.len()),
+    };
+    for operation in operation.operations {
+        let opcode = operation.opcode() as u32;
+        match converter.operation_to_body(operation) {
+            Ok(body) => proto.operations.push(OperationEntry {
+                opcode,
+                body: body.bytes().to_vec(),
+            }),
+            Err(status) => {
+                proto.clear_message();
+                return Err(status);
+            }
+        }
+    }
+    encode_entries(proto)
+}
+
+/// Create a native batch result from a response body.
+///
+/// The body of a result is only converted if its status is `ResponseStatus::Success`.
+pub(super) fn body_to_result(
+    converter: &impl Convert,
+    body: &[u8],
+) -> std::result::Result<Result, ResponseStatus> {
+    decode_entries(body, |proto: &mut ResultProto| {
+        let results = proto
+            .results
+            .iter_mut()
+            .map(|entry| match u32_to_response_status(entry.status)? {
+                ResponseStatus::Success => Ok(Ok(converter.body_to_result(
+                    ResponseBody::from_bytes(std::mem::take(&mut entry.body)),
+                    u32_to_opcode(entry.opcode)?,
+                )?)),
+                status => Ok(Err(status)),
+            })
+            .collect::<std::result::Result<_, ResponseStatus>>()?;
+
+        Ok(Result { results })
+    })
+}
+
+/// Create a response body from a native batch result.
+///
+/// Failed results are encoded with their status only.
+pub(super) fn result_to_body(
+    converter: &impl Convert,
+    result: Result,
+) -> std::result::Result<Vec<u8>, ResponseStatus> {
+    let mut proto = ResultProto {
+        results: Vec::with_capacity(result.results.len()),
+    };
+    for result in result.results {
+        let entry = match result {
+            Ok(result) => {
+                let opcode = result.opcode() as u32;
+                match converter.result_to_body(result) {
+                    Ok(body) => ResultEntry {
+                        opcode,
+                        status: ResponseStatus::Success as u32,
+                        body: body.bytes().to_vec(),
+                    },
+                    Err(status) => {
+                        proto.clear_message();
+                        return Err(status);
+                    }
+                }
+            }
+            Err(status) => ResultEntry {
+                opcode: 0,
+                status: status as u32,
+                body: Vec::new(),
+            },
+        };
+        proto.results.push(entry);
+    }
+    encode_entries(proto)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::batch::{
+        Operation as OperationProto, OperationEntry, Result as ResultProto, ResultEntry,
+    };
+    use super::super::ProtobufConverter;
+    use crate::operations::psa_algorithm::Hash;
+    use crate::operations::{
+        batch, ping, psa_generate_random, psa_hash_compute, Convert, NativeOperation, NativeResult,
+    };
+    use crate::requests::request::RequestBody;
+    use crate::requests::response::ResponseBody;
+    use crate::requests::{Opcode, ResponseStatus};
+    use prost::Message;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_operation() -> batch::Operation {
+        batch::Operation {
+            continue_on_error: true,
+            operations: vec![
+                NativeOperation::PsaGenerateRandom(psa_generate_random::Operation { size: 32 }),
+                NativeOperation::PsaHashCompute(psa_hash_compute::Operation {
+                    alg: Hash::Sha256,
+                    input: vec![0x11; 16].into(),
+                }),
+            ],
+        }
+    }
+
+    fn encode(proto: impl Message) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        proto.encode(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn op_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::Batch(get_operation()))
+            .expect("Failed to convert request");
+
+        let proto = OperationProto::decode(body.bytes()).unwrap();
+        assert!(proto.continue_on_error);
+        assert_eq!(proto.operations.len(), 2);
+        assert_eq!(proto.operations[0].opcode, Opcode::PsaGenerateRandom as u32);
+        assert_eq!(proto.operations[1].opcode, Opcode::PsaHashCompute as u32);
+
+        match CONVERTER
+            .body_to_operation(body, Opcode::Batch)
+            .expect("Failed to convert back to operation")
+        {
+            NativeOperation::Batch(op) => {
+                assert!(op.continue_on_error);
+                match &op.operations[..] {
+                    [NativeOperation::PsaGenerateRandom(random), NativeOperation::PsaHashCompute(hash)] =>
+                    {
+                        assert_eq!(random.size, 32);
+                        assert_eq!(hash.alg, Hash::Sha256);
+                        assert_eq!(*hash.input, vec![0x11; 16]);
+                    }
+                    operations => panic!("Unexpected operations: {:?}", operations),
+                }
+            }
+            _ => panic!("Expected Batch"),
+        }
+    }
+
+    #[test]
+    fn result_e2e() {
+        let result = batch::Result {
+            results: vec![
+                Ok(NativeResult::PsaGenerateRandom(
+                    psa_generate_random::Result {
+                        random_bytes: vec![0x42; 32].into(),
+                    },
+                )),
+                Err(ResponseStatus::PsaErrorNotSupported),
+            ],
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::Batch(result))
+            .expect("Failed to convert response");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::Batch)
+            .expect("Failed to convert back to result")
+        {
+            NativeResult::Batch(result) => {
+                assert_eq!(
+                    result.first_error(),
+                    Some(ResponseStatus::PsaErrorNotSupported)
+                );
+                match &result.results[..] {
+                    [Ok(NativeResult::PsaGenerateRandom(random)), Err(_)] => {
+                        assert_eq!(*random.random_bytes, vec![0x42; 32])
+                    }
+                    results => panic!("Unexpected results: {:?}", results),
+                }
+            }
+            _ => panic!("Expected Batch"),
+        }
+    }
+
+    #[test]
+    fn failed_result_body_is_ignored() {
+        let proto = ResultProto {
+            results: vec![ResultEntry {
+                opcode: Opcode::PsaSignHash as u32,
+                status: ResponseStatus::PsaErrorInvalidSignature as u32,
+                body: vec![0xff; 4],
+            }],
+        };
+        let body = ResponseBody::from_bytes(encode(proto));
+
+        match CONVERTER.body_to_result(body, Opcode::Batch).unwrap() {
+            NativeResult::Batch(result) => assert_eq!(
+                result.first_error(),
+                Some(ResponseStatus::PsaErrorInvalidSignature)
+            ),
+            _ => panic!("Expected Batch"),
+        }
+    }
+
+    #[test]
+    fn nested_batch() {
+        let mut op = get_operation();
+        op.operations.push(NativeOperation::Batch(batch::Operation {
+            continue_on_error: false,
+            operations: vec![NativeOperation::Ping(ping::Operation)],
+        }));
+        assert_eq!(
+            CONVERTER
+                .operation_to_body(NativeOperation::Batch(op))
+                .unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+
+        let proto = OperationProto {
+            continue_on_error: false,
+            operations: vec![OperationEntry {
+                opcode: Opcode::Batch as u32,
+                body: Vec::new(),
+            }],
+        };
+        assert_eq!(
+            CONVERTER
+                .body_to_operation(RequestBody::from_bytes(encode(proto)), Opcode::Batch)
+                .unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn core_operations() {
+        let mut op = get_operation();
+        op.operations.push(NativeOperation::Ping(ping::Operation));
+        assert_eq!(
+            CONVERTER
+                .operation_to_body(NativeOperation::Batch(op))
+                .unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+
+        for opcode in [
+            Opcode::ListClients,
+            Opcode::DeleteClient,
+            Opcode::ListAllKeys,
+        ]
+        .iter()
+        {
+            let proto = OperationProto {
+                continue_on_error: false,
+                operations: vec![OperationEntry {
+                    opcode: *opcode as u32,
+                    body: Vec::new(),
+                }],
+            };
+            assert_eq!(
+                CONVERTER
+                    .body_to_operation(RequestBody::from_bytes(encode(proto)), Opcode::Batch)
+                    .unwrap_err(),
+                ResponseStatus::InvalidEncoding
+            );
+        }
+    }
+
+    #[test]
+    fn opcodes() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::Batch(get_operation()))
+            .unwrap();
+        assert_eq!(
+            super::body_to_opcodes(body.bytes()).unwrap(),
+            vec![
+                Opcode::PsaGenerateRandom as u32,
+                Opcode::PsaHashCompute as u32
+            ]
+        );
+        assert_eq!(
+            super::body_to_opcodes(&[0xff; 4]).unwrap_err(),
+            ResponseStatus::DeserializingBodyFailed
+        );
+        assert_eq!(
+            super::body_to_entry_lens(body.bytes()).unwrap(),
+            vec![
+                (Opcode::PsaGenerateRandom as u32, 2),
+                (Opcode::PsaHashCompute as u32, 20)
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_entries() {
+        let proto = OperationProto {
+            continue_on_error: false,
+            operations: vec![OperationEntry {
+                opcode: 0xffff,
+                body: Vec::new(),
+            }],
+        };
+        assert_eq!(
+            CONVERTER
+                .body_to_operation(RequestBody::from_bytes(encode(proto)), Opcode::Batch)
+                .unwrap_err(),
+            ResponseStatus::OpcodeDoesNotExist
+        );
+
+        let proto = OperationProto {
+            continue_on_error: false,
+            operations: vec![OperationEntry {
+                opcode: Opcode::PsaGenerateRandom as u32,
+                body: vec![0xff; 4],
+            }],
+        };
+        assert_eq!(
+            CONVERTER
+                .body_to_operation(RequestBody::from_bytes(encode(proto)), Opcode::Batch)
+                .unwrap_err(),
+            ResponseStatus::DeserializingBodyFailed
+        );
+
+        let proto = ResultProto {
+            results: vec![ResultEntry {
+                opcode: 0,
+                status: 0x1_0000,
+                body: Vec::new(),
+            }],
+        };
+        assert_eq!(
+            CONVERTER
+                .body_to_result(ResponseBody::from_bytes(encode(proto)), Opcode::Batch)
+                .unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::can_do_crypto_batch::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::can_do_crypto_batch::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let checks = proto_op
+            .checks
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Operation { checks })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        let checks = op
+            .checks
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(OperationProto { checks })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let statuses = proto_result
+            .statuses
+            .into_iter()
+            .map(u32_to_response_status)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Result { statuses })
+    }
+}
+
+/// Convert a status from its protobuf representation
+pub(super) fn u32_to_response_status(
+    status: u32,
+) -> std::result::Result<ResponseStatus, ResponseStatus> {
+    let status = u16::try_from(status).map_err(|_| {
+        error!(
+            "Value {} does not correspond to a valid ResponseStatus.",
+            status
+        );
+        ResponseStatus::InvalidEncoding
+    })?;
+    ResponseStatus::try_from(status)
+}
+
+impl From<Result> for ResultProto {
+    fn from(result: Result) -> Self {
+        ResultProto {
+            statuses: result
+                .statuses
+                .into_iter()
+                .map(|status| status as u32)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::convert_can_do_crypto::check_type_to_i32;
+    use super::super::generated_ops::can_do_crypto_batch::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::can_do_crypto::{self, CheckType};
+    use crate::operations::can_do_crypto_batch::{Operation, Result};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{self, Attributes, Lifetime, Policy, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{Opcode, ResponseStatus};
+    use std::convert::{TryFrom, TryInto};
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_check(check_type: CheckType, bits: usize) -> can_do_crypto::Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash().set_verify_hash();
+        can_do_crypto::Operation {
+            check_type,
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: psa_key_attributes::Type::RsaKeyPair,
+                bits,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::RsaPkcs1v15Sign {
+                            hash_alg: Hash::Sha256.into(),
+                        },
+                    ),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn op_to_proto_to_op() {
+        let op = Operation {
+            checks: vec![
+                get_check(CheckType::Use, 2048),
+                get_check(CheckType::Generate, 4096),
+            ],
+        };
+
+        let proto: OperationProto = op.try_into().unwrap();
+        assert_eq!(proto.checks.len(), 2);
+        assert_eq!(
+            proto.checks[1].check_type,
+            check_type_to_i32(CheckType::Generate)
+        );
+
+        let op: Operation = proto.try_into().unwrap();
+        assert_eq!(op.checks.len(), 2);
+        assert_eq!(op.checks[0].check_type, CheckType::Use);
+        assert_eq!(
+            op.checks[0].attributes,
+            get_check(CheckType::Use, 2048).attributes
+        );
+        assert_eq!(op.checks[1].check_type, CheckType::Generate);
+        assert_eq!(op.checks[1].attributes.bits, 4096);
+    }
+
+    #[test]
+    fn proto_invalid_check() {
+        let mut proto: OperationProto = Operation {
+            checks: vec![get_check(CheckType::Use, 2048)],
+        }
+        .try_into()
+        .unwrap();
+        proto.checks[0].attributes = None;
+
+        assert_eq!(
+            Operation::try_from(proto).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn result_to_proto_to_result() {
+        let result = Result {
+            statuses: vec![
+                ResponseStatus::Success,
+                ResponseStatus::PsaErrorNotSupported,
+            ],
+        };
+        assert!(!result.all_supported());
+
+        let proto: ResultProto = result.clone().into();
+        assert_eq!(proto.statuses, vec![0, 1134]);
+        assert_eq!(Result::try_from(proto).unwrap(), result);
+    }
+
+    #[test]
+    fn proto_invalid_status() {
+        for status in [0xffff, 0x1_0000].iter() {
+            let proto = ResultProto {
+                statuses: vec![0, *status],
+            };
+            assert_eq!(
+                Result::try_from(proto).unwrap_err(),
+                ResponseStatus::InvalidEncoding
+            );
+        }
+    }
+
+    #[test]
+    fn op_e2e() {
+        let op = Operation {
+            checks: vec![
+                get_check(CheckType::Import, 1024),
+                get_check(CheckType::Use, 0),
+            ],
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::CanDoCryptoBatch(op))
+            .expect("Failed to convert request");
+
+        match CONVERTER
+            .body_to_operation(body, Opcode::CanDoCryptoBatch)
+            .expect("Failed to convert back to operation")
+        {
+            NativeOperation::CanDoCryptoBatch(op) => {
+                assert_eq!(op.checks.len(), 2);
+                assert_eq!(op.checks[0].check_type, CheckType::Import);
+                assert_eq!(op.checks[1].attributes.bits, 0);
+            }
+            _ => panic!("Expected CanDoCryptoBatch"),
+        }
+    }
+
+    #[test]
+    fn result_e2e() {
+        let result = Result {
+            statuses: vec![ResponseStatus::Success, ResponseStatus::Success],
+        };
+        assert!(result.all_supported());
+        let body = CONVERTER
+            .result_to_body(NativeResult::CanDoCryptoBatch(result.clone()))
+            .expect("Failed to convert response");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::CanDoCryptoBatch)
+            .expect("Failed to convert back to result")
+        {
+            NativeResult::CanDoCryptoBatch(converted) => assert_eq!(converted, result),
+            _ => panic!("Expected CanDoCryptoBatch"),
+        }
+    }
+}
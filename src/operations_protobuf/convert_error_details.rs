@@ -0,0 +1,60 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::error_details::ErrorDetails as ErrorDetailsProto;
+use crate::requests::response::ErrorDetails;
+
+impl From<ErrorDetailsProto> for ErrorDetails {
+    fn from(proto_details: ErrorDetailsProto) -> Self {
+        ErrorDetails {
+            message: proto_details.message,
+            field_path: proto_details.field_path,
+            provider_code: proto_details.provider_code,
+        }
+    }
+}
+
+impl From<ErrorDetails> for ErrorDetailsProto {
+    fn from(details: ErrorDetails) -> Self {
+        ErrorDetailsProto {
+            message: details.message,
+            field_path: details.field_path,
+            provider_code: details.provider_code,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Convert, ProtobufConverter};
+    use crate::requests::response::{ErrorDetails, ResponseBody};
+    use crate::requests::ResponseStatus;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn error_details_e2e() {
+        let details = ErrorDetails {
+            message: String::from("Hash length not permitted by the algorithm"),
+            field_path: Some(String::from("hash")),
+            provider_code: Some(-135),
+        };
+        let body = CONVERTER
+            .error_details_to_body(details.clone())
+            .expect("Failed to convert error details");
+        assert_eq!(
+            CONVERTER
+                .body_to_error_details(body)
+                .expect("Failed to convert back to error details"),
+            details
+        );
+    }
+
+    #[test]
+    fn mangled_error_details() {
+        let body = ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert_eq!(
+            CONVERTER.body_to_error_details(body).unwrap_err(),
+            ResponseStatus::DeserializingBodyFailed
+        );
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::list_all_keys::{
+    ClientKeyInfo as ClientKeyInfoProto, Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::list_all_keys::{ClientKeyInfo, Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use num::FromPrimitive;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let provider_id = match proto_op.provider_id {
+            Some(provider_id) => match FromPrimitive::from_u32(provider_id) {
+                Some(id) => Some(id),
+                None => return Err(ResponseStatus::ProviderDoesNotExist),
+            },
+            None => None,
+        };
+
+        Ok(Operation {
+            client: proto_op.client,
+            provider_id,
+        })
+    }
+}
+
+impl From<Operation> for OperationProto {
+    fn from(op: Operation) -> Self {
+        OperationProto {
+            client: op.client,
+            provider_id: op.provider_id.map(|id| id as u32),
+        }
+    }
+}
+
+impl TryFrom<ClientKeyInfoProto> for ClientKeyInfo {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_info: ClientKeyInfoProto) -> std::result::Result<Self, Self::Error> {
+        let key_info = proto_info
+            .key_info
+            .ok_or_else(|| {
+                error!("The key_info field of ClientKeyInfo protobuf message is not set (mandatory field).");
+                ResponseStatus::InvalidEncoding
+            })?
+            .try_into()?;
+
+        Ok(ClientKeyInfo {
+            client: proto_info.client,
+            key_info,
+        })
+    }
+}
+
+impl TryFrom<ClientKeyInfo> for ClientKeyInfoProto {
+    type Error = ResponseStatus;
+
+    fn try_from(info: ClientKeyInfo) -> std::result::Result<Self, Self::Error> {
+        Ok(ClientKeyInfoProto {
+            client: info.client,
+            key_info: Some(info.key_info.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let keys = proto_op
+            .keys
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Result { keys })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Result) -> std::result::Result<Self, Self::Error> {
+        let keys = op
+            .keys
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(ResultProto { keys })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::list_all_keys::{
+        ClientKeyInfo as ClientKeyInfoProto, Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::list_all_keys::{ClientKeyInfo, Operation, Result};
+    use crate::operations::list_keys::KeyInfo;
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode, ProviderId, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_client_key_info() -> ClientKeyInfo {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash().set_verify_hash();
+        ClientKeyInfo {
+            client: String::from("client-a"),
+            key_info: KeyInfo {
+                provider_id: ProviderId::MbedCrypto,
+                name: String::from("signing-key"),
+                attributes: Attributes {
+                    lifetime: Lifetime::Persistent,
+                    key_type: Type::RsaKeyPair,
+                    bits: 2048,
+                    policy: Policy {
+                        usage_flags,
+                        permitted_algorithms: Algorithm::AsymmetricSignature(
+                            AsymmetricSignature::RsaPkcs1v15Sign {
+                                hash_alg: Hash::Sha256.into(),
+                            },
+                        ),
+                    },
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn resp_to_proto_to_resp() {
+        let resp = Result {
+            keys: vec![get_client_key_info()],
+        };
+
+        let proto: ResultProto = resp.try_into().unwrap();
+        assert_eq!(proto.keys.len(), 1);
+        assert_eq!(proto.keys[0].client, "client-a");
+        assert_eq!(
+            proto.keys[0].key_info.as_ref().unwrap().provider_id,
+            ProviderId::MbedCrypto as u32
+        );
+
+        let resp: Result = proto.try_into().unwrap();
+        assert_eq!(resp.keys, vec![get_client_key_info()]);
+    }
+
+    #[test]
+    fn proto_missing_key_info() {
+        let proto = ResultProto {
+            keys: vec![ClientKeyInfoProto {
+                client: String::from("client-a"),
+                key_info: None,
+            }],
+        };
+        let resp: std::result::Result<Result, _> = proto.try_into();
+        assert_eq!(resp.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn op_invalid_provider() {
+        let proto = OperationProto {
+            client: None,
+            provider_id: Some(0xff),
+        };
+        let op: std::result::Result<Operation, _> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::ProviderDoesNotExist);
+    }
+
+    #[test]
+    fn op_filters_e2e() {
+        let op = Operation {
+            client: Some(String::from("client-a")),
+            provider_id: Some(ProviderId::Tpm),
+        };
+        let req_body = CONVERTER
+            .operation_to_body(NativeOperation::ListAllKeys(op.clone()))
+            .expect("Failed to convert request");
+
+        match CONVERTER
+            .body_to_operation(req_body, Opcode::ListAllKeys)
+            .expect("Failed to convert back to operation")
+        {
+            NativeOperation::ListAllKeys(converted) => assert_eq!(converted, op),
+            _ => panic!("Expected ListAllKeys"),
+        }
+    }
+
+    #[test]
+    fn empty_op_body() {
+        let req_body = RequestBody::from_bytes(Vec::new());
+        match CONVERTER
+            .body_to_operation(req_body, Opcode::ListAllKeys)
+            .expect("Failed to convert request")
+        {
+            NativeOperation::ListAllKeys(op) => assert_eq!(op, Operation::default()),
+            _ => panic!("Expected ListAllKeys"),
+        }
+    }
+
+    #[test]
+    fn result_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::ListAllKeys(Result {
+                keys: vec![get_client_key_info()],
+            }))
+            .expect("Failed to convert response");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::ListAllKeys)
+            .expect("Failed to convert back to result")
+        {
+            NativeResult::ListAllKeys(result) => {
+                assert_eq!(result.keys, vec![get_client_key_info()])
+            }
+            _ => panic!("Expected ListAllKeys"),
+        }
+    }
+
+    #[test]
+    fn filters_match() {
+        let key = get_client_key_info();
+        assert!(Operation::default().matches(&key));
+        assert!(Operation {
+            client: Some(String::from("client-a")),
+            provider_id: Some(ProviderId::MbedCrypto),
+        }
+        .matches(&key));
+        assert!(!Operation {
+            client: Some(String::from("client-b")),
+            provider_id: None,
+        }
+        .matches(&key));
+        assert!(!Operation {
+            client: None,
+            provider_id: Some(ProviderId::Pkcs11),
+        }
+        .matches(&key));
+    }
+}
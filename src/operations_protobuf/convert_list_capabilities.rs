@@ -0,0 +1,322 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_can_do_crypto::{check_type_to_i32, i32_to_check_type};
+use super::generated_ops::list_capabilities::{
+    KeyTypeCapability as KeyTypeCapabilityProto, Operation as OperationProto,
+    ProviderCapabilities as ProviderCapabilitiesProto, Result as ResultProto,
+};
+use crate::operations::list_capabilities::{
+    KeyTypeCapability, Operation, ProviderCapabilities, Result,
+};
+use crate::requests::ResponseStatus;
+use log::error;
+use num::FromPrimitive;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let provider_id = match proto_op.provider_id {
+            Some(provider_id) => match FromPrimitive::from_u32(provider_id) {
+                Some(id) => Some(id),
+                None => return Err(ResponseStatus::ProviderDoesNotExist),
+            },
+            None => None,
+        };
+
+        Ok(Operation { provider_id })
+    }
+}
+
+impl From<Operation> for OperationProto {
+    fn from(op: Operation) -> Self {
+        OperationProto {
+            provider_id: op.provider_id.map(|id| id as u32),
+        }
+    }
+}
+
+impl TryFrom<KeyTypeCapabilityProto> for KeyTypeCapability {
+    type Error = ResponseStatus;
+
+    fn try_from(
+        proto_capability: KeyTypeCapabilityProto,
+    ) -> std::result::Result<Self, Self::Error> {
+        let key_type = proto_capability
+            .key_type
+            .ok_or_else(|| {
+                error!("The key_type field of KeyTypeCapability protobuf message is not set (mandatory field).");
+                ResponseStatus::InvalidEncoding
+            })?
+            .try_into()?;
+
+        Ok(KeyTypeCapability {
+            key_type,
+            min_bits: proto_capability.min_bits.try_into()?,
+            max_bits: proto_capability.max_bits.try_into()?,
+            algorithms: proto_capability
+                .algorithms
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+            usage_flags: proto_capability
+                .usage_flags
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+            check_types: proto_capability
+                .check_types
+                .into_iter()
+                .map(i32_to_check_type)
+                .collect::<std::result::Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<KeyTypeCapability> for KeyTypeCapabilityProto {
+    type Error = ResponseStatus;
+
+    fn try_from(capability: KeyTypeCapability) -> std::result::Result<Self, Self::Error> {
+        Ok(KeyTypeCapabilityProto {
+            key_type: Some(capability.key_type.try_into()?),
+            min_bits: capability.min_bits.try_into()?,
+            max_bits: capability.max_bits.try_into()?,
+            algorithms: capability
+                .algorithms
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+            usage_flags: capability
+                .usage_flags
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+            check_types: capability
+                .check_types
+                .into_iter()
+                .map(check_type_to_i32)
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<ProviderCapabilitiesProto> for ProviderCapabilities {
+    type Error = ResponseStatus;
+
+    fn try_from(
+        proto_capabilities: ProviderCapabilitiesProto,
+    ) -> std::result::Result<Self, Self::Error> {
+        let provider_id = match FromPrimitive::from_u32(proto_capabilities.provider_id) {
+            Some(id) => id,
+            None => return Err(ResponseStatus::ProviderDoesNotExist),
+        };
+
+        Ok(ProviderCapabilities {
+            provider_id,
+            key_types: proto_capabilities
+                .key_types
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<ProviderCapabilities> for ProviderCapabilitiesProto {
+    type Error = ResponseStatus;
+
+    fn try_from(capabilities: ProviderCapabilities) -> std::result::Result<Self, Self::Error> {
+        Ok(ProviderCapabilitiesProto {
+            provider_id: capabilities.provider_id as u32,
+            key_types: capabilities
+                .key_types
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let providers = proto_result
+            .providers
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Result { providers })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        let providers = result
+            .providers
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(ResultProto { providers })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::list_capabilities::{
+        KeyTypeCapability as KeyTypeCapabilityProto, Operation as OperationProto,
+        ProviderCapabilities as ProviderCapabilitiesProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::can_do_crypto::CheckType;
+    use crate::operations::list_capabilities::{
+        KeyTypeCapability, Operation, ProviderCapabilities, Result,
+    };
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode, ProviderId, ResponseStatus};
+    use std::convert::{TryFrom, TryInto};
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_capabilities() -> ProviderCapabilities {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash().set_verify_hash();
+        ProviderCapabilities {
+            provider_id: ProviderId::Tpm,
+            key_types: vec![KeyTypeCapability {
+                key_type: Type::RsaKeyPair,
+                min_bits: 1024,
+                max_bits: 4096,
+                algorithms: vec![Algorithm::AsymmetricSignature(
+                    AsymmetricSignature::RsaPkcs1v15Sign {
+                        hash_alg: Hash::Sha256.into(),
+                    },
+                )],
+                usage_flags: vec![usage_flags],
+                check_types: vec![CheckType::Use, CheckType::Generate, CheckType::Import],
+            }],
+        }
+    }
+
+    #[test]
+    fn resp_to_proto_to_resp() {
+        let resp = Result {
+            providers: vec![get_capabilities()],
+        };
+
+        let proto: ResultProto = resp.try_into().unwrap();
+        assert_eq!(proto.providers.len(), 1);
+        assert_eq!(proto.providers[0].provider_id, ProviderId::Tpm as u32);
+        assert_eq!(proto.providers[0].key_types[0].max_bits, 4096);
+        assert_eq!(proto.providers[0].key_types[0].check_types, vec![1, 2, 3]);
+
+        let resp: Result = proto.try_into().unwrap();
+        assert_eq!(resp.providers, vec![get_capabilities()]);
+    }
+
+    #[test]
+    fn proto_invalid_capabilities() {
+        let capability = KeyTypeCapabilityProto {
+            key_type: None,
+            min_bits: 0,
+            max_bits: 0,
+            algorithms: Vec::new(),
+            usage_flags: Vec::new(),
+            check_types: Vec::new(),
+        };
+        let proto = ResultProto {
+            providers: vec![ProviderCapabilitiesProto {
+                provider_id: ProviderId::Core as u32,
+                key_types: vec![capability],
+            }],
+        };
+        assert_eq!(
+            Result::try_from(proto).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+
+        let mut proto: ResultProto = Result {
+            providers: vec![get_capabilities()],
+        }
+        .try_into()
+        .unwrap();
+        proto.providers[0].key_types[0].check_types.push(0);
+        assert_eq!(
+            Result::try_from(proto.clone()).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+        proto.providers[0].key_types[0].check_types.clear();
+        proto.providers[0].provider_id = 0xff;
+        assert_eq!(
+            Result::try_from(proto).unwrap_err(),
+            ResponseStatus::ProviderDoesNotExist
+        );
+    }
+
+    #[test]
+    fn op_invalid_provider() {
+        let proto = OperationProto {
+            provider_id: Some(0xff),
+        };
+        assert_eq!(
+            Operation::try_from(proto).unwrap_err(),
+            ResponseStatus::ProviderDoesNotExist
+        );
+    }
+
+    #[test]
+    fn empty_op_body() {
+        let req_body = RequestBody::from_bytes(Vec::new());
+        match CONVERTER
+            .body_to_operation(req_body, Opcode::ListCapabilities)
+            .expect("Failed to convert request")
+        {
+            NativeOperation::ListCapabilities(op) => assert_eq!(op, Operation::default()),
+            _ => panic!("Expected ListCapabilities"),
+        }
+    }
+
+    #[test]
+    fn op_e2e() {
+        let op = Operation {
+            provider_id: Some(ProviderId::Pkcs11),
+        };
+        let req_body = CONVERTER
+            .operation_to_body(NativeOperation::ListCapabilities(op))
+            .expect("Failed to convert request");
+
+        match CONVERTER
+            .body_to_operation(req_body, Opcode::ListCapabilities)
+            .expect("Failed to convert back to operation")
+        {
+            NativeOperation::ListCapabilities(converted) => assert_eq!(converted, op),
+            _ => panic!("Expected ListCapabilities"),
+        }
+    }
+
+    #[test]
+    fn result_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::ListCapabilities(Result {
+                providers: vec![get_capabilities()],
+            }))
+            .expect("Failed to convert response");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::ListCapabilities)
+            .expect("Failed to convert back to result")
+        {
+            NativeResult::ListCapabilities(result) => {
+                assert_eq!(result.providers, vec![get_capabilities()])
+            }
+            _ => panic!("Expected ListCapabilities"),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::list_keys::{
+use super::generated_ops::list_keys_ext::{
     KeyInfo as KeyInfoProto, Operation as OperationProto, Result as ResultProto,
 };
 use crate::operations::list_keys::{KeyInfo, Operation, Result};
@@ -12,16 +12,38 @@ use std::convert::{TryFrom, TryInto};
 impl TryFrom<OperationProto> for Operation {
     type Error = ResponseStatus;
 
-    fn try_from(_proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
-        Ok(Operation {})
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let provider_id = match proto_op.provider_id {
+            Some(provider_id) => match FromPrimitive::from_u32(provider_id) {
+                Some(id) => Some(id),
+                None => return Err(ResponseStatus::ProviderDoesNotExist),
+            },
+            None => None,
+        };
+
+        Ok(Operation {
+            provider_id,
+            key_type: proto_op.key_type.map(TryInto::try_into).transpose()?,
+            usage_flags: proto_op.usage_flags.map(TryInto::try_into).transpose()?,
+            name_prefix: proto_op.name_prefix,
+            page_size: proto_op.page_size,
+            continuation_token: proto_op.continuation_token,
+        })
     }
 }
 
 impl TryFrom<Operation> for OperationProto {
     type Error = ResponseStatus;
 
-    fn try_from(_op: Operation) -> std::result::Result<Self, Self::Error> {
-        Ok(Default::default())
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            provider_id: op.provider_id.map(|id| id as u32),
+            key_type: op.key_type.map(TryInto::try_into).transpose()?,
+            usage_flags: op.usage_flags.map(TryInto::try_into).transpose()?,
+            name_prefix: op.name_prefix,
+            page_size: op.page_size,
+            continuation_token: op.continuation_token,
+        })
     }
 }
 
@@ -71,7 +93,10 @@ impl TryFrom<ResultProto> for Result {
             keys.push(key.try_into()?);
         }
 
-        Ok(Result { keys })
+        Ok(Result {
+            keys,
+            continuation_token: proto_op.continuation_token,
+        })
     }
 }
 
@@ -84,22 +109,28 @@ impl TryFrom<Result> for ResultProto {
             keys.push(key.try_into()?);
         }
 
-        Ok(ResultProto { keys })
+        Ok(ResultProto {
+            keys,
+            continuation_token: op.continuation_token,
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
-    // Operation <-> Proto conversions are not tested since they're too simple
-    use super::super::generated_ops::list_keys::{KeyInfo as KeyInfoProto, Result as ResultProto};
-    use super::super::generated_ops::psa_key_attributes::KeyAttributes as KeyAttributesProto;
+    use super::super::generated_ops::list_keys_ext::{
+        KeyInfo as KeyInfoProto, Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::generated_ops::psa_key_attributes_ext::KeyAttributes as KeyAttributesProto;
     use super::super::{Convert, ProtobufConverter};
     use crate::operations::list_keys::{KeyInfo, Operation, Result};
     use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
     use crate::operations::psa_key_attributes::{self, Attributes, Lifetime, Policy, UsageFlags};
     use crate::operations::{NativeOperation, NativeResult};
-    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ProviderId};
-    use std::convert::TryInto;
+    use crate::requests::{
+        request::RequestBody, response::ResponseBody, Opcode, ProviderId, ResponseStatus,
+    };
+    use std::convert::{TryFrom, TryInto};
 
     static CONVERTER: ProtobufConverter = ProtobufConverter {};
 
@@ -152,7 +183,10 @@ mod test {
 
     #[test]
     fn resp_to_proto() {
-        let mut resp: Result = Result { keys: Vec::new() };
+        let mut resp: Result = Result {
+            keys: Vec::new(),
+            continuation_token: None,
+        };
         let mut usage_flags = UsageFlags::default();
         let _ = usage_flags
             .set_decrypt()
@@ -205,7 +239,7 @@ mod test {
 
     #[test]
     fn op_list_keys_from_native() {
-        let list_keys = Operation {};
+        let list_keys = Operation::default();
         let body = CONVERTER
             .operation_to_body(NativeOperation::ListKeys(list_keys))
             .expect("Failed to convert request");
@@ -214,7 +248,7 @@ mod test {
 
     #[test]
     fn op_list_keys_e2e() {
-        let list_keys = Operation {};
+        let list_keys = Operation::default();
         let req_body = CONVERTER
             .operation_to_body(NativeOperation::ListKeys(list_keys))
             .expect("Failed to convert request");
@@ -244,7 +278,10 @@ mod test {
 
     #[test]
     fn result_list_keys_from_native() {
-        let mut list_keys = Result { keys: Vec::new() };
+        let mut list_keys = Result {
+            keys: Vec::new(),
+            continuation_token: None,
+        };
         let mut usage_flags = UsageFlags::default();
         let _ = usage_flags
             .set_decrypt()
@@ -298,7 +335,10 @@ mod test {
             .set_sign_hash()
             .set_verify_hash()
             .set_derive();
-        let mut list_keys = Result { keys: Vec::new() };
+        let mut list_keys = Result {
+            keys: Vec::new(),
+            continuation_token: None,
+        };
         let key_info = KeyInfo {
             provider_id: ProviderId::MbedCrypto,
             name: String::from("Baz"),
@@ -343,4 +383,71 @@ mod test {
             .body_to_result(resp_body, Opcode::ListKeys)
             .is_err());
     }
+
+    #[test]
+    fn op_filters_e2e() {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        let list_keys = Operation {
+            provider_id: Some(ProviderId::Core),
+            key_type: Some(psa_key_attributes::Type::RsaKeyPair),
+            usage_flags: Some(usage_flags),
+            name_prefix: Some(String::from("prefix/")),
+            page_size: Some(50),
+            continuation_token: Some(String::from("token")),
+        };
+        let req_body = CONVERTER
+            .operation_to_body(NativeOperation::ListKeys(list_keys.clone()))
+            .expect("Failed to convert request");
+
+        match CONVERTER
+            .body_to_operation(req_body, Opcode::ListKeys)
+            .expect("Failed to convert back to operation")
+        {
+            NativeOperation::ListKeys(op) => assert_eq!(op, list_keys),
+            _ => panic!("Expected list_keys"),
+        }
+    }
+
+    #[test]
+    fn op_without_filters_is_empty() {
+        // Requests without filters keep the encoding of previous versions.
+        let proto: OperationProto = Operation::default().try_into().unwrap();
+        assert_eq!(proto, OperationProto::default());
+        let op: Operation = OperationProto::default().try_into().unwrap();
+        assert_eq!(op, Operation::default());
+    }
+
+    #[test]
+    fn op_unknown_provider() {
+        let proto = OperationProto {
+            provider_id: Some(0xff),
+            ..Default::default()
+        };
+        assert_eq!(
+            Operation::try_from(proto).unwrap_err(),
+            ResponseStatus::ProviderDoesNotExist
+        );
+    }
+
+    #[test]
+    fn result_continuation_token_e2e() {
+        let list_keys = Result {
+            keys: Vec::new(),
+            continuation_token: Some(String::from("next page")),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::ListKeys(list_keys))
+            .expect("Failed to convert response");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::ListKeys)
+            .expect("Failed to convert back to result")
+        {
+            NativeResult::ListKeys(result) => {
+                assert_eq!(result.continuation_token, Some(String::from("next page")))
+            }
+            _ => panic!("Expected list_keys"),
+        }
+    }
 }
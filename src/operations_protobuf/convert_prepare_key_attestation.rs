@@ -1,6 +1,6 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::prepare_key_attestation::{
+use super::generated_ops::prepare_key_attestation_ext::{
     prepare_key_attestation_output, prepare_key_attestation_params, Operation as OperationProto,
     PrepareKeyAttestationOutput, PrepareKeyAttestationParams, Result as ResultProto,
 };
@@ -29,6 +29,19 @@ impl TryFrom<Operation> for OperationProto {
                     ),
                 }),
             }),
+            Operation::Certify {
+                attested_key_name,
+                attesting_key_name,
+            } => Ok(OperationProto {
+                parameters: Some(PrepareKeyAttestationParams {
+                    mechanism: Some(prepare_key_attestation_params::Mechanism::Certify(
+                        prepare_key_attestation_params::Certify {
+                            attested_key_name,
+                            attesting_key_name: attesting_key_name.unwrap_or_default(),
+                        },
+                    )),
+                }),
+            }),
         }
     }
 }
@@ -57,6 +70,25 @@ impl TryFrom<OperationProto> for Operation {
                     Some(attesting_key_name)
                 },
             }),
+            OperationProto {
+                parameters:
+                    Some(PrepareKeyAttestationParams {
+                        mechanism:
+                            Some(prepare_key_attestation_params::Mechanism::Certify(
+                                prepare_key_attestation_params::Certify {
+                                    attested_key_name,
+                                    attesting_key_name,
+                                },
+                            )),
+                    }),
+            } => Ok(Operation::Certify {
+                attested_key_name,
+                attesting_key_name: if attesting_key_name.is_empty() {
+                    None
+                } else {
+                    Some(attesting_key_name)
+                },
+            }),
             _ => {
                 error!("The encoding of the operation does not follow the expected pattern");
                 Err(ResponseStatus::InvalidEncoding)
@@ -87,6 +119,21 @@ impl TryFrom<Result> for ResultProto {
                     ),
                 }),
             }),
+            Result::Certify {
+                name,
+                public,
+                attesting_key_pub,
+            } => Ok(ResultProto {
+                output: Some(PrepareKeyAttestationOutput {
+                    mechanism: Some(prepare_key_attestation_output::Mechanism::Certify(
+                        prepare_key_attestation_output::Certify {
+                            name: name.to_vec(),
+                            public: public.to_vec(),
+                            attesting_key_pub: attesting_key_pub.to_vec(),
+                        },
+                    )),
+                }),
+            }),
         }
     }
 }
@@ -113,6 +160,23 @@ impl TryFrom<ResultProto> for Result {
                 public: public.into(),
                 attesting_key_pub: attesting_key_pub.into(),
             }),
+            ResultProto {
+                output:
+                    Some(PrepareKeyAttestationOutput {
+                        mechanism:
+                            Some(prepare_key_attestation_output::Mechanism::Certify(
+                                prepare_key_attestation_output::Certify {
+                                    name,
+                                    public,
+                                    attesting_key_pub,
+                                },
+                            )),
+                    }),
+            } => Ok(Result::Certify {
+                name: name.into(),
+                public: public.into(),
+                attesting_key_pub: attesting_key_pub.into(),
+            }),
             _ => {
                 error!("The encoding of the operation does not follow the expected pattern");
                 Err(ResponseStatus::InvalidEncoding)
@@ -123,7 +187,7 @@ impl TryFrom<ResultProto> for Result {
 
 #[cfg(test)]
 mod test {
-    use super::super::generated_ops::prepare_key_attestation::{
+    use super::super::generated_ops::prepare_key_attestation_ext::{
         prepare_key_attestation_output, prepare_key_attestation_params,
         Operation as OperationProto, PrepareKeyAttestationOutput, PrepareKeyAttestationParams,
         Result as ResultProto,
@@ -158,10 +222,13 @@ mod test {
 
         let op: Operation = proto.try_into().expect("Conversion failed");
 
-        let Operation::ActivateCredential {
-            attested_key_name,
-            attesting_key_name,
-        } = op;
+        let (attested_key_name, attesting_key_name) = match op {
+            Operation::ActivateCredential {
+                attested_key_name,
+                attesting_key_name,
+            } => (attested_key_name, attesting_key_name),
+            _ => panic!("Expected ActivateCredential"),
+        };
 
         assert_eq!(attested_key_name, op_attested_key_name);
         assert_eq!(
@@ -189,9 +256,12 @@ mod test {
 
         let op: Operation = proto.try_into().expect("Conversion failed");
 
-        let Operation::ActivateCredential {
-            attesting_key_name, ..
-        } = op;
+        let attesting_key_name = match op {
+            Operation::ActivateCredential {
+                attesting_key_name, ..
+            } => attesting_key_name,
+            _ => panic!("Expected ActivateCredential"),
+        };
 
         assert!(attesting_key_name.is_none());
     }
@@ -328,11 +398,14 @@ mod test {
 
         let result: Result = proto.try_into().expect("Conversion failed");
 
-        let Result::ActivateCredential {
-            name,
-            public,
-            attesting_key_pub,
-        } = result;
+        let (name, public, attesting_key_pub) = match result {
+            Result::ActivateCredential {
+                name,
+                public,
+                attesting_key_pub,
+            } => (name, public, attesting_key_pub),
+            _ => panic!("Expected ActivateCredential"),
+        };
 
         assert_eq!(name.to_vec(), resp_name);
         assert_eq!(public.to_vec(), resp_public);
@@ -359,4 +432,68 @@ mod test {
             .body_to_result(body, Opcode::PrepareKeyAttestation)
             .expect("Failed to convert to operation");
     }
+
+    #[test]
+    fn prepare_key_attestation_certify_op_e2e() {
+        let op = Operation::Certify {
+            attested_key_name: String::from("attested key name"),
+            attesting_key_name: Some(String::from("attesting key name")),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Conversion failed");
+        assert!(matches!(
+            proto.parameters,
+            Some(PrepareKeyAttestationParams {
+                mechanism: Some(prepare_key_attestation_params::Mechanism::Certify(_)),
+            })
+        ));
+
+        let op: Operation = proto.try_into().expect("Conversion failed");
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PrepareKeyAttestation(op))
+            .expect("Failed to convert to body");
+
+        match CONVERTER
+            .body_to_operation(body, Opcode::PrepareKeyAttestation)
+            .expect("Failed to convert to operation")
+        {
+            NativeOperation::PrepareKeyAttestation(Operation::Certify {
+                attested_key_name,
+                attesting_key_name,
+            }) => {
+                assert_eq!(attested_key_name, "attested key name");
+                assert_eq!(attesting_key_name.unwrap(), "attesting key name");
+            }
+            _ => panic!("Expected Certify"),
+        }
+    }
+
+    #[test]
+    fn prepare_key_attestation_certify_resp_e2e() {
+        let result = Result::Certify {
+            name: vec![0xff; 34].into(),
+            public: vec![0xcc; 32].into(),
+            attesting_key_pub: vec![0x22; 32].into(),
+        };
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PrepareKeyAttestation(result))
+            .expect("Failed to convert to body");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::PrepareKeyAttestation)
+            .expect("Failed to convert to result")
+        {
+            NativeResult::PrepareKeyAttestation(Result::Certify {
+                name,
+                public,
+                attesting_key_pub,
+            }) => {
+                assert_eq!(name.to_vec(), vec![0xff; 34]);
+                assert_eq!(public.to_vec(), vec![0xcc; 32]);
+                assert_eq!(attesting_key_pub.to_vec(), vec![0x22; 32]);
+            }
+            _ => panic!("Expected Certify"),
+        }
+    }
 }
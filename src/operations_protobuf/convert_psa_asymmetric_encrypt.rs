@@ -1,8 +1,10 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::psa_asymmetric_encrypt::{
-    Operation as OperationProto, Result as ResultProto,
+use super::generated_ops::psa_asymmetric_encrypt::Result as ResultProto;
+use super::generated_ops::psa_asymmetric_encrypt_ext::{
+    operation::Key as KeyProto, Operation as OperationProto,
 };
+use crate::operations::key_reference::KeyReference;
 use crate::operations::psa_asymmetric_encrypt::{Operation, Result};
 use crate::requests::ResponseStatus;
 use log::error;
@@ -19,7 +21,14 @@ impl TryFrom<OperationProto> for Operation {
         };
 
         Ok(Operation {
-            key_name: proto_op.key_name,
+            key: match proto_op.key {
+                Some(KeyProto::KeyName(key_name)) => KeyReference::Name(key_name),
+                Some(KeyProto::PublicKey(public_key)) => {
+                    KeyReference::PublicKey(public_key.try_into()?)
+                }
+                // An empty key name is not encoded on the wire.
+                None => KeyReference::Name(String::new()),
+            },
             alg: proto_op
                 .alg
                 .ok_or_else(|| {
@@ -43,7 +52,14 @@ impl TryFrom<Operation> for OperationProto {
             None => vec![],
         };
         Ok(OperationProto {
-            key_name: op.key_name,
+            key: match op.key {
+                // Keep the encoding of an empty key name, which is not written on the wire.
+                KeyReference::Name(key_name) if key_name.is_empty() => None,
+                KeyReference::Name(key_name) => Some(KeyProto::KeyName(key_name)),
+                KeyReference::PublicKey(public_key) => {
+                    Some(KeyProto::PublicKey(public_key.try_into()?))
+                }
+            },
             alg,
             plaintext: op.plaintext.to_vec(),
             salt,
@@ -74,10 +90,12 @@ impl TryFrom<Result> for ResultProto {
 #[cfg(test)]
 mod test {
     use super::super::generated_ops::psa_algorithm as algorithm_proto;
-    use super::super::generated_ops::psa_asymmetric_encrypt::{
-        Operation as OperationProto, Result as ResultProto,
+    use super::super::generated_ops::psa_asymmetric_encrypt::Result as ResultProto;
+    use super::super::generated_ops::psa_asymmetric_encrypt_ext::{
+        operation::Key as KeyProto, Operation as OperationProto,
     };
     use super::super::{Convert, ProtobufConverter};
+    use crate::operations::key_reference::KeyReference;
     use crate::operations::psa_algorithm::AsymmetricEncryption;
     use crate::operations::psa_asymmetric_encrypt::{Operation, Result};
     use std::convert::TryInto;
@@ -99,13 +117,13 @@ mod test {
                 ),
             ),
         });
-        proto.key_name = key_name.clone();
+        proto.key = Some(KeyProto::KeyName(key_name.clone()));
         proto.salt = salt;
 
         let op: Operation = proto.try_into().expect("Failed to convert");
 
         assert_eq!(*op.plaintext, message);
-        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.key, KeyReference::Name(key_name));
         assert_eq!(op.salt, None);
     }
 
@@ -117,14 +135,14 @@ mod test {
         let op = Operation {
             plaintext: message.clone().into(),
             alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
-            key_name: key_name.clone(),
+            key: key_name.clone().into(),
             salt: None,
         };
 
         let proto: OperationProto = op.try_into().expect("Failed to convert");
 
         assert_eq!(proto.plaintext, message);
-        assert_eq!(proto.key_name, key_name);
+        assert_eq!(proto.key, Some(KeyProto::KeyName(key_name)));
         assert_eq!(proto.salt, vec![]);
     }
 
@@ -155,7 +173,7 @@ mod test {
     fn psa_encrypt_message_op_e2e() {
         let name = "test name".to_string();
         let op = Operation {
-            key_name: name,
+            key: name.into(),
             alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
             plaintext: vec![0x11, 0x22, 0x33].into(),
             salt: None,
@@ -0,0 +1,133 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_copy_key::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_copy_key::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            source_key_name: proto_op.source_key_name,
+            target_key_name: proto_op.target_key_name,
+            attributes: proto_op
+                .attributes
+                .ok_or_else(|| {
+                    error!("The attributes field of PsaCopyKey::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            source_key_name: op.source_key_name,
+            target_key_name: op.target_key_name,
+            attributes: Some(op.attributes.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_copy_key::Operation as OperationProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_copy_key::Operation;
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::NativeOperation;
+    use crate::requests::{Opcode, ResponseStatus};
+    use std::convert::{TryFrom, TryInto};
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_operation() -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash().set_sign_message();
+        Operation {
+            source_key_name: String::from("source"),
+            target_key_name: String::from("target"),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::RsaKeyPair,
+                bits: 0,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::RsaPss {
+                            hash_alg: Hash::Sha256.into(),
+                        },
+                    ),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn psa_copy_key_op_to_proto_to_op() {
+        let proto: OperationProto = get_operation().try_into().unwrap();
+        assert_eq!(proto.source_key_name, "source");
+        assert_eq!(proto.target_key_name, "target");
+        assert!(proto.attributes.is_some());
+
+        let op: Operation = proto.try_into().unwrap();
+        assert_eq!(op.source_key_name, "source");
+        assert_eq!(op.target_key_name, "target");
+        assert_eq!(op.attributes, get_operation().attributes);
+    }
+
+    #[test]
+    fn psa_copy_key_missing_attributes() {
+        let proto = OperationProto {
+            source_key_name: String::from("source"),
+            target_key_name: String::from("target"),
+            attributes: None,
+        };
+        assert_eq!(
+            Operation::try_from(proto).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn psa_copy_key_op_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaCopyKey(get_operation()))
+            .expect("Failed to convert request");
+
+        match CONVERTER
+            .body_to_operation(body, Opcode::PsaCopyKey)
+            .expect("Failed to convert back to operation")
+        {
+            NativeOperation::PsaCopyKey(op) => {
+                assert_eq!(op.target_key_name, "target");
+                assert_eq!(op.attributes, get_operation().attributes);
+            }
+            _ => panic!("Expected PsaCopyKey"),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::psa_generate_key::{Operation as OperationProto, Result as ResultProto};
+use super::generated_ops::psa_generate_key_ext::{
+    Operation as OperationProto, Result as ResultProto,
+};
 use crate::operations::psa_generate_key::{Operation, Result};
 use crate::requests::ResponseStatus;
 use log::error;
@@ -39,16 +41,20 @@ impl TryFrom<Operation> for OperationProto {
 impl TryFrom<Result> for ResultProto {
     type Error = ResponseStatus;
 
-    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
-        Ok(Default::default())
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            key_handle: result.key_handle,
+        })
     }
 }
 
 impl TryFrom<ResultProto> for Result {
     type Error = ResponseStatus;
 
-    fn try_from(_response: ResultProto) -> std::result::Result<Self, Self::Error> {
-        Ok(Result {})
+    fn try_from(response: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            key_handle: response.key_handle,
+        })
     }
 }
 
@@ -58,12 +64,11 @@ mod test {
     use super::super::generated_ops::psa_algorithm::{
         self as algorithm_proto, Algorithm as AlgorithmProto,
     };
-    use super::super::generated_ops::psa_generate_key::{
+    use super::super::generated_ops::psa_generate_key_ext::{
         Operation as OperationProto, Result as ResultProto,
     };
-    use super::super::generated_ops::psa_key_attributes::{
-        self as key_attributes_proto, KeyAttributes as KeyAttributesProto,
-    };
+    use super::super::generated_ops::psa_key_attributes as key_attributes_proto;
+    use super::super::generated_ops::psa_key_attributes_ext::KeyAttributes as KeyAttributesProto;
     use super::super::{Convert, ProtobufConverter};
     use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
     use crate::operations::psa_generate_key::{Operation, Result};
@@ -100,14 +105,18 @@ mod test {
 
     #[test]
     fn create_key_res_from_proto() {
-        let proto = ResultProto {};
-        let _res: Result = proto.try_into().expect("Failed conversion");
+        let proto = ResultProto {
+            key_handle: Some(String::from("#volatile/1")),
+        };
+        let res: Result = proto.try_into().expect("Failed conversion");
+        assert_eq!(res.key_handle.as_deref(), Some("#volatile/1"));
     }
 
     #[test]
     fn create_key_res_to_proto() {
-        let res = Result {};
-        let _proto: ResultProto = res.try_into().expect("Failed conversion");
+        let res = Result::default();
+        let proto: ResultProto = res.try_into().expect("Failed conversion");
+        assert_eq!(proto.key_handle, None);
     }
 
     #[test]
@@ -162,6 +171,7 @@ mod test {
                 variant: Some(key_attributes_proto::key_type::Variant::RsaKeyPair(key_attributes_proto::key_type::RsaKeyPair {})),
             }),
             key_bits: 1024,
+            key_lifetime: None,
             key_policy: Some(key_attributes_proto::KeyPolicy {
                 key_usage_flags: Some(key_attributes_proto::UsageFlags {
                     export: true,
@@ -0,0 +1,165 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_get_key_attributes::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_get_key_attributes::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use num::FromPrimitive;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let provider_id = match FromPrimitive::from_u32(proto_result.provider_id) {
+            Some(id) => id,
+            None => return Err(ResponseStatus::ProviderDoesNotExist),
+        };
+
+        Ok(Result {
+            provider_id,
+            attributes: proto_result
+                .attributes
+                .ok_or_else(|| {
+                    error!("The attributes field of PsaGetKeyAttributes::Result message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            provider_id: result.provider_id as u32,
+            attributes: Some(result.attributes.try_into()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_get_key_attributes::Result as ResultProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_get_key_attributes::{Operation, Result};
+    use crate::operations::psa_key_attributes::{
+        Attributes, EccFamily, Lifetime, Policy, Type, UsageFlags,
+    };
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{Opcode, ProviderId, ResponseStatus};
+    use std::convert::{TryFrom, TryInto};
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_result() -> Result {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags
+            .set_sign_hash()
+            .set_sign_message()
+            .set_verify_hash()
+            .set_verify_message();
+        Result {
+            provider_id: ProviderId::Tpm,
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::EccKeyPair {
+                    curve_family: EccFamily::SecpR1,
+                },
+                bits: 256,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::Ecdsa {
+                            hash_alg: Hash::Sha256.into(),
+                        },
+                    ),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn result_to_proto_to_result() {
+        let proto: ResultProto = get_result().try_into().unwrap();
+        assert_eq!(proto.provider_id, ProviderId::Tpm as u32);
+        assert!(proto.attributes.is_some());
+
+        let result: Result = proto.try_into().unwrap();
+        assert_eq!(result, get_result());
+    }
+
+    #[test]
+    fn invalid_result_proto() {
+        let mut proto: ResultProto = get_result().try_into().unwrap();
+        proto.provider_id = 0xff;
+        assert_eq!(
+            Result::try_from(proto).unwrap_err(),
+            ResponseStatus::ProviderDoesNotExist
+        );
+
+        let mut proto: ResultProto = get_result().try_into().unwrap();
+        proto.attributes = None;
+        assert_eq!(
+            Result::try_from(proto).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn op_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaGetKeyAttributes(Operation {
+                key_name: String::from("key"),
+            }))
+            .expect("Failed to convert request");
+
+        match CONVERTER
+            .body_to_operation(body, Opcode::PsaGetKeyAttributes)
+            .expect("Failed to convert back to operation")
+        {
+            NativeOperation::PsaGetKeyAttributes(op) => assert_eq!(op.key_name, "key"),
+            _ => panic!("Expected PsaGetKeyAttributes"),
+        }
+    }
+
+    #[test]
+    fn result_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaGetKeyAttributes(get_result()))
+            .expect("Failed to convert response");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::PsaGetKeyAttributes)
+            .expect("Failed to convert back to result")
+        {
+            NativeResult::PsaGetKeyAttributes(result) => assert_eq!(result, get_result()),
+            _ => panic!("Expected PsaGetKeyAttributes"),
+        }
+    }
+}
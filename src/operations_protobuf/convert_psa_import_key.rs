@@ -1,6 +1,8 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::psa_import_key::{Operation as OperationProto, Result as ResultProto};
+use super::generated_ops::psa_import_key_ext::{
+    Operation as OperationProto, Result as ResultProto,
+};
 use crate::operations::psa_import_key::{Operation, Result};
 use crate::requests::ResponseStatus;
 use crate::secrecy::ExposeSecret;
@@ -42,16 +44,20 @@ impl TryFrom<Operation> for OperationProto {
 impl TryFrom<ResultProto> for Result {
     type Error = ResponseStatus;
 
-    fn try_from(_proto_op: ResultProto) -> std::result::Result<Self, Self::Error> {
-        Ok(Result {})
+    fn try_from(proto_op: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            key_handle: proto_op.key_handle,
+        })
     }
 }
 
 impl TryFrom<Result> for ResultProto {
     type Error = ResponseStatus;
 
-    fn try_from(_op: Result) -> std::result::Result<Self, Self::Error> {
-        Ok(ResultProto {})
+    fn try_from(op: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            key_handle: op.key_handle,
+        })
     }
 }
 
@@ -61,12 +67,11 @@ mod test {
     use super::super::generated_ops::psa_algorithm::{
         self as algorithm_proto, Algorithm as AlgorithmProto,
     };
-    use super::super::generated_ops::psa_import_key::{
+    use super::super::generated_ops::psa_import_key_ext::{
         Operation as OperationProto, Result as ResultProto,
     };
-    use super::super::generated_ops::psa_key_attributes::{
-        self as key_attributes_proto, KeyAttributes as KeyAttributesProto,
-    };
+    use super::super::generated_ops::psa_key_attributes as key_attributes_proto;
+    use super::super::generated_ops::psa_key_attributes_ext::KeyAttributes as KeyAttributesProto;
     use super::super::{Convert, ProtobufConverter};
     use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
     use crate::operations::psa_key_attributes::{self, Attributes, Lifetime, Policy, UsageFlags};
@@ -109,13 +114,13 @@ mod test {
 
     #[test]
     fn psa_import_key_res_from_proto() {
-        let proto = ResultProto {};
+        let proto = ResultProto { key_handle: None };
         let _res: Result = proto.try_into().expect("Failed conversion");
     }
 
     #[test]
     fn psa_import_key_res_to_proto() {
-        let res = Result {};
+        let res = Result::default();
         let _proto: ResultProto = res.try_into().expect("Failed conversion");
     }
 
@@ -172,6 +177,7 @@ mod test {
                 variant: Some(key_attributes_proto::key_type::Variant::RsaKeyPair(key_attributes_proto::key_type::RsaKeyPair {})),
             }),
             key_bits: 1024,
+            key_lifetime: None,
             key_policy: Some(key_attributes_proto::KeyPolicy {
                 key_usage_flags: Some(key_attributes_proto::UsageFlags {
                     export: true,
@@ -7,7 +7,11 @@ use super::generated_ops::psa_key_attributes::{
     KeyAttributes as KeyAttributesProto, KeyPolicy as KeyPolicyProto, KeyType as KeyTypeProto,
     UsageFlags as UsageFlagsProto,
 };
+use super::generated_ops::psa_key_attributes_ext::{
+    KeyAttributes as KeyAttributesExtProto, PublicKey as PublicKeyProto,
+};
 // Native imports
+use crate::operations::key_reference::PublicKey;
 use crate::operations::psa_algorithm::Algorithm;
 use crate::operations::psa_key_attributes::{
     Attributes, DhFamily, EccFamily, Lifetime, Policy, Type, UsageFlags,
@@ -267,6 +271,30 @@ impl TryFrom<Type> for KeyTypeProto {
     }
 }
 
+// Lifetime values of the PSA Crypto API
+const LIFETIME_VOLATILE: u32 = 0;
+const LIFETIME_PERSISTENT: u32 = 1;
+
+// Lifetime: from protobuf to native. Keys are persistent when the lifetime is not set, as it was
+// not part of the message before volatile keys were supported.
+fn lifetime_from_proto(key_lifetime: Option<u32>) -> Lifetime {
+    match key_lifetime {
+        None | Some(LIFETIME_PERSISTENT) => Lifetime::Persistent,
+        Some(LIFETIME_VOLATILE) => Lifetime::Volatile,
+        Some(lifetime) => Lifetime::Custom(lifetime),
+    }
+}
+
+// Lifetime: from native to protobuf. The lifetime of persistent keys is not set to keep their
+// encoding unchanged.
+fn lifetime_to_proto(lifetime: Lifetime) -> Option<u32> {
+    match lifetime {
+        Lifetime::Persistent => None,
+        Lifetime::Volatile => Some(LIFETIME_VOLATILE),
+        Lifetime::Custom(lifetime) => Some(lifetime),
+    }
+}
+
 // Attributes: from protobuf to native
 impl TryFrom<KeyAttributesProto> for Attributes {
     type Error = ResponseStatus;
@@ -314,6 +342,72 @@ impl TryFrom<Attributes> for KeyAttributesProto {
     }
 }
 
+// Attributes with lifetime: from protobuf to native
+impl TryFrom<KeyAttributesExtProto> for Attributes {
+    type Error = ResponseStatus;
+
+    fn try_from(key_attributes_proto: KeyAttributesExtProto) -> Result<Self> {
+        let attributes: Attributes = KeyAttributesProto {
+            key_type: key_attributes_proto.key_type,
+            key_bits: key_attributes_proto.key_bits,
+            key_policy: key_attributes_proto.key_policy,
+        }
+        .try_into()?;
+        Ok(Attributes {
+            lifetime: lifetime_from_proto(key_attributes_proto.key_lifetime),
+            ..attributes
+        })
+    }
+}
+
+// Attributes with lifetime: from native to protobuf
+impl TryFrom<Attributes> for KeyAttributesExtProto {
+    type Error = ResponseStatus;
+
+    fn try_from(key_attributes: Attributes) -> Result<Self> {
+        let lifetime = key_attributes.lifetime;
+        let key_attributes_proto: KeyAttributesProto = key_attributes.try_into()?;
+        Ok(KeyAttributesExtProto {
+            key_type: key_attributes_proto.key_type,
+            key_bits: key_attributes_proto.key_bits,
+            key_policy: key_attributes_proto.key_policy,
+            key_lifetime: lifetime_to_proto(lifetime),
+        })
+    }
+}
+
+// PublicKey: from protobuf to native
+impl TryFrom<PublicKeyProto> for PublicKey {
+    type Error = ResponseStatus;
+
+    fn try_from(public_key_proto: PublicKeyProto) -> Result<Self> {
+        Ok(PublicKey {
+            attributes: public_key_proto
+                .attributes
+                .ok_or_else(|| {
+                    error!(
+                        "The attributes field of PublicKey message is not set (mandatory field)."
+                    );
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            data: public_key_proto.data,
+        })
+    }
+}
+
+// PublicKey: from native to protobuf
+impl TryFrom<PublicKey> for PublicKeyProto {
+    type Error = ResponseStatus;
+
+    fn try_from(public_key: PublicKey) -> Result<Self> {
+        Ok(PublicKeyProto {
+            attributes: Some(public_key.attributes.try_into()?),
+            data: public_key.data,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(deprecated)]
@@ -321,6 +415,7 @@ mod test {
     use super::super::generated_ops::psa_key_attributes::{
         self as key_attributes_proto, KeyAttributes as KeyAttributesProto,
     };
+    use super::super::generated_ops::psa_key_attributes_ext::KeyAttributes as KeyAttributesExtProto;
     use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
     use crate::operations::psa_key_attributes::{self, Attributes, Lifetime, Policy, UsageFlags};
     use crate::requests::ResponseStatus;
@@ -654,4 +749,33 @@ mod test {
             ResponseStatus::InvalidEncoding
         );
     }
+
+    #[test]
+    fn key_lifetime() {
+        let mut key_attrs = Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: psa_key_attributes::Type::RawData,
+            bits: 1024,
+            policy: Policy {
+                usage_flags: UsageFlags::default(),
+                permitted_algorithms: Algorithm::None,
+            },
+        };
+        let proto: KeyAttributesExtProto = key_attrs.try_into().unwrap();
+        assert_eq!(proto.key_lifetime, None);
+
+        for (lifetime, value) in [(Lifetime::Volatile, 0), (Lifetime::Custom(0x80), 0x80)] {
+            key_attrs.lifetime = lifetime;
+            let proto: KeyAttributesExtProto = key_attrs.try_into().unwrap();
+            assert_eq!(proto.key_lifetime, Some(value));
+            assert_eq!(Attributes::try_from(proto).unwrap(), key_attrs);
+        }
+
+        let mut proto: KeyAttributesExtProto = key_attrs.try_into().unwrap();
+        proto.key_lifetime = Some(1);
+        assert_eq!(
+            Attributes::try_from(proto).unwrap().lifetime,
+            Lifetime::Persistent
+        );
+    }
 }
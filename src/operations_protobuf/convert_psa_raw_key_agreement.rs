@@ -59,12 +59,11 @@ mod test {
     use super::super::generated_ops::psa_algorithm::{
         self as algorithm_proto, Algorithm as AlgorithmProto,
     };
-    use super::super::generated_ops::psa_import_key::{
+    use super::super::generated_ops::psa_import_key_ext::{
         Operation as OperationProto, Result as ResultProto,
     };
-    use super::super::generated_ops::psa_key_attributes::{
-        self as key_attributes_proto, KeyAttributes as KeyAttributesProto,
-    };
+    use super::super::generated_ops::psa_key_attributes as key_attributes_proto;
+    use super::super::generated_ops::psa_key_attributes_ext::KeyAttributes as KeyAttributesProto;
     use super::super::{Convert, ProtobufConverter};
     use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
     use crate::operations::psa_key_attributes::{self, Attributes, Lifetime, Policy, UsageFlags};
@@ -107,13 +106,13 @@ mod test {
 
     #[test]
     fn psa_raw_key_agreement_res_from_proto() {
-        let proto = ResultProto {};
+        let proto = ResultProto { key_handle: None };
         let _res: Result = proto.try_into().expect("Failed conversion");
     }
 
     #[test]
     fn psa_raw_key_agreement_res_to_proto() {
-        let res = Result {};
+        let res = Result::default();
         let _proto: ResultProto = res.try_into().expect("Failed conversion");
     }
 
@@ -170,6 +169,7 @@ mod test {
                 variant: Some(key_attributes_proto::key_type::Variant::RsaKeyPair(key_attributes_proto::key_type::RsaKeyPair {})),
             }),
             key_bits: 1024,
+            key_lifetime: None,
             key_policy: Some(key_attributes_proto::KeyPolicy {
                 key_usage_flags: Some(key_attributes_proto::UsageFlags {
                     export: true,
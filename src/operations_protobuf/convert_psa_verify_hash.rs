@@ -1,6 +1,10 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::psa_verify_hash::{Operation as OperationProto, Result as ResultProto};
+use super::generated_ops::psa_verify_hash::Result as ResultProto;
+use super::generated_ops::psa_verify_hash_ext::{
+    operation::Key as KeyProto, Operation as OperationProto,
+};
+use crate::operations::key_reference::KeyReference;
 use crate::operations::psa_verify_hash::{Operation, Result};
 use crate::requests::ResponseStatus;
 use log::error;
@@ -14,7 +18,14 @@ impl TryFrom<OperationProto> for Operation {
         let hash = Zeroizing::new(proto_op.hash);
         let signature = Zeroizing::new(proto_op.signature);
         Ok(Operation {
-            key_name: proto_op.key_name,
+            key: match proto_op.key {
+                Some(KeyProto::KeyName(key_name)) => KeyReference::Name(key_name),
+                Some(KeyProto::PublicKey(public_key)) => {
+                    KeyReference::PublicKey(public_key.try_into()?)
+                }
+                // An empty key name is not encoded on the wire.
+                None => KeyReference::Name(String::new()),
+            },
             alg: proto_op
                 .alg
                 .ok_or_else(|| {
@@ -34,7 +45,14 @@ impl TryFrom<Operation> for OperationProto {
     fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
         let alg = Some(op.alg.try_into()?);
         Ok(OperationProto {
-            key_name: op.key_name,
+            key: match op.key {
+                // Keep the encoding of an empty key name, which is not written on the wire.
+                KeyReference::Name(key_name) if key_name.is_empty() => None,
+                KeyReference::Name(key_name) => Some(KeyProto::KeyName(key_name)),
+                KeyReference::PublicKey(public_key) => {
+                    Some(KeyProto::PublicKey(public_key.try_into()?))
+                }
+            },
             alg,
             hash: op.hash.to_vec(),
             signature: op.signature.to_vec(),
@@ -61,11 +79,14 @@ impl TryFrom<Result> for ResultProto {
 #[cfg(test)]
 mod test {
     use super::super::generated_ops::psa_algorithm as algorithm_proto;
-    use super::super::generated_ops::psa_verify_hash::{
-        Operation as OperationProto, Result as ResultProto,
+    use super::super::generated_ops::psa_verify_hash::Result as ResultProto;
+    use super::super::generated_ops::psa_verify_hash_ext::{
+        operation::Key as KeyProto, Operation as OperationProto,
     };
     use super::super::{Convert, ProtobufConverter};
-    use crate::operations::psa_algorithm::AsymmetricSignature;
+    use crate::operations::key_reference::{KeyReference, PublicKey};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
     use crate::operations::psa_verify_hash::{Operation, Result};
     use crate::operations::{NativeOperation, NativeResult};
     use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
@@ -93,13 +114,13 @@ mod test {
                 ),
             ),
         });
-        proto.key_name = key_name.clone();
+        proto.key = Some(KeyProto::KeyName(key_name.clone()));
         proto.signature = signature.clone();
 
         let op: Operation = proto.try_into().expect("Failed to convert");
 
         assert_eq!(op.hash, hash.into());
-        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.key, KeyReference::Name(key_name));
         assert_eq!(op.signature, signature.into());
     }
 
@@ -112,17 +133,33 @@ mod test {
         let op = Operation {
             hash: hash.clone().into(),
             alg: AsymmetricSignature::RsaPkcs1v15SignRaw,
-            key_name: key_name.clone(),
+            key: key_name.clone().into(),
             signature: signature.clone().into(),
         };
 
         let proto: OperationProto = op.try_into().expect("Failed to convert");
 
         assert_eq!(proto.hash, hash);
-        assert_eq!(proto.key_name, key_name);
+        assert_eq!(proto.key, Some(KeyProto::KeyName(key_name)));
         assert_eq!(proto.signature, signature);
     }
 
+    #[test]
+    fn empty_key_name() {
+        let op = Operation {
+            hash: vec![0x11, 0x22, 0x33].into(),
+            alg: AsymmetricSignature::RsaPkcs1v15SignRaw,
+            key: KeyReference::from(""),
+            signature: vec![0x11, 0x22, 0x33].into(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        assert_eq!(proto.key, None);
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.key, KeyReference::from(""));
+    }
+
     #[test]
     fn asym_proto_to_resp() {
         let proto: ResultProto = Default::default();
@@ -142,7 +179,7 @@ mod test {
         let op = Operation {
             hash: vec![0x11, 0x22, 0x33].into(),
             alg: AsymmetricSignature::RsaPkcs1v15SignRaw,
-            key_name: "test name".to_string(),
+            key: KeyReference::from("test name"),
             signature: vec![0x11, 0x22, 0x33].into(),
         };
         let body = CONVERTER
@@ -154,6 +191,47 @@ mod test {
             .is_ok());
     }
 
+    #[test]
+    fn op_inline_public_key_e2e() {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_verify_hash().set_verify_message();
+        let public_key = PublicKey {
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::RsaPublicKey,
+                bits: 2048,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::RsaPkcs1v15SignRaw,
+                    ),
+                },
+            },
+            data: vec![0x30, 0x06, 0x02, 0x01, 0x05, 0x02, 0x01, 0x03],
+        };
+        let op = Operation {
+            hash: vec![0x11, 0x22, 0x33].into(),
+            alg: AsymmetricSignature::RsaPkcs1v15SignRaw,
+            key: public_key.clone().into(),
+            signature: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaVerifyHash(op))
+            .expect("Failed to convert request");
+        // The body starts with the public key (field 5) in place of the key name
+        assert_eq!(body.bytes()[0], 0x2a);
+
+        match CONVERTER
+            .body_to_operation(body, Opcode::PsaVerifyHash)
+            .expect("Failed to convert back to operation")
+        {
+            NativeOperation::PsaVerifyHash(op) => {
+                assert_eq!(op.key, KeyReference::PublicKey(public_key))
+            }
+            _ => panic!("Expected PsaVerifyHash"),
+        }
+    }
+
     #[test]
     fn resp_asym_sign_e2e() {
         let result = Result {};
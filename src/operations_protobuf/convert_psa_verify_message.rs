@@ -1,8 +1,10 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::psa_verify_message::{
-    Operation as OperationProto, Result as ResultProto,
+use super::generated_ops::psa_verify_message::Result as ResultProto;
+use super::generated_ops::psa_verify_message_ext::{
+    operation::Key as KeyProto, Operation as OperationProto,
 };
+use crate::operations::key_reference::KeyReference;
 use crate::operations::psa_verify_message::{Operation, Result};
 use crate::requests::ResponseStatus;
 use log::error;
@@ -16,7 +18,14 @@ impl TryFrom<OperationProto> for Operation {
         let message = Zeroizing::new(proto_op.message);
         let signature = Zeroizing::new(proto_op.signature);
         Ok(Operation {
-            key_name: proto_op.key_name,
+            key: match proto_op.key {
+                Some(KeyProto::KeyName(key_name)) => KeyReference::Name(key_name),
+                Some(KeyProto::PublicKey(public_key)) => {
+                    KeyReference::PublicKey(public_key.try_into()?)
+                }
+                // An empty key name is not encoded on the wire.
+                None => KeyReference::Name(String::new()),
+            },
             alg: proto_op
                 .alg
                 .ok_or_else(|| {
@@ -36,7 +45,14 @@ impl TryFrom<Operation> for OperationProto {
     fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
         let alg = Some(op.alg.try_into()?);
         Ok(OperationProto {
-            key_name: op.key_name,
+            key: match op.key {
+                // Keep the encoding of an empty key name, which is not written on the wire.
+                KeyReference::Name(key_name) if key_name.is_empty() => None,
+                KeyReference::Name(key_name) => Some(KeyProto::KeyName(key_name)),
+                KeyReference::PublicKey(public_key) => {
+                    Some(KeyProto::PublicKey(public_key.try_into()?))
+                }
+            },
             alg,
             message: op.message.to_vec(),
             signature: op.signature.to_vec(),
@@ -63,10 +79,12 @@ impl TryFrom<Result> for ResultProto {
 #[cfg(test)]
 mod test {
     use super::super::generated_ops::psa_algorithm as algorithm_proto;
-    use super::super::generated_ops::psa_verify_message::{
-        Operation as OperationProto, Result as ResultProto,
+    use super::super::generated_ops::psa_verify_message::Result as ResultProto;
+    use super::super::generated_ops::psa_verify_message_ext::{
+        operation::Key as KeyProto, Operation as OperationProto,
     };
     use super::super::{Convert, ProtobufConverter};
+    use crate::operations::key_reference::KeyReference;
     use crate::operations::psa_algorithm::AsymmetricSignature;
     use crate::operations::psa_verify_message::{Operation, Result};
     use crate::operations::{NativeOperation, NativeResult};
@@ -95,13 +113,13 @@ mod test {
                 ),
             ),
         });
-        proto.key_name = key_name.clone();
+        proto.key = Some(KeyProto::KeyName(key_name.clone()));
         proto.signature = signature.clone();
 
         let op: Operation = proto.try_into().expect("Failed to convert");
 
         assert_eq!(op.message, hash.into());
-        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.key, KeyReference::Name(key_name));
         assert_eq!(op.signature, signature.into());
     }
 
@@ -114,14 +132,14 @@ mod test {
         let op = Operation {
             message: hash.clone().into(),
             alg: AsymmetricSignature::RsaPkcs1v15SignRaw,
-            key_name: key_name.clone(),
+            key: key_name.clone().into(),
             signature: signature.clone().into(),
         };
 
         let proto: OperationProto = op.try_into().expect("Failed to convert");
 
         assert_eq!(proto.message, hash);
-        assert_eq!(proto.key_name, key_name);
+        assert_eq!(proto.key, Some(KeyProto::KeyName(key_name)));
         assert_eq!(proto.signature, signature);
     }
 
@@ -144,7 +162,7 @@ mod test {
         let op = Operation {
             message: vec![0x11, 0x22, 0x33].into(),
             alg: AsymmetricSignature::RsaPkcs1v15SignRaw,
-            key_name: "test name".to_string(),
+            key: KeyReference::from("test name"),
             signature: vec![0x11, 0x22, 0x33].into(),
         };
         let body = CONVERTER
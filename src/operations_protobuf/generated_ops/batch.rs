@@ -0,0 +1,28 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperationEntry {
+    #[prost(uint32, tag="1")]
+    pub opcode: u32,
+    #[prost(bytes="vec", tag="2")]
+    pub body: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(bool, tag="1")]
+    pub continue_on_error: bool,
+    #[prost(message, repeated, tag="2")]
+    pub operations: ::prost::alloc::vec::Vec<OperationEntry>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResultEntry {
+    #[prost(uint32, tag="1")]
+    pub opcode: u32,
+    #[prost(uint32, tag="2")]
+    pub status: u32,
+    #[prost(bytes="vec", tag="3")]
+    pub body: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(message, repeated, tag="1")]
+    pub results: ::prost::alloc::vec::Vec<ResultEntry>,
+}
@@ -0,0 +1,10 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(message, repeated, tag="1")]
+    pub checks: ::prost::alloc::vec::Vec<super::can_do_crypto::Operation>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(uint32, repeated, tag="1")]
+    pub statuses: ::prost::alloc::vec::Vec<u32>,
+}
@@ -0,0 +1,9 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ErrorDetails {
+    #[prost(string, tag="1")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub field_path: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag="3")]
+    pub provider_code: ::core::option::Option<i64>,
+}
@@ -0,0 +1,19 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientKeyInfo {
+    #[prost(string, tag="1")]
+    pub client: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub key_info: ::core::option::Option<super::list_keys_ext::KeyInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, optional, tag="1")]
+    pub client: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag="2")]
+    pub provider_id: ::core::option::Option<u32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(message, repeated, tag="1")]
+    pub keys: ::prost::alloc::vec::Vec<ClientKeyInfo>,
+}
@@ -0,0 +1,32 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyTypeCapability {
+    #[prost(message, optional, tag="1")]
+    pub key_type: ::core::option::Option<super::psa_key_attributes::KeyType>,
+    #[prost(uint32, tag="2")]
+    pub min_bits: u32,
+    #[prost(uint32, tag="3")]
+    pub max_bits: u32,
+    #[prost(message, repeated, tag="4")]
+    pub algorithms: ::prost::alloc::vec::Vec<super::psa_algorithm::Algorithm>,
+    #[prost(message, repeated, tag="5")]
+    pub usage_flags: ::prost::alloc::vec::Vec<super::psa_key_attributes::UsageFlags>,
+    #[prost(enumeration="super::can_do_crypto::CheckType", repeated, tag="6")]
+    pub check_types: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProviderCapabilities {
+    #[prost(uint32, tag="1")]
+    pub provider_id: u32,
+    #[prost(message, repeated, tag="2")]
+    pub key_types: ::prost::alloc::vec::Vec<KeyTypeCapability>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(uint32, optional, tag="1")]
+    pub provider_id: ::core::option::Option<u32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(message, repeated, tag="1")]
+    pub providers: ::prost::alloc::vec::Vec<ProviderCapabilities>,
+}
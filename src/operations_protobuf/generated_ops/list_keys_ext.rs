@@ -0,0 +1,33 @@
+/// list_keys.KeyInfo with the lifetime of the key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyInfo {
+    #[prost(uint32, tag="1")]
+    pub provider_id: u32,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub attributes: ::core::option::Option<super::psa_key_attributes_ext::KeyAttributes>,
+}
+/// list_keys.Operation with filters and pagination.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(uint32, optional, tag="1")]
+    pub provider_id: ::core::option::Option<u32>,
+    #[prost(message, optional, tag="2")]
+    pub key_type: ::core::option::Option<super::psa_key_attributes::KeyType>,
+    #[prost(message, optional, tag="3")]
+    pub usage_flags: ::core::option::Option<super::psa_key_attributes::UsageFlags>,
+    #[prost(string, optional, tag="4")]
+    pub name_prefix: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag="5")]
+    pub page_size: ::core::option::Option<u32>,
+    #[prost(string, optional, tag="6")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(message, repeated, tag="1")]
+    pub keys: ::prost::alloc::vec::Vec<KeyInfo>,
+    #[prost(string, optional, tag="2")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::string::String>,
+}
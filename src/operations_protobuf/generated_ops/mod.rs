@@ -23,6 +23,7 @@ pub mod list_authenticators;
 pub mod list_keys;
 pub mod list_clients;
 pub mod delete_client;
+pub mod list_all_keys;
 pub mod ping;
 pub mod psa_key_attributes;
 pub mod psa_algorithm;
@@ -31,8 +32,24 @@ pub mod psa_hash_compute;
 pub mod psa_hash_compare;
 pub mod psa_raw_key_agreement;
 pub mod can_do_crypto;
+pub mod psa_copy_key;
+pub mod psa_get_key_attributes;
+pub mod psa_close_key;
+pub mod list_capabilities;
+pub mod can_do_crypto_batch;
+pub mod batch;
 pub mod attest_key;
 pub mod prepare_key_attestation;
+pub mod error_details;
+pub mod psa_key_attributes_ext;
+pub mod list_keys_ext;
+pub mod psa_generate_key_ext;
+pub mod psa_import_key_ext;
+pub mod psa_verify_hash_ext;
+pub mod psa_verify_message_ext;
+pub mod psa_asymmetric_encrypt_ext;
+pub mod attest_key_ext;
+pub mod prepare_key_attestation_ext;
 
 use zeroize::Zeroize;
 
@@ -175,6 +192,25 @@ empty_clear_message!(psa_hash_compare::Result);
 empty_clear_message!(can_do_crypto::Operation);
 empty_clear_message!(can_do_crypto::Result);
 empty_clear_message!(prepare_key_attestation::Operation);
+empty_clear_message!(list_all_keys::Operation);
+empty_clear_message!(list_all_keys::Result);
+empty_clear_message!(psa_copy_key::Operation);
+empty_clear_message!(psa_copy_key::Result);
+empty_clear_message!(psa_get_key_attributes::Operation);
+empty_clear_message!(psa_get_key_attributes::Result);
+empty_clear_message!(psa_close_key::Operation);
+empty_clear_message!(psa_close_key::Result);
+empty_clear_message!(error_details::ErrorDetails);
+empty_clear_message!(list_capabilities::Operation);
+empty_clear_message!(list_capabilities::Result);
+empty_clear_message!(can_do_crypto_batch::Operation);
+empty_clear_message!(can_do_crypto_batch::Result);
+empty_clear_message!(list_keys_ext::Operation);
+empty_clear_message!(list_keys_ext::Result);
+empty_clear_message!(psa_generate_key_ext::Operation);
+empty_clear_message!(psa_generate_key_ext::Result);
+empty_clear_message!(psa_import_key_ext::Result);
+empty_clear_message!(prepare_key_attestation_ext::Operation);
 
 impl ClearProtoMessage for psa_sign_hash::Operation {
     fn clear_message(&mut self) {
@@ -214,12 +250,32 @@ impl ClearProtoMessage for psa_verify_message::Operation {
     }
 }
 
+impl ClearProtoMessage for psa_verify_hash_ext::Operation {
+    fn clear_message(&mut self) {
+        self.hash.zeroize();
+        self.signature.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_verify_message_ext::Operation {
+    fn clear_message(&mut self) {
+        self.message.zeroize();
+        self.signature.zeroize();
+    }
+}
+
 impl ClearProtoMessage for psa_import_key::Operation {
     fn clear_message(&mut self) {
         self.data.zeroize();
     }
 }
 
+impl ClearProtoMessage for psa_import_key_ext::Operation {
+    fn clear_message(&mut self) {
+        self.data.zeroize();
+    }
+}
+
 impl ClearProtoMessage for psa_export_public_key::Result {
     fn clear_message(&mut self) {
         self.data.zeroize();
@@ -239,6 +295,13 @@ impl ClearProtoMessage for psa_asymmetric_encrypt::Operation {
     }
 }
 
+impl ClearProtoMessage for psa_asymmetric_encrypt_ext::Operation {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+        self.salt.zeroize();
+    }
+}
+
 impl ClearProtoMessage for psa_asymmetric_decrypt::Operation {
     fn clear_message(&mut self) {
         self.salt.zeroize();
@@ -399,6 +462,98 @@ impl ClearProtoMessage for prepare_key_attestation::Result {
     }
 }
 
+impl ClearProtoMessage for attest_key_ext::Operation {
+    fn clear_message(&mut self) {
+        use attest_key_ext::attestation_mechanism_params::{ActivateCredential, Certify, Mechanism};
+
+        if let Some(attest_key_ext::AttestationMechanismParams {
+            mechanism: Some(mechanism),
+        }) = &mut self.parameters
+        {
+            match mechanism {
+                Mechanism::ActivateCredential(ActivateCredential {
+                    credential_blob,
+                    secret,
+                }) => {
+                    credential_blob.zeroize();
+                    secret.zeroize();
+                }
+                Mechanism::Certify(Certify { nonce }) => nonce.zeroize(),
+            }
+        }
+    }
+}
+
+impl ClearProtoMessage for attest_key_ext::Result {
+    fn clear_message(&mut self) {
+        use attest_key_ext::attestation_output::{ActivateCredential, Certify, Mechanism};
+
+        if let Some(attest_key_ext::AttestationOutput {
+            mechanism: Some(mechanism),
+        }) = &mut self.output
+        {
+            match mechanism {
+                Mechanism::ActivateCredential(ActivateCredential { credential }) => {
+                    credential.zeroize()
+                }
+                Mechanism::Certify(Certify {
+                    attestation_data,
+                    signature,
+                }) => {
+                    attestation_data.zeroize();
+                    signature.zeroize();
+                }
+            }
+        }
+    }
+}
+
+impl ClearProtoMessage for prepare_key_attestation_ext::Result {
+    fn clear_message(&mut self) {
+        use prepare_key_attestation_ext::prepare_key_attestation_output::{
+            ActivateCredential, Certify, Mechanism,
+        };
+
+        if let Some(prepare_key_attestation_ext::PrepareKeyAttestationOutput {
+            mechanism: Some(mechanism),
+        }) = &mut self.output
+        {
+            match mechanism {
+                Mechanism::ActivateCredential(ActivateCredential {
+                    name,
+                    public,
+                    attesting_key_pub,
+                })
+                | Mechanism::Certify(Certify {
+                    name,
+                    public,
+                    attesting_key_pub,
+                }) => {
+                    name.zeroize();
+                    public.zeroize();
+                    attesting_key_pub.zeroize();
+                }
+            }
+        }
+    }
+}
+
+impl ClearProtoMessage for batch::Operation {
+    fn clear_message(&mut self) {
+        for entry in self.operations.iter_mut() {
+            entry.body.zeroize();
+        }
+    }
+}
+
+impl ClearProtoMessage for batch::Result {
+    fn clear_message(&mut self) {
+        for entry in self.results.iter_mut() {
+            entry.body.zeroize();
+        }
+    }
+}
+
 #[test]
 fn i32_conversions() {
     assert_eq!(
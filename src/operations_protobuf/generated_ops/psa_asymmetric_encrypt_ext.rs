@@ -0,0 +1,22 @@
+/// psa_asymmetric_encrypt.Operation with a public key to use instead of the name of a stored key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(message, optional, tag="2")]
+    pub alg: ::core::option::Option<super::psa_algorithm::algorithm::AsymmetricEncryption>,
+    #[prost(bytes="vec", tag="3")]
+    pub plaintext: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub salt: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="operation::Key", tags="1, 5")]
+    pub key: ::core::option::Option<operation::Key>,
+}
+/// Nested message and enum types in `Operation`.
+pub mod operation {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Key {
+        #[prost(string, tag="1")]
+        KeyName(::prost::alloc::string::String),
+        #[prost(message, tag="5")]
+        PublicKey(super::super::psa_key_attributes_ext::PublicKey),
+    }
+}
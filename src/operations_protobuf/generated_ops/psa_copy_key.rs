@@ -0,0 +1,12 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub source_key_name: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub target_key_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub attributes: ::core::option::Option<super::psa_key_attributes_ext::KeyAttributes>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+}
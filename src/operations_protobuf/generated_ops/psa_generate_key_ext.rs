@@ -0,0 +1,14 @@
+/// psa_generate_key.Operation with the lifetime of the key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub attributes: ::core::option::Option<super::psa_key_attributes_ext::KeyAttributes>,
+}
+/// psa_generate_key.Result with the handle of volatile keys.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(string, optional, tag="1")]
+    pub key_handle: ::core::option::Option<::prost::alloc::string::String>,
+}
@@ -0,0 +1,12 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(uint32, tag="1")]
+    pub provider_id: u32,
+    #[prost(message, optional, tag="2")]
+    pub attributes: ::core::option::Option<super::psa_key_attributes_ext::KeyAttributes>,
+}
@@ -0,0 +1,16 @@
+/// psa_import_key.Operation with the lifetime of the key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub attributes: ::core::option::Option<super::psa_key_attributes_ext::KeyAttributes>,
+    #[prost(bytes="vec", tag="3")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// psa_import_key.Result with the handle of volatile keys.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(string, optional, tag="1")]
+    pub key_handle: ::core::option::Option<::prost::alloc::string::String>,
+}
@@ -0,0 +1,20 @@
+/// psa_key_attributes.KeyAttributes with the lifetime of the key. Keys are persistent when it is
+/// not set.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyAttributes {
+    #[prost(message, optional, tag="1")]
+    pub key_type: ::core::option::Option<super::psa_key_attributes::KeyType>,
+    #[prost(uint32, tag="2")]
+    pub key_bits: u32,
+    #[prost(message, optional, tag="3")]
+    pub key_policy: ::core::option::Option<super::psa_key_attributes::KeyPolicy>,
+    #[prost(uint32, optional, tag="4")]
+    pub key_lifetime: ::core::option::Option<u32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PublicKey {
+    #[prost(message, optional, tag="1")]
+    pub attributes: ::core::option::Option<KeyAttributes>,
+    #[prost(bytes="vec", tag="2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
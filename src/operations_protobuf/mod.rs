@@ -23,6 +23,7 @@ mod convert_list_authenticators;
 mod convert_list_keys;
 mod convert_list_clients;
 mod convert_delete_client;
+mod convert_list_all_keys;
 mod convert_psa_asymmetric_encrypt;
 mod convert_psa_asymmetric_decrypt;
 mod convert_psa_aead_encrypt;
@@ -32,47 +33,68 @@ mod convert_psa_cipher_decrypt;
 mod convert_psa_generate_random;
 mod convert_psa_raw_key_agreement;
 mod convert_can_do_crypto;
+mod convert_psa_copy_key;
+mod convert_psa_get_key_attributes;
+mod convert_psa_close_key;
+mod convert_list_capabilities;
+mod convert_can_do_crypto_batch;
+mod convert_batch;
 mod convert_attest_key;
 mod convert_prepare_key_attestation;
+mod convert_error_details;
 
 #[rustfmt::skip]
-#[allow(unused_qualifications, missing_copy_implementations, clippy::pedantic, clippy::module_inception, clippy::upper_case_acronyms, clippy::enum_variant_names)]
+#[allow(dead_code, unused_qualifications, missing_copy_implementations, clippy::pedantic, clippy::module_inception, clippy::upper_case_acronyms, clippy::enum_variant_names)]
 mod generated_ops;
 
 use crate::operations::{Convert, NativeOperation, NativeResult};
+use crate::requests::response::ErrorDetails;
 use crate::requests::{
     request::RequestBody, response::ResponseBody, BodyType, Opcode, ResponseStatus, Result,
 };
-use generated_ops::attest_key as attest_key_proto;
+use generated_ops::attest_key_ext as attest_key_proto;
 use generated_ops::can_do_crypto as can_do_crypto_proto;
+use generated_ops::can_do_crypto_batch as can_do_crypto_batch_proto;
 use generated_ops::delete_client as delete_client_proto;
+use generated_ops::error_details as error_details_proto;
+use generated_ops::list_all_keys as list_all_keys_proto;
 use generated_ops::list_authenticators as list_authenticators_proto;
+use generated_ops::list_capabilities as list_capabilities_proto;
 use generated_ops::list_clients as list_clients_proto;
-use generated_ops::list_keys as list_keys_proto;
+use generated_ops::list_keys_ext as list_keys_proto;
 use generated_ops::list_opcodes as list_opcodes_proto;
 use generated_ops::list_providers as list_providers_proto;
 use generated_ops::ping as ping_proto;
-use generated_ops::prepare_key_attestation as prepare_key_attestation_proto;
+use generated_ops::prepare_key_attestation_ext as prepare_key_attestation_proto;
 use generated_ops::psa_aead_decrypt as psa_aead_decrypt_proto;
 use generated_ops::psa_aead_encrypt as psa_aead_encrypt_proto;
 use generated_ops::psa_asymmetric_decrypt as psa_asymmetric_decrypt_proto;
 use generated_ops::psa_asymmetric_encrypt as psa_asymmetric_encrypt_proto;
+use generated_ops::psa_asymmetric_encrypt_ext as psa_asymmetric_encrypt_ext_proto;
 use generated_ops::psa_cipher_decrypt as psa_cipher_decrypt_proto;
 use generated_ops::psa_cipher_encrypt as psa_cipher_encrypt_proto;
+use generated_ops::psa_close_key as psa_close_key_proto;
+use generated_ops::psa_copy_key as psa_copy_key_proto;
 use generated_ops::psa_destroy_key as psa_destroy_key_proto;
 use generated_ops::psa_export_key as psa_export_key_proto;
 use generated_ops::psa_export_public_key as psa_export_public_key_proto;
-use generated_ops::psa_generate_key as psa_generate_key_proto;
+use generated_ops::psa_generate_key_ext as psa_generate_key_proto;
 use generated_ops::psa_generate_random as psa_generate_random_proto;
+use generated_ops::psa_get_key_attributes as psa_get_key_attributes_proto;
 use generated_ops::psa_hash_compare as psa_hash_compare_proto;
 use generated_ops::psa_hash_compute as psa_hash_compute_proto;
-use generated_ops::psa_import_key as psa_import_key_proto;
+use generated_ops::psa_import_key_ext as psa_import_key_proto;
 use generated_ops::psa_raw_key_agreement as psa_raw_key_agreement_proto;
 use generated_ops::psa_sign_hash as psa_sign_hash_proto;
 use generated_ops::psa_sign_message as psa_sign_message_proto;
 use generated_ops::psa_verify_hash as psa_verify_hash_proto;
+use generated_ops::psa_verify_hash_ext as psa_verify_hash_ext_proto;
 use generated_ops::psa_verify_message as psa_verify_message_proto;
+use generated_ops::psa_verify_message_ext as psa_verify_message_ext_proto;
 use generated_ops::ClearProtoMessage;
+
+pub(crate) use convert_batch::body_to_entry_lens as batch_body_to_entry_lens;
+pub(crate) use convert_batch::body_to_opcodes as batch_body_to_opcodes;
 use prost::Message;
 use std::convert::TryInto;
 
@@ -135,6 +157,10 @@ impl Convert for ProtobufConverter {
                 body.bytes(),
                 delete_client_proto::Operation
             ))),
+            Opcode::ListAllKeys => Ok(NativeOperation::ListAllKeys(wire_to_native!(
+                body.bytes(),
+                list_all_keys_proto::Operation
+            ))),
             Opcode::Ping => Ok(NativeOperation::Ping(wire_to_native!(
                 body.bytes(),
                 ping_proto::Operation
@@ -165,7 +191,7 @@ impl Convert for ProtobufConverter {
             ))),
             Opcode::PsaVerifyHash => Ok(NativeOperation::PsaVerifyHash(wire_to_native!(
                 body.bytes(),
-                psa_verify_hash_proto::Operation
+                psa_verify_hash_ext_proto::Operation
             ))),
             Opcode::PsaSignMessage => Ok(NativeOperation::PsaSignMessage(wire_to_native!(
                 body.bytes(),
@@ -173,10 +199,10 @@ impl Convert for ProtobufConverter {
             ))),
             Opcode::PsaVerifyMessage => Ok(NativeOperation::PsaVerifyMessage(wire_to_native!(
                 body.bytes(),
-                psa_verify_message_proto::Operation
+                psa_verify_message_ext_proto::Operation
             ))),
             Opcode::PsaAsymmetricEncrypt => Ok(NativeOperation::PsaAsymmetricEncrypt(
-                wire_to_native!(body.bytes(), psa_asymmetric_encrypt_proto::Operation),
+                wire_to_native!(body.bytes(), psa_asymmetric_encrypt_ext_proto::Operation),
             )),
             Opcode::PsaAsymmetricDecrypt => Ok(NativeOperation::PsaAsymmetricDecrypt(
                 wire_to_native!(body.bytes(), psa_asymmetric_decrypt_proto::Operation),
@@ -217,6 +243,29 @@ impl Convert for ProtobufConverter {
                 body.bytes(),
                 can_do_crypto_proto::Operation
             ))),
+            Opcode::PsaCopyKey => Ok(NativeOperation::PsaCopyKey(wire_to_native!(
+                body.bytes(),
+                psa_copy_key_proto::Operation
+            ))),
+            Opcode::PsaGetKeyAttributes => Ok(NativeOperation::PsaGetKeyAttributes(
+                wire_to_native!(body.bytes(), psa_get_key_attributes_proto::Operation),
+            )),
+            Opcode::PsaCloseKey => Ok(NativeOperation::PsaCloseKey(wire_to_native!(
+                body.bytes(),
+                psa_close_key_proto::Operation
+            ))),
+            Opcode::ListCapabilities => Ok(NativeOperation::ListCapabilities(wire_to_native!(
+                body.bytes(),
+                list_capabilities_proto::Operation
+            ))),
+            Opcode::CanDoCryptoBatch => Ok(NativeOperation::CanDoCryptoBatch(wire_to_native!(
+                body.bytes(),
+                can_do_crypto_batch_proto::Operation
+            ))),
+            Opcode::Batch => Ok(NativeOperation::Batch(convert_batch::body_to_operation(
+                self,
+                body.bytes(),
+            )?)),
             Opcode::AttestKey => Ok(NativeOperation::AttestKey(wire_to_native!(
                 body.bytes(),
                 attest_key_proto::Operation
@@ -248,6 +297,9 @@ impl Convert for ProtobufConverter {
             NativeOperation::DeleteClient(operation) => Ok(RequestBody::from_bytes(
                 native_to_wire!(operation, delete_client_proto::Operation),
             )),
+            NativeOperation::ListAllKeys(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, list_all_keys_proto::Operation),
+            )),
             NativeOperation::Ping(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
                 operation,
                 ping_proto::Operation
@@ -271,16 +323,16 @@ impl Convert for ProtobufConverter {
                 native_to_wire!(operation, psa_sign_hash_proto::Operation),
             )),
             NativeOperation::PsaVerifyHash(operation) => Ok(RequestBody::from_bytes(
-                native_to_wire!(operation, psa_verify_hash_proto::Operation),
+                native_to_wire!(operation, psa_verify_hash_ext_proto::Operation),
             )),
             NativeOperation::PsaSignMessage(operation) => Ok(RequestBody::from_bytes(
                 native_to_wire!(operation, psa_sign_message_proto::Operation),
             )),
             NativeOperation::PsaVerifyMessage(operation) => Ok(RequestBody::from_bytes(
-                native_to_wire!(operation, psa_verify_message_proto::Operation),
+                native_to_wire!(operation, psa_verify_message_ext_proto::Operation),
             )),
             NativeOperation::PsaAsymmetricEncrypt(operation) => Ok(RequestBody::from_bytes(
-                native_to_wire!(operation, psa_asymmetric_encrypt_proto::Operation),
+                native_to_wire!(operation, psa_asymmetric_encrypt_ext_proto::Operation),
             )),
             NativeOperation::PsaAsymmetricDecrypt(operation) => Ok(RequestBody::from_bytes(
                 native_to_wire!(operation, psa_asymmetric_decrypt_proto::Operation),
@@ -312,6 +364,25 @@ impl Convert for ProtobufConverter {
             NativeOperation::CanDoCrypto(operation) => Ok(RequestBody::from_bytes(
                 native_to_wire!(operation, can_do_crypto_proto::Operation),
             )),
+            NativeOperation::PsaCopyKey(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
+                operation,
+                psa_copy_key_proto::Operation
+            ))),
+            NativeOperation::PsaGetKeyAttributes(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_get_key_attributes_proto::Operation),
+            )),
+            NativeOperation::PsaCloseKey(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_close_key_proto::Operation),
+            )),
+            NativeOperation::ListCapabilities(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, list_capabilities_proto::Operation),
+            )),
+            NativeOperation::CanDoCryptoBatch(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, can_do_crypto_batch_proto::Operation),
+            )),
+            NativeOperation::Batch(operation) => Ok(RequestBody::from_bytes(
+                convert_batch::operation_to_body(self, operation)?,
+            )),
             NativeOperation::AttestKey(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
                 operation,
                 attest_key_proto::Operation
@@ -348,6 +419,10 @@ impl Convert for ProtobufConverter {
                 body.bytes(),
                 delete_client_proto::Result
             ))),
+            Opcode::ListAllKeys => Ok(NativeResult::ListAllKeys(wire_to_native!(
+                body.bytes(),
+                list_all_keys_proto::Result
+            ))),
             Opcode::Ping => Ok(NativeResult::Ping(wire_to_native!(
                 body.bytes(),
                 ping_proto::Result
@@ -430,6 +505,30 @@ impl Convert for ProtobufConverter {
                 body.bytes(),
                 can_do_crypto_proto::Result
             ))),
+            Opcode::PsaCopyKey => Ok(NativeResult::PsaCopyKey(wire_to_native!(
+                body.bytes(),
+                psa_copy_key_proto::Result
+            ))),
+            Opcode::PsaGetKeyAttributes => Ok(NativeResult::PsaGetKeyAttributes(wire_to_native!(
+                body.bytes(),
+                psa_get_key_attributes_proto::Result
+            ))),
+            Opcode::PsaCloseKey => Ok(NativeResult::PsaCloseKey(wire_to_native!(
+                body.bytes(),
+                psa_close_key_proto::Result
+            ))),
+            Opcode::ListCapabilities => Ok(NativeResult::ListCapabilities(wire_to_native!(
+                body.bytes(),
+                list_capabilities_proto::Result
+            ))),
+            Opcode::CanDoCryptoBatch => Ok(NativeResult::CanDoCryptoBatch(wire_to_native!(
+                body.bytes(),
+                can_do_crypto_batch_proto::Result
+            ))),
+            Opcode::Batch => Ok(NativeResult::Batch(convert_batch::body_to_result(
+                self,
+                body.bytes(),
+            )?)),
             Opcode::AttestKey => Ok(NativeResult::AttestKey(wire_to_native!(
                 body.bytes(),
                 attest_key_proto::Result
@@ -465,6 +564,10 @@ impl Convert for ProtobufConverter {
                 result,
                 delete_client_proto::Result
             ))),
+            NativeResult::ListAllKeys(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                list_all_keys_proto::Result
+            ))),
             NativeResult::Ping(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
                 result,
                 ping_proto::Result
@@ -541,6 +644,26 @@ impl Convert for ProtobufConverter {
                 result,
                 can_do_crypto_proto::Result
             ))),
+            NativeResult::PsaCopyKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_copy_key_proto::Result
+            ))),
+            NativeResult::PsaGetKeyAttributes(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_get_key_attributes_proto::Result),
+            )),
+            NativeResult::PsaCloseKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_close_key_proto::Result
+            ))),
+            NativeResult::ListCapabilities(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, list_capabilities_proto::Result),
+            )),
+            NativeResult::CanDoCryptoBatch(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, can_do_crypto_batch_proto::Result),
+            )),
+            NativeResult::Batch(result) => Ok(ResponseBody::from_bytes(
+                convert_batch::result_to_body(self, result)?,
+            )),
             NativeResult::AttestKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
                 result,
                 attest_key_proto::Result
@@ -550,4 +673,18 @@ impl Convert for ProtobufConverter {
             )),
         }
     }
+
+    fn error_details_to_body(&self, details: ErrorDetails) -> Result<ResponseBody> {
+        Ok(ResponseBody::from_bytes(native_to_wire!(
+            details,
+            error_details_proto::ErrorDetails
+        )))
+    }
+
+    fn body_to_error_details(&self, body: ResponseBody) -> Result<ErrorDetails> {
+        Ok(wire_to_native!(
+            body.bytes(),
+            error_details_proto::ErrorDetails
+        ))
+    }
 }
@@ -6,3 +6,13 @@
 pub mod wire_header_1_0;
 
 const MAGIC_NUMBER: u32 = 0x5EC0_A710;
+
+/// Bit of the header `flags` field signalling response authentication.
+///
+/// In a request, it asks the service to authenticate its response and signals that a nonce of
+/// `RESPONSE_AUTH_NONCE_LEN` bytes follows the authentication field. In a response, it signals
+/// that an authentication trailer of `auth_len` bytes follows the body.
+pub const RESPONSE_AUTH_FLAG: u16 = 0x0001;
+
+/// Length in bytes of the nonce of requests asking for the authentication of their response.
+pub const RESPONSE_AUTH_NONCE_LEN: usize = 16;
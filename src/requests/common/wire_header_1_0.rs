@@ -24,7 +24,9 @@ const REQUEST_HDR_SIZE: u16 = 30;
 #[cfg_attr(feature = "fuzz", derive(Arbitrary))]
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct WireHeader {
-    /// Implementation-defined flags. Not used in Parsec currently. Must be present, but must be zero.
+    /// Implementation-defined flags. Only the `RESPONSE_AUTH_FLAG` bit is defined: in a request it
+    /// signals a nonce of `RESPONSE_AUTH_NONCE_LEN` bytes after the authentication field, in a
+    /// response an authentication trailer after the body. All other bits must be zero.
     pub flags: u16,
     /// Provider ID value
     pub provider: u8,
@@ -12,13 +12,65 @@ pub mod utils;
 pub mod common;
 pub mod request;
 pub mod response;
+pub mod proxy;
+mod read_limits;
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
+use log::debug;
+pub use read_limits::ReadLimits;
 pub use request::Request;
 pub use response::Response;
-pub use response_status::{ResponseStatus, Result};
+pub use response_status::{ResponseStatus, Result, StatusCategory};
 use std::convert::TryFrom;
 use std::fmt;
+use std::str::FromStr;
+
+/// Implement `as_str`, `all` and `FromStr` for an enum from the list of its canonical identifiers
+///
+/// Parsing ignores ASCII case as well as `-`, `_` and space separators, and also accepts the
+/// optional aliases given after the canonical identifier.
+macro_rules! string_identifiers {
+    ($type:ident, $error:expr, { $($variant:ident => $name:literal $(| $alias:literal)*,)* }) => {
+        impl $type {
+            /// Stable machine identifier of the variant
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($type::$variant => $name,)*
+                }
+            }
+
+            /// All the variants, in the order of their codes
+            pub fn all() -> &'static [$type] {
+                &[$($type::$variant,)*]
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = ResponseStatus;
+
+            fn from_str(s: &str) -> Result<Self> {
+                let identifier = normalize_identifier(s);
+                $(
+                    if identifier == normalize_identifier($name)
+                        $(|| identifier == normalize_identifier($alias))*
+                    {
+                        return Ok($type::$variant);
+                    }
+                )*
+                debug!("\"{}\" is not a valid {} identifier.", s, stringify!($type));
+                Err($error)
+            }
+        }
+    };
+}
+
+fn normalize_identifier(identifier: &str) -> String {
+    identifier
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
 
 /// Listing of provider types and their associated codes.
 ///
@@ -65,6 +117,15 @@ impl TryFrom<u8> for ProviderId {
     }
 }
 
+string_identifiers!(ProviderId, ResponseStatus::ProviderDoesNotExist, {
+    Core => "core",
+    MbedCrypto => "mbed-crypto",
+    Pkcs11 => "pkcs11",
+    Tpm => "tpm",
+    TrustedService => "trusted-service",
+    CryptoAuthLib => "cryptoauthlib",
+});
+
 /// Listing of body encoding types and their associated codes.
 ///
 /// Passed in headers as `content_type` and `accept_type`.
@@ -76,6 +137,10 @@ pub enum BodyType {
     Protobuf = 0,
 }
 
+string_identifiers!(BodyType, ResponseStatus::ContentTypeNotSupported, {
+    Protobuf => "protobuf" | "proto",
+});
+
 /// Listing of available operations and their associated opcode.
 ///
 /// Passed in headers as `opcode`. Check the
@@ -143,6 +208,20 @@ pub enum Opcode {
     PrepareKeyAttestation = 0x001F,
     /// CanDoCrypto operation
     CanDoCrypto = 0x0020,
+    /// ListAllKeys operation (admin operation)
+    ListAllKeys = 0x0021,
+    /// PsaCopyKey operation
+    PsaCopyKey = 0x0022,
+    /// PsaGetKeyAttributes operation
+    PsaGetKeyAttributes = 0x0023,
+    /// PsaCloseKey operation
+    PsaCloseKey = 0x0024,
+    /// ListCapabilities operation
+    ListCapabilities = 0x0025,
+    /// CanDoCryptoBatch operation
+    CanDoCryptoBatch = 0x0026,
+    /// Batch operation
+    Batch = 0x0027,
 }
 
 impl Opcode {
@@ -156,7 +235,9 @@ impl Opcode {
             | Opcode::ListAuthenticators
             | Opcode::ListKeys
             | Opcode::ListClients
-            | Opcode::DeleteClient => true,
+            | Opcode::DeleteClient
+            | Opcode::ListAllKeys
+            | Opcode::ListCapabilities => true,
             Opcode::PsaGenerateKey
             | Opcode::PsaDestroyKey
             | Opcode::PsaSignHash
@@ -178,7 +259,12 @@ impl Opcode {
             | Opcode::PsaRawKeyAgreement
             | Opcode::CanDoCrypto
             | Opcode::AttestKey
-            | Opcode::PrepareKeyAttestation => false,
+            | Opcode::PrepareKeyAttestation
+            | Opcode::PsaCopyKey
+            | Opcode::PsaGetKeyAttributes
+            | Opcode::PsaCloseKey
+            | Opcode::CanDoCryptoBatch
+            | Opcode::Batch => false,
         }
     }
 
@@ -186,7 +272,7 @@ impl Opcode {
     pub fn is_admin(&self) -> bool {
         // match to ensure exhaustivity when a new opcode is added
         match self {
-            Opcode::ListClients | Opcode::DeleteClient => true,
+            Opcode::ListClients | Opcode::DeleteClient | Opcode::ListAllKeys => true,
             Opcode::Ping
             | Opcode::ListProviders
             | Opcode::ListOpcodes
@@ -213,7 +299,13 @@ impl Opcode {
             | Opcode::PsaRawKeyAgreement
             | Opcode::CanDoCrypto
             | Opcode::AttestKey
-            | Opcode::PrepareKeyAttestation => false,
+            | Opcode::PrepareKeyAttestation
+            | Opcode::PsaCopyKey
+            | Opcode::PsaGetKeyAttributes
+            | Opcode::PsaCloseKey
+            | Opcode::ListCapabilities
+            | Opcode::CanDoCryptoBatch
+            | Opcode::Batch => false,
         }
     }
 
@@ -223,6 +315,45 @@ impl Opcode {
     }
 }
 
+string_identifiers!(Opcode, ResponseStatus::OpcodeDoesNotExist, {
+    Ping => "Ping",
+    PsaGenerateKey => "PsaGenerateKey",
+    PsaDestroyKey => "PsaDestroyKey",
+    PsaSignHash => "PsaSignHash",
+    PsaVerifyHash => "PsaVerifyHash",
+    PsaImportKey => "PsaImportKey",
+    PsaExportPublicKey => "PsaExportPublicKey",
+    ListProviders => "ListProviders",
+    ListOpcodes => "ListOpcodes",
+    PsaAsymmetricEncrypt => "PsaAsymmetricEncrypt",
+    PsaAsymmetricDecrypt => "PsaAsymmetricDecrypt",
+    PsaExportKey => "PsaExportKey",
+    PsaGenerateRandom => "PsaGenerateRandom",
+    ListAuthenticators => "ListAuthenticators",
+    PsaHashCompute => "PsaHashCompute",
+    PsaHashCompare => "PsaHashCompare",
+    PsaAeadEncrypt => "PsaAeadEncrypt",
+    PsaAeadDecrypt => "PsaAeadDecrypt",
+    PsaRawKeyAgreement => "PsaRawKeyAgreement",
+    PsaCipherEncrypt => "PsaCipherEncrypt",
+    PsaCipherDecrypt => "PsaCipherDecrypt",
+    PsaSignMessage => "PsaSignMessage",
+    PsaVerifyMessage => "PsaVerifyMessage",
+    ListKeys => "ListKeys",
+    ListClients => "ListClients",
+    DeleteClient => "DeleteClient",
+    AttestKey => "AttestKey",
+    PrepareKeyAttestation => "PrepareKeyAttestation",
+    CanDoCrypto => "CanDoCrypto",
+    ListAllKeys => "ListAllKeys",
+    PsaCopyKey => "PsaCopyKey",
+    PsaGetKeyAttributes => "PsaGetKeyAttributes",
+    PsaCloseKey => "PsaCloseKey",
+    ListCapabilities => "ListCapabilities",
+    CanDoCryptoBatch => "CanDoCryptoBatch",
+    Batch => "Batch",
+});
+
 /// Listing of available authentication methods.
 ///
 /// Passed in headers as `auth_type`.
@@ -256,11 +387,101 @@ impl fmt::Display for AuthType {
     }
 }
 
+string_identifiers!(AuthType, ResponseStatus::AuthenticatorDoesNotExist, {
+    NoAuth => "no-auth" | "none",
+    Direct => "direct",
+    Jwt => "jwt",
+    UnixPeerCredentials => "unix-peer-credentials",
+    JwtSvid => "jwt-svid",
+});
+
 #[test]
 fn check_opcode_nature() {
     assert!(Opcode::ListKeys.is_core());
     assert!(!Opcode::ListKeys.is_crypto());
     assert!(Opcode::PsaGenerateKey.is_crypto());
     assert!(Opcode::ListClients.is_admin());
+    assert!(Opcode::ListAllKeys.is_admin());
+    assert!(Opcode::ListAllKeys.is_core());
     assert!(!Opcode::PsaGenerateKey.is_admin());
 }
+
+#[test]
+fn identifiers_round_trip() {
+    fn check<T: FromStr<Err = ResponseStatus> + PartialEq + fmt::Debug + Copy>(
+        all: &[T],
+        as_str: fn(&T) -> &'static str,
+    ) {
+        for variant in all {
+            let name = as_str(variant);
+            assert_eq!(T::from_str(name).unwrap(), *variant);
+            assert_eq!(T::from_str(&name.to_uppercase()).unwrap(), *variant);
+            assert_eq!(all.iter().filter(|other| as_str(other) == name).count(), 1);
+        }
+    }
+    check(ProviderId::all(), ProviderId::as_str);
+    check(BodyType::all(), BodyType::as_str);
+    check(Opcode::all(), Opcode::as_str);
+    check(AuthType::all(), AuthType::as_str);
+}
+
+#[test]
+fn all_variants_listed() {
+    assert_eq!(
+        ProviderId::all().len(),
+        (0..=u8::MAX)
+            .filter(|id| ProviderId::try_from(*id).is_ok())
+            .count()
+    );
+    assert_eq!(
+        AuthType::all().len(),
+        (0..=u8::MAX)
+            .filter(|code| <AuthType as num::FromPrimitive>::from_u8(*code).is_some())
+            .count()
+    );
+    assert_eq!(
+        Opcode::all().len(),
+        (0..=u16::MAX)
+            .filter(|code| <Opcode as num::FromPrimitive>::from_u16(*code).is_some())
+            .count()
+    );
+}
+
+#[test]
+fn identifiers_aliases() {
+    assert_eq!("pkcs11".parse::<ProviderId>().unwrap(), ProviderId::Pkcs11);
+    assert_eq!(
+        "MbedCrypto".parse::<ProviderId>().unwrap(),
+        ProviderId::MbedCrypto
+    );
+    assert_eq!(
+        "psa_sign_hash".parse::<Opcode>().unwrap(),
+        Opcode::PsaSignHash
+    );
+    assert_eq!(
+        "PsaSignHash".parse::<Opcode>().unwrap(),
+        Opcode::PsaSignHash
+    );
+    assert_eq!(
+        "UnixPeerCredentials".parse::<AuthType>().unwrap(),
+        AuthType::UnixPeerCredentials
+    );
+    assert_eq!("none".parse::<AuthType>().unwrap(), AuthType::NoAuth);
+    assert_eq!("proto".parse::<BodyType>().unwrap(), BodyType::Protobuf);
+    assert_eq!(
+        "pkcs 12".parse::<ProviderId>().unwrap_err(),
+        ResponseStatus::ProviderDoesNotExist
+    );
+    assert_eq!(
+        "".parse::<Opcode>().unwrap_err(),
+        ResponseStatus::OpcodeDoesNotExist
+    );
+    assert_eq!(
+        "basic".parse::<AuthType>().unwrap_err(),
+        ResponseStatus::AuthenticatorDoesNotExist
+    );
+    assert_eq!(
+        "json".parse::<BodyType>().unwrap_err(),
+        ResponseStatus::ContentTypeNotSupported
+    );
+}
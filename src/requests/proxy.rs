@@ -0,0 +1,529 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Request forwarding proxy
+//!
+//! A `Proxy` relays one request from a client stream to a service stream, and the response back
+//! to the client. Hooks can inspect and modify the header and authentication of the request,
+//! inspect its body, and modify the header of the response, or deny the request with a
+//! `ResponseStatus` which is then returned to the client without contacting the service.
+//!
+//! Requests and responses are read with lenient headers, so that operations, providers and
+//! authentication types unknown to this version of the crate are relayed unchanged.
+//!
+//! Rewriting the authentication of a request changes the request the service receives. A response
+//! authenticated with the `response-auth` feature covers the request as received by the service,
+//! so the client cannot verify it against the request it sent.
+//!
+//! ```
+//! use parsec_interface::requests::proxy::{AuthRewrite, OpcodeAllowlist, Proxy};
+//! use parsec_interface::requests::{AuthType, Opcode};
+//!
+//! let proxy = Proxy::new(1 << 20)
+//!     .with_hook(OpcodeAllowlist::new(vec![Opcode::Ping, Opcode::PsaSignHash]))
+//!     .with_hook(AuthRewrite::new(
+//!         None,
+//!         AuthType::Direct,
+//!         b"container-1".to_vec(),
+//!     ));
+//! ```
+use super::request::{LenientRequestHeader, Request, RequestAuth, RequestBody};
+use super::response::{LenientResponse, LenientResponseHeader, Response};
+use super::{AuthType, BodyType, Opcode, ReadLimits, ResponseStatus, Result};
+use crate::operations_protobuf::batch_body_to_opcodes;
+use crate::secrecy::{ExposeSecret, Secret};
+use derivative::Derivative;
+use log::error;
+use num::FromPrimitive;
+use std::collections::HashSet;
+use std::io::{Read, Write};
+
+/// Hook called by the proxy on every request and response it relays
+pub trait ProxyHook {
+    /// Inspect or modify the header and authentication of a request, and inspect its body, before
+    /// it is forwarded.
+    ///
+    /// Returning an error denies the request: the status is sent back to the client and the
+    /// request is not forwarded.
+    fn on_request(
+        &mut self,
+        header: &mut LenientRequestHeader,
+        body: &RequestBody,
+        auth: &mut RequestAuth,
+    ) -> Result<()>;
+
+    /// Inspect or modify the header of a response before it is returned to the client.
+    ///
+    /// Returning an error replaces the response with an empty one containing the status.
+    fn on_response(
+        &mut self,
+        _request_header: &LenientRequestHeader,
+        _response_header: &mut LenientResponseHeader,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Hook denying the requests for operations which are not in a list, including the operations
+/// unknown to this version of the crate
+///
+/// If `Opcode::Batch` is allowed, the body of batches is decoded and each of their operations must
+/// be allowed as well. Batches with a content type other than protobuf are denied.
+#[derive(Clone, Debug)]
+pub struct OpcodeAllowlist {
+    opcodes: HashSet<Opcode>,
+}
+
+impl OpcodeAllowlist {
+    /// Create a hook allowing only the given opcodes
+    pub fn new(opcodes: impl IntoIterator<Item = Opcode>) -> Self {
+        OpcodeAllowlist {
+            opcodes: opcodes.into_iter().collect(),
+        }
+    }
+
+    fn check(&self, opcode: u32) -> Result<()> {
+        match FromPrimitive::from_u32(opcode) {
+            Some(opcode) if self.opcodes.contains(&opcode) => Ok(()),
+            _ => {
+                error!("Opcode {:#x} is not allowed by the proxy.", opcode);
+                Err(ResponseStatus::PsaErrorNotPermitted)
+            }
+        }
+    }
+}
+
+impl ProxyHook for OpcodeAllowlist {
+    fn on_request(
+        &mut self,
+        header: &mut LenientRequestHeader,
+        body: &RequestBody,
+        _auth: &mut RequestAuth,
+    ) -> Result<()> {
+        self.check(header.opcode)?;
+        if header.opcode() == Some(Opcode::Batch) {
+            if header.content_type() != Some(BodyType::Protobuf) {
+                error!("The operations of the batch can not be inspected by the proxy.");
+                return Err(ResponseStatus::PsaErrorNotPermitted);
+            }
+            for opcode in batch_body_to_opcodes(body)? {
+                self.check(opcode)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Hook replacing the authentication of requests
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct AuthRewrite {
+    from: Option<AuthType>,
+    auth_type: AuthType,
+    #[derivative(Debug = "ignore")]
+    auth: Secret<Vec<u8>>,
+}
+
+impl AuthRewrite {
+    /// Create a hook setting the authentication type and value of the requests.
+    ///
+    /// If `from` is given, only the requests using this authentication type are rewritten and the
+    /// other requests are denied with `NotAuthenticated`, so that a client cannot pick the
+    /// authentication seen by the service. If `from` is `None`, every request is rewritten.
+    pub fn new(from: Option<AuthType>, auth_type: AuthType, auth: Vec<u8>) -> Self {
+        AuthRewrite {
+            from,
+            auth_type,
+            auth: Secret::new(auth),
+        }
+    }
+}
+
+impl ProxyHook for AuthRewrite {
+    fn on_request(
+        &mut self,
+        header: &mut LenientRequestHeader,
+        _body: &RequestBody,
+        auth: &mut RequestAuth,
+    ) -> Result<()> {
+        if let Some(from) = self.from {
+            if Some(from) != header.auth_type() {
+                error!(
+                    "Request authentication type {} is not rewritten by the proxy.",
+                    header.auth_type
+                );
+                return Err(ResponseStatus::NotAuthenticated);
+            }
+        }
+        header.auth_type = self.auth_type as u8;
+        *auth = RequestAuth::new(self.auth.expose_secret().clone());
+        Ok(())
+    }
+}
+
+/// Relay of requests and responses between a client and the service
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Proxy {
+    limits: ReadLimits,
+    #[derivative(Debug = "ignore")]
+    hooks: Vec<Box<dyn ProxyHook>>,
+}
+
+impl Proxy {
+    /// Create a proxy without hooks, rejecting requests and responses with a body larger than
+    /// `body_len_limit` bytes
+    pub fn new(body_len_limit: usize) -> Self {
+        Proxy {
+            limits: ReadLimits::new(body_len_limit),
+            hooks: Vec::new(),
+        }
+    }
+
+    /// Replace the limits applied when reading requests and responses
+    pub fn with_read_limits(mut self, limits: ReadLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Add a hook, called after the ones already added
+    pub fn with_hook(mut self, hook: impl ProxyHook + 'static) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Relay one request from the client to the service and its response back to the client.
+    ///
+    /// Returns the status of the response sent to the client, which is the status set by the
+    /// denying hook if the request was not forwarded.
+    ///
+    /// # Errors
+    /// - if the request can not be read from the client, or forwarded to the service, or if the
+    ///   response can not be read from the service, a response with the corresponding status is
+    ///   sent to the client, if possible, and the status is returned.
+    /// - if the response can not be written to the client, the corresponding status is returned.
+    pub fn relay(
+        &mut self,
+        client: &mut (impl Read + Write),
+        service: &mut (impl Read + Write),
+    ) -> Result<ResponseStatus> {
+        let mut request = match Request::read_from_stream_lenient(client, &self.limits) {
+            Ok(request) => request,
+            Err(status) => {
+                error!("Failed to read the request from the client ({}).", status);
+                if let Err(write_status) = Response::from_status(status).write_to_stream(client) {
+                    error!(
+                        "Failed to send the response to the client ({}).",
+                        write_status
+                    );
+                }
+                return Err(status);
+            }
+        };
+
+        for hook in self.hooks.iter_mut() {
+            if let Err(status) =
+                hook.on_request(&mut request.header, &request.body, &mut request.auth)
+            {
+                LenientResponse::from_request_header(request.header, status)
+                    .write_to_stream(client)?;
+                return Ok(status);
+            }
+        }
+
+        let header = request.header;
+        let mut response = match request
+            .write_to_stream(service)
+            .and_then(|_| Response::read_from_stream_lenient(service, &self.limits))
+        {
+            Ok(response) => response,
+            Err(status) => {
+                error!("Failed to relay the request to the service ({}).", status);
+                LenientResponse::from_request_header(header, status).write_to_stream(client)?;
+                return Err(status);
+            }
+        };
+
+        for hook in self.hooks.iter_mut() {
+            if let Err(status) = hook.on_response(&header, &mut response.header) {
+                response = LenientResponse::from_request_header(header, status);
+                break;
+            }
+        }
+
+        let status = response.header.status;
+        response.write_to_stream(client)?;
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ProviderId;
+    use super::*;
+    use crate::operations::{batch, psa_destroy_key, psa_export_key, Convert, NativeOperation};
+    use crate::operations_protobuf::ProtobufConverter;
+    use crate::requests::request::RequestHeader;
+    use crate::requests::response::ResponseBody;
+    use std::io::Cursor;
+
+    #[derive(Debug)]
+    struct Duplex {
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>,
+    }
+
+    impl Duplex {
+        fn new(input: Vec<u8>) -> Self {
+            Duplex {
+                input: Cursor::new(input),
+                output: Vec::new(),
+            }
+        }
+    }
+
+    impl Read for Duplex {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.input.read(buf)
+        }
+    }
+
+    impl Write for Duplex {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.output.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn get_request(opcode: Opcode, auth_type: AuthType) -> Vec<u8> {
+        get_request_with_body(opcode, auth_type, RequestBody::from_bytes(vec![1, 2, 3]))
+    }
+
+    fn get_request_with_body(opcode: Opcode, auth_type: AuthType, body: RequestBody) -> Vec<u8> {
+        let request = Request {
+            header: RequestHeader {
+                provider: ProviderId::MbedCrypto,
+                session: 0x42,
+                content_type: BodyType::Protobuf,
+                accept_type: BodyType::Protobuf,
+                auth_type,
+                opcode,
+            },
+            body,
+            auth: RequestAuth::new(vec![0x11; 4]),
+            response_auth_nonce: None,
+        };
+        let mut bytes = Vec::new();
+        request.write_to_stream(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn get_response(opcode: Opcode) -> Vec<u8> {
+        let mut response = Response::from_status(ResponseStatus::Success);
+        response.header.provider = ProviderId::MbedCrypto;
+        response.header.opcode = opcode;
+        response.body = ResponseBody::from_bytes(vec![4, 5]);
+        let mut bytes = Vec::new();
+        response.write_to_stream(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn relay_without_hooks() {
+        let request = get_request(Opcode::PsaSignHash, AuthType::Direct);
+        let response = get_response(Opcode::PsaSignHash);
+        let mut client = Duplex::new(request.clone());
+        let mut service = Duplex::new(response.clone());
+
+        let status = Proxy::new(1024).relay(&mut client, &mut service).unwrap();
+        assert_eq!(status, ResponseStatus::Success);
+        assert_eq!(service.output, request);
+        assert_eq!(client.output, response);
+    }
+
+    #[test]
+    fn relay_with_auth_rewrite() {
+        let mut client = Duplex::new(get_request(
+            Opcode::PsaSignHash,
+            AuthType::UnixPeerCredentials,
+        ));
+        let mut service = Duplex::new(get_response(Opcode::PsaSignHash));
+        let mut proxy = Proxy::new(1024).with_hook(AuthRewrite::new(
+            Some(AuthType::UnixPeerCredentials),
+            AuthType::Direct,
+            b"app".to_vec(),
+        ));
+
+        let _ = proxy.relay(&mut client, &mut service).unwrap();
+        let request = Request::read_from_stream(&mut Cursor::new(service.output), 1024).unwrap();
+        assert_eq!(request.header.auth_type, AuthType::Direct);
+        assert_eq!(request.auth.buffer.expose_secret(), b"app");
+        assert_eq!(*request.body, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn relay_auth_rewrite_direct() {
+        let mut client = Duplex::new(get_request(Opcode::PsaSignHash, AuthType::Direct));
+        let mut service = Duplex::new(get_response(Opcode::PsaSignHash));
+        let mut proxy =
+            Proxy::new(1024).with_hook(AuthRewrite::new(None, AuthType::Direct, b"app".to_vec()));
+
+        let _ = proxy.relay(&mut client, &mut service).unwrap();
+        let request = Request::read_from_stream(&mut Cursor::new(service.output), 1024).unwrap();
+        assert_eq!(request.header.auth_type, AuthType::Direct);
+        assert_eq!(request.auth.buffer.expose_secret(), b"app");
+
+        let mut client = Duplex::new(get_request(Opcode::PsaSignHash, AuthType::Direct));
+        let mut service = Duplex::new(Vec::new());
+        let mut proxy = Proxy::new(1024).with_hook(AuthRewrite::new(
+            Some(AuthType::UnixPeerCredentials),
+            AuthType::Direct,
+            b"app".to_vec(),
+        ));
+
+        let status = proxy.relay(&mut client, &mut service).unwrap();
+        assert_eq!(status, ResponseStatus::NotAuthenticated);
+        assert!(service.output.is_empty());
+    }
+
+    #[test]
+    fn relay_denied() {
+        let mut client = Duplex::new(get_request(Opcode::PsaExportKey, AuthType::Direct));
+        let mut service = Duplex::new(Vec::new());
+        let mut proxy = Proxy::new(1024).with_hook(OpcodeAllowlist::new(vec![Opcode::Ping]));
+
+        let status = proxy.relay(&mut client, &mut service).unwrap();
+        assert_eq!(status, ResponseStatus::PsaErrorNotPermitted);
+        assert!(service.output.is_empty());
+        let response = Response::read_from_stream(&mut Cursor::new(client.output), 1024).unwrap();
+        assert_eq!(response.header.status, ResponseStatus::PsaErrorNotPermitted);
+        assert_eq!(response.header.opcode, Opcode::PsaExportKey);
+        assert_eq!(response.header.session, 0x42);
+    }
+
+    #[test]
+    fn relay_unknown_opcode() {
+        let mut request = get_request(Opcode::PsaSignHash, AuthType::UnixPeerCredentials);
+        request[28..32].copy_from_slice(&0xFFFF_0001_u32.to_le_bytes());
+        let mut response = get_response(Opcode::PsaSignHash);
+        response[28..32].copy_from_slice(&0xFFFF_0001_u32.to_le_bytes());
+        let mut client = Duplex::new(request);
+        let mut service = Duplex::new(response.clone());
+        let mut proxy = Proxy::new(1024).with_hook(AuthRewrite::new(
+            Some(AuthType::UnixPeerCredentials),
+            AuthType::Direct,
+            b"app".to_vec(),
+        ));
+
+        let status = proxy.relay(&mut client, &mut service).unwrap();
+        assert_eq!(status, ResponseStatus::Success);
+        assert_eq!(client.output, response);
+        let request = Request::read_from_stream_lenient(
+            &mut Cursor::new(service.output),
+            &ReadLimits::new(1024),
+        )
+        .unwrap();
+        assert_eq!(request.header.opcode, 0xFFFF_0001);
+        assert_eq!(request.header.auth_type(), Some(AuthType::Direct));
+        assert_eq!(request.auth.buffer.expose_secret(), b"app");
+    }
+
+    #[test]
+    fn relay_unknown_opcode_denied() {
+        let mut request = get_request(Opcode::Ping, AuthType::Direct);
+        request[28..32].copy_from_slice(&0xFFFF_0001_u32.to_le_bytes());
+        let mut client = Duplex::new(request);
+        let mut service = Duplex::new(Vec::new());
+        let mut proxy = Proxy::new(1024).with_hook(OpcodeAllowlist::new(vec![Opcode::Ping]));
+
+        let status = proxy.relay(&mut client, &mut service).unwrap();
+        assert_eq!(status, ResponseStatus::PsaErrorNotPermitted);
+        assert!(service.output.is_empty());
+        let response = Response::read_from_stream_lenient(
+            &mut Cursor::new(client.output),
+            &ReadLimits::new(1024),
+        )
+        .unwrap();
+        assert_eq!(response.header.status, ResponseStatus::PsaErrorNotPermitted);
+        assert_eq!(response.header.opcode, 0xFFFF_0001);
+    }
+
+    fn get_batch_request(operations: Vec<NativeOperation>) -> Vec<u8> {
+        let body = ProtobufConverter {}
+            .operation_to_body(NativeOperation::Batch(batch::Operation {
+                continue_on_error: false,
+                operations,
+            }))
+            .unwrap();
+        get_request_with_body(Opcode::Batch, AuthType::Direct, body)
+    }
+
+    #[test]
+    fn relay_batch() {
+        let allowlist = || {
+            OpcodeAllowlist::new(vec![
+                Opcode::Batch,
+                Opcode::PsaDestroyKey,
+                Opcode::PsaSignHash,
+            ])
+        };
+        let destroy = || {
+            NativeOperation::PsaDestroyKey(psa_destroy_key::Operation {
+                key_name: String::from("key"),
+            })
+        };
+
+        let mut client = Duplex::new(get_batch_request(vec![destroy(), destroy()]));
+        let mut service = Duplex::new(get_response(Opcode::Batch));
+        let status = Proxy::new(1024)
+            .with_hook(allowlist())
+            .relay(&mut client, &mut service)
+            .unwrap();
+        assert_eq!(status, ResponseStatus::Success);
+        assert!(!service.output.is_empty());
+
+        let mut client = Duplex::new(get_batch_request(vec![
+            destroy(),
+            NativeOperation::PsaExportKey(psa_export_key::Operation {
+                key_name: String::from("key"),
+            }),
+        ]));
+        let mut service = Duplex::new(Vec::new());
+        let status = Proxy::new(1024)
+            .with_hook(allowlist())
+            .relay(&mut client, &mut service)
+            .unwrap();
+        assert_eq!(status, ResponseStatus::PsaErrorNotPermitted);
+        assert!(service.output.is_empty());
+    }
+
+    #[test]
+    fn relay_service_failure() {
+        let mut client = Duplex::new(get_request(Opcode::Ping, AuthType::Direct));
+        let mut service = Duplex::new(Vec::new());
+
+        let status = Proxy::new(1024)
+            .relay(&mut client, &mut service)
+            .unwrap_err();
+        assert_eq!(status, ResponseStatus::ConnectionError);
+        let response = Response::read_from_stream(&mut Cursor::new(client.output), 1024).unwrap();
+        assert_eq!(response.header.status, ResponseStatus::ConnectionError);
+        assert_eq!(response.header.opcode, Opcode::Ping);
+    }
+
+    #[test]
+    fn relay_body_too_large() {
+        let mut client = Duplex::new(get_request(Opcode::Ping, AuthType::Direct));
+        let mut service = Duplex::new(Vec::new());
+
+        let mut proxy = Proxy::new(1024)
+            .with_read_limits(ReadLimits::new(1024).with_opcode_body_len_limit(Opcode::Ping, 2));
+        let status = proxy.relay(&mut client, &mut service).unwrap_err();
+        assert_eq!(status, ResponseStatus::BodySizeExceedsLimit);
+        assert!(service.output.is_empty());
+        let response = Response::read_from_stream(&mut Cursor::new(client.output), 1024).unwrap();
+        assert_eq!(response.header.status, ResponseStatus::BodySizeExceedsLimit);
+    }
+}
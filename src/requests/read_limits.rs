@@ -0,0 +1,266 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Limits applied when reading requests and responses
+//!
+//! The limits are checked against the lengths in the raw header, after it has been read but
+//! before the buffers of the body and authentication fields are allocated. The bodies of the
+//! operations of a protobuf batch request are checked once the body of the batch has been read.
+use super::common::wire_header_1_0::WireHeader as Raw;
+use super::{AuthType, BodyType, Opcode, ResponseStatus, Result};
+use crate::operations_protobuf::batch_body_to_entry_lens;
+use log::error;
+use num::FromPrimitive;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// Policy of maximum body and authentication lengths, in bytes.
+///
+/// The body length limit can be set per opcode and the authentication length limit per
+/// authentication type. The default body length limit applies to the opcodes without a specific
+/// limit, including the ones unknown to this version of the crate. The authentication length is
+/// only limited by the size of its header field for the authentication types without a specific
+/// limit.
+///
+/// The body length limit of `Opcode::Batch` applies to the aggregate size of a batch, which
+/// contains the bodies of all its operations. The body of each operation of a batch request must
+/// also be within the body length limit of its opcode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReadLimits {
+    body_len_limit: usize,
+    opcode_body_len_limits: HashMap<Opcode, usize>,
+    auth_len_limits: HashMap<AuthType, usize>,
+}
+
+impl ReadLimits {
+    /// Create limits applying the same body length limit to all opcodes
+    pub fn new(body_len_limit: usize) -> Self {
+        ReadLimits {
+            body_len_limit,
+            opcode_body_len_limits: HashMap::new(),
+            auth_len_limits: HashMap::new(),
+        }
+    }
+
+    /// Set the body length limit of an opcode
+    pub fn with_opcode_body_len_limit(mut self, opcode: Opcode, limit: usize) -> Self {
+        let _ = self.opcode_body_len_limits.insert(opcode, limit);
+        self
+    }
+
+    /// Set the authentication length limit of an authentication type
+    pub fn with_auth_len_limit(mut self, auth_type: AuthType, limit: usize) -> Self {
+        let _ = self.auth_len_limits.insert(auth_type, limit);
+        self
+    }
+
+    /// Body length limit applying to an opcode
+    pub fn body_len_limit(&self, opcode: Opcode) -> usize {
+        self.opcode_body_len_limits
+            .get(&opcode)
+            .copied()
+            .unwrap_or(self.body_len_limit)
+    }
+
+    /// Authentication length limit applying to an authentication type
+    pub fn auth_len_limit(&self, auth_type: AuthType) -> usize {
+        self.auth_len_limits
+            .get(&auth_type)
+            .copied()
+            .unwrap_or_else(|| usize::from(u16::MAX))
+    }
+
+    fn raw_body_len_limit(&self, opcode: u32) -> usize {
+        match FromPrimitive::from_u32(opcode) {
+            Some(opcode) => self.body_len_limit(opcode),
+            None => self.body_len_limit,
+        }
+    }
+
+    /// Check the lengths of the raw header of a request.
+    ///
+    /// Returns `ResponseStatus::BodySizeExceedsLimit` if the body or authentication field is
+    /// larger than allowed.
+    pub(crate) fn check_request(&self, header: &Raw) -> Result<()> {
+        self.check_body_len(header)?;
+        let auth_len = usize::from(header.auth_len);
+        let limit = match FromPrimitive::from_u8(header.auth_type) {
+            Some(auth_type) => self.auth_len_limit(auth_type),
+            None => usize::from(u16::MAX),
+        };
+        if auth_len > limit {
+            error!(
+                "Request authentication length ({}) bigger than the limit given ({}).",
+                auth_len, limit
+            );
+            return Err(ResponseStatus::BodySizeExceedsLimit);
+        }
+        Ok(())
+    }
+
+    /// Check the bodies of the operations of a batch request against the limits of their opcodes.
+    ///
+    /// Requests which are not batches, or whose content type is not protobuf, are not checked.
+    /// Returns `ResponseStatus::BodySizeExceedsLimit` if the body of an operation is larger than
+    /// allowed, or `ResponseStatus::DeserializingBodyFailed` if the batch can not be decoded.
+    pub(crate) fn check_request_body(&self, header: &Raw, body: &[u8]) -> Result<()> {
+        if header.opcode != Opcode::Batch as u32 || header.content_type != BodyType::Protobuf as u8
+        {
+            return Ok(());
+        }
+        for (opcode, body_len) in batch_body_to_entry_lens(body)? {
+            let limit = self.raw_body_len_limit(opcode);
+            if body_len > limit {
+                error!(
+                    "Body length of batched operation ({}) bigger than the limit given ({}).",
+                    body_len, limit
+                );
+                return Err(ResponseStatus::BodySizeExceedsLimit);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the length of the raw header of a response.
+    ///
+    /// Returns `ResponseStatus::BodySizeExceedsLimit` if the body is larger than allowed.
+    pub(crate) fn check_response(&self, header: &Raw) -> Result<()> {
+        self.check_body_len(header)
+    }
+
+    fn check_body_len(&self, header: &Raw) -> Result<()> {
+        let body_len = usize::try_from(header.body_len)?;
+        let limit = self.raw_body_len_limit(header.opcode);
+        if body_len > limit {
+            error!(
+                "Body length ({}) bigger than the limit given ({}).",
+                body_len, limit
+            );
+            return Err(ResponseStatus::BodySizeExceedsLimit);
+        }
+        Ok(())
+    }
+}
+
+impl From<usize> for ReadLimits {
+    fn from(body_len_limit: usize) -> Self {
+        ReadLimits::new(body_len_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::Hash;
+    use crate::operations::{batch, psa_hash_compute, Convert, NativeOperation};
+    use crate::operations_protobuf::ProtobufConverter;
+
+    fn get_header(opcode: u32, auth_type: u8, body_len: u32, auth_len: u16) -> Raw {
+        Raw {
+            flags: 0,
+            provider: 0,
+            session: 0,
+            content_type: 0,
+            accept_type: 0,
+            auth_type,
+            body_len,
+            auth_len,
+            opcode,
+            status: 0,
+            reserved1: 0,
+            reserved2: 0,
+        }
+    }
+
+    #[test]
+    fn per_opcode_limits() {
+        let limits = ReadLimits::new(1024)
+            .with_opcode_body_len_limit(Opcode::PsaImportKey, 8192)
+            .with_opcode_body_len_limit(Opcode::PsaSignHash, 128);
+        assert_eq!(limits.body_len_limit(Opcode::PsaImportKey), 8192);
+        assert_eq!(limits.body_len_limit(Opcode::PsaSignHash), 128);
+        assert_eq!(limits.body_len_limit(Opcode::Ping), 1024);
+
+        let direct = AuthType::Direct as u8;
+        let import = Opcode::PsaImportKey as u32;
+        let sign = Opcode::PsaSignHash as u32;
+        limits
+            .check_request(&get_header(import, direct, 8192, 0))
+            .unwrap();
+        limits
+            .check_response(&get_header(sign, direct, 128, 0))
+            .unwrap();
+        for header in [
+            get_header(import, direct, 8193, 0),
+            get_header(sign, direct, 129, 0),
+            get_header(0xffff, direct, 1025, 0),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                limits.check_request(header).unwrap_err(),
+                ResponseStatus::BodySizeExceedsLimit
+            );
+            assert_eq!(
+                limits.check_response(header).unwrap_err(),
+                ResponseStatus::BodySizeExceedsLimit
+            );
+        }
+    }
+
+    #[test]
+    fn auth_limits() {
+        let limits = ReadLimits::new(0).with_auth_len_limit(AuthType::Direct, 16);
+        assert_eq!(limits.auth_len_limit(AuthType::Direct), 16);
+        assert_eq!(limits.auth_len_limit(AuthType::JwtSvid), 65535);
+
+        let ping = Opcode::Ping as u32;
+        limits
+            .check_request(&get_header(ping, AuthType::Direct as u8, 0, 16))
+            .unwrap();
+        limits
+            .check_request(&get_header(ping, AuthType::JwtSvid as u8, 0, 4096))
+            .unwrap();
+        assert_eq!(
+            limits
+                .check_request(&get_header(ping, AuthType::Direct as u8, 0, 17))
+                .unwrap_err(),
+            ResponseStatus::BodySizeExceedsLimit
+        );
+    }
+
+    #[test]
+    fn batch_limits() {
+        let body = ProtobufConverter {}
+            .operation_to_body(NativeOperation::Batch(batch::Operation {
+                continue_on_error: false,
+                operations: vec![NativeOperation::PsaHashCompute(
+                    psa_hash_compute::Operation {
+                        alg: Hash::Sha256,
+                        input: vec![0x11; 64].into(),
+                    },
+                )],
+            }))
+            .unwrap();
+        let batch = get_header(Opcode::Batch as u32, 0, body.len() as u32, 0);
+
+        ReadLimits::new(1024)
+            .check_request_body(&batch, &body)
+            .unwrap();
+        let limits = ReadLimits::new(1024).with_opcode_body_len_limit(Opcode::PsaHashCompute, 32);
+        assert_eq!(
+            limits.check_request_body(&batch, &body).unwrap_err(),
+            ResponseStatus::BodySizeExceedsLimit
+        );
+        assert_eq!(
+            limits.check_request_body(&batch, &[0xff; 4]).unwrap_err(),
+            ResponseStatus::DeserializingBodyFailed
+        );
+
+        let mut other = batch;
+        other.opcode = Opcode::PsaHashCompute as u32;
+        limits.check_request_body(&other, &body).unwrap();
+        let mut other = batch;
+        other.content_type = 0xff;
+        limits.check_request_body(&other, &body).unwrap();
+    }
+}
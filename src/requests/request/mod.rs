@@ -4,11 +4,11 @@
 //!
 //! A `Request` is to the service to execute one operation.
 use super::common::wire_header_1_0::WireHeader as Raw;
+use super::common::{RESPONSE_AUTH_FLAG, RESPONSE_AUTH_NONCE_LEN};
 use super::response::ResponseHeader;
-use crate::requests::{ResponseStatus, Result};
+use crate::requests::{ReadLimits, ResponseStatus, Result};
 use crate::secrecy::ExposeSecret;
 use derivative::Derivative;
-use log::error;
 use std::convert::{TryFrom, TryInto};
 use std::io::{Read, Write};
 
@@ -18,7 +18,7 @@ mod request_header;
 
 pub use request_auth::RequestAuth;
 pub use request_body::RequestBody;
-pub use request_header::RequestHeader;
+pub use request_header::{LenientRequestHeader, RequestHeader};
 
 #[cfg(feature = "testing")]
 pub use super::common::wire_header_1_0::WireHeader as RawHeader;
@@ -37,6 +37,9 @@ pub struct Request {
     /// specified in the header is needed to authenticate the request.
     #[derivative(Debug = "ignore")]
     pub auth: RequestAuth,
+    /// Nonce asking the service to authenticate its response. The client must choose a new
+    /// random nonce for each request, the response authentication covering it.
+    pub response_auth_nonce: Option<[u8; RESPONSE_AUTH_NONCE_LEN]>,
 }
 
 impl Request {
@@ -48,6 +51,7 @@ impl Request {
             header: RequestHeader::new(),
             body: RequestBody::new(),
             auth: RequestAuth::new(Vec::new()),
+            response_auth_nonce: None,
         }
     }
 
@@ -60,14 +64,20 @@ impl Request {
     /// `ResponseStatus::ConnectionError` is returned.
     /// - if encoding any of the fields in the header fails, `ResponseStatus::InvalidEncoding`
     /// is returned.
-    pub fn write_to_stream(self, stream: &mut impl Write) -> Result<()> {
+    pub fn write_to_stream(&self, stream: &mut impl Write) -> Result<()> {
         let mut raw_header: Raw = self.header.into();
         raw_header.body_len = u32::try_from(self.body.len())?;
         raw_header.auth_len = u16::try_from(self.auth.buffer.expose_secret().len())?;
+        if self.response_auth_nonce.is_some() {
+            raw_header.flags |= RESPONSE_AUTH_FLAG;
+        }
         raw_header.write_to_stream(stream)?;
 
         self.body.write_to_stream(stream)?;
         self.auth.write_to_stream(stream)?;
+        if let Some(nonce) = &self.response_auth_nonce {
+            stream.write_all(nonce)?;
+        }
 
         Ok(())
     }
@@ -84,22 +94,153 @@ impl Request {
     /// - if the request body size specified in the header is larger than the limit passed as
     /// a parameter, `BodySizeExceedsLimit` will be returned.
     pub fn read_from_stream(stream: &mut impl Read, body_len_limit: usize) -> Result<Request> {
+        Request::read_from_stream_with_limits(stream, &ReadLimits::new(body_len_limit))
+    }
+
+    /// Deserialise request from given stream, applying the limits of the opcode and
+    /// authentication type of the request.
+    ///
+    /// The limits are checked before the body and authentication buffers are allocated.
+    ///
+    /// # Errors
+    /// - if reading any of the subfields (header, body or auth) fails, the corresponding
+    ///   `ResponseStatus` will be returned.
+    /// - if the request body or authentication size specified in the header, or the body of an
+    ///   operation of a batch, is larger than the limits, `BodySizeExceedsLimit` will be returned.
+    pub fn read_from_stream_with_limits(
+        stream: &mut impl Read,
+        limits: &ReadLimits,
+    ) -> Result<Request> {
         let raw_header = Raw::read_from_stream(stream)?;
+        limits.check_request(&raw_header)?;
         let body_len = usize::try_from(raw_header.body_len)?;
-        if body_len > body_len_limit {
-            error!(
-                "Request body length ({}) bigger than the limit given ({}).",
-                body_len, body_len_limit
-            );
-            return Err(ResponseStatus::BodySizeExceedsLimit);
-        }
         let body = RequestBody::read_from_stream(stream, body_len)?;
+        limits.check_request_body(&raw_header, &body)?;
         let auth = RequestAuth::read_from_stream(stream, usize::from(raw_header.auth_len))?;
+        let response_auth_nonce = read_response_auth_nonce(stream, &raw_header)?;
 
         Ok(Request {
             header: raw_header.try_into()?,
             body,
             auth,
+            response_auth_nonce,
+        })
+    }
+
+    /// Deserialise request from given stream, keeping the values of its header which are unknown
+    /// to this version of the crate.
+    ///
+    /// The limits of the opcode and authentication type of the request are applied as in
+    /// `read_from_stream_with_limits`, the default body length limit applying to unknown opcodes.
+    ///
+    /// # Errors
+    /// - if reading any of the subfields (header, body or auth) fails, the corresponding
+    ///   `ResponseStatus` will be returned.
+    /// - if the request body or authentication size specified in the header, or the body of an
+    ///   operation of a batch, is larger than the limits, `BodySizeExceedsLimit` will be returned.
+    pub fn read_from_stream_lenient(
+        stream: &mut impl Read,
+        limits: &ReadLimits,
+    ) -> Result<LenientRequest> {
+        let raw_header = Raw::read_from_stream(stream)?;
+        limits.check_request(&raw_header)?;
+        let body_len = usize::try_from(raw_header.body_len)?;
+        let body = RequestBody::read_from_stream(stream, body_len)?;
+        limits.check_request_body(&raw_header, &body)?;
+        let auth = RequestAuth::read_from_stream(stream, usize::from(raw_header.auth_len))?;
+        let response_auth_nonce = read_response_auth_nonce(stream, &raw_header)?;
+
+        Ok(LenientRequest {
+            header: raw_header.into(),
+            body,
+            auth,
+            response_auth_nonce,
+        })
+    }
+}
+
+/// Read the nonce following the authentication field of requests asking for the authentication
+/// of their response.
+fn read_response_auth_nonce(
+    stream: &mut impl Read,
+    raw_header: &Raw,
+) -> Result<Option<[u8; RESPONSE_AUTH_NONCE_LEN]>> {
+    if raw_header.flags & RESPONSE_AUTH_FLAG == 0 {
+        return Ok(None);
+    }
+    let mut nonce = [0; RESPONSE_AUTH_NONCE_LEN];
+    stream.read_exact(&mut nonce)?;
+    Ok(Some(nonce))
+}
+
+/// Representation of the request wire format with a lenient header.
+///
+/// It is read with `Request::read_from_stream_lenient` and can be written back unchanged, even
+/// if its header contains values unknown to this version of the crate.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct LenientRequest {
+    /// Request header, keeping the raw values of its fields
+    pub header: LenientRequestHeader,
+    /// Request body
+    pub body: RequestBody,
+    /// Request authentication
+    #[derivative(Debug = "ignore")]
+    pub auth: RequestAuth,
+    /// Nonce asking the service to authenticate its response. The response authentication flag
+    /// of the header is set when writing the request if, and only if, the nonce is present.
+    pub response_auth_nonce: Option<[u8; RESPONSE_AUTH_NONCE_LEN]>,
+}
+
+impl LenientRequest {
+    /// Serialise request and write it to given stream.
+    ///
+    /// # Errors
+    /// - if an IO operation fails while writing any of the subfields of the request,
+    ///   `ResponseStatus::ConnectionError` is returned.
+    /// - if encoding any of the fields in the header fails, `ResponseStatus::InvalidEncoding`
+    ///   is returned.
+    pub fn write_to_stream(self, stream: &mut impl Write) -> Result<()> {
+        let mut raw_header: Raw = self.header.into();
+        raw_header.body_len = u32::try_from(self.body.len())?;
+        raw_header.auth_len = u16::try_from(self.auth.buffer.expose_secret().len())?;
+        raw_header.flags &= !RESPONSE_AUTH_FLAG;
+        if self.response_auth_nonce.is_some() {
+            raw_header.flags |= RESPONSE_AUTH_FLAG;
+        }
+        raw_header.write_to_stream(stream)?;
+
+        self.body.write_to_stream(stream)?;
+        self.auth.write_to_stream(stream)?;
+        if let Some(nonce) = &self.response_auth_nonce {
+            stream.write_all(nonce)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Request> for LenientRequest {
+    fn from(request: Request) -> Self {
+        LenientRequest {
+            header: request.header.into(),
+            body: request.body,
+            auth: request.auth,
+            response_auth_nonce: request.response_auth_nonce,
+        }
+    }
+}
+
+/// Strict conversion from the lenient request, failing if its header contains unknown values.
+impl TryFrom<LenientRequest> for Request {
+    type Error = ResponseStatus;
+
+    fn try_from(request: LenientRequest) -> Result<Self> {
+        Ok(Request {
+            header: request.header.try_into()?,
+            body: request.body,
+            auth: request.auth,
+            response_auth_nonce: request.response_auth_nonce,
         })
     }
 }
@@ -314,6 +455,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lenient_request_round_trip() {
+        let mut bytes = get_request_1_bytes();
+        // Put an unknown provider, authentication type and opcode.
+        bytes[10] = 0xA0;
+        bytes[21] = 0xF0;
+        bytes[28..32].copy_from_slice(&0xFFFF_0001_u32.to_le_bytes());
+        let mut mock = test_utils::MockReadWrite {
+            buffer: bytes.clone(),
+        };
+
+        let request = Request::read_from_stream_lenient(&mut mock, &ReadLimits::new(1000))
+            .expect("Failed to read request");
+        assert_eq!(request.header.provider(), None);
+        assert_eq!(request.header.auth_type(), None);
+        assert_eq!(request.header.opcode, 0xFFFF_0001);
+        assert_eq!(*request.body, vec![0x70, 0x80, 0x90]);
+
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        request
+            .write_to_stream(&mut mock)
+            .expect("Failed to write request");
+        assert_eq!(mock.buffer, bytes);
+
+        let mut mock = test_utils::MockReadWrite { buffer: bytes };
+        assert_eq!(
+            Request::read_from_stream(&mut mock, 1000).unwrap_err(),
+            ResponseStatus::AuthenticatorDoesNotExist
+        );
+    }
+
+    #[test]
+    fn lenient_request_to_strict() {
+        let mut mock = test_utils::MockReadWrite {
+            buffer: get_request_1_bytes(),
+        };
+
+        let request = Request::read_from_stream_lenient(&mut mock, &ReadLimits::new(1000))
+            .expect("Failed to read request");
+        let request = Request::try_from(request).expect("Failed to convert request");
+        assert_eq!(request.header, get_request_1().header);
+    }
+
+    #[test]
+    fn response_auth_nonce() {
+        let mut request = get_request_1();
+        request.response_auth_nonce = Some([0x5A; RESPONSE_AUTH_NONCE_LEN]);
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        request
+            .write_to_stream(&mut mock)
+            .expect("Failed to write request");
+
+        let mut expected = get_request_1_bytes();
+        expected[8] = 0x01; // WireHeader::flags
+        expected.extend(vec![0x5A; RESPONSE_AUTH_NONCE_LEN]);
+        assert_eq!(mock.buffer, expected);
+
+        let read = Request::read_from_stream(&mut mock, 1000).expect("Failed to read request");
+        assert_eq!(read.response_auth_nonce, request.response_auth_nonce);
+        assert_eq!(read.body, request.body);
+
+        let mut mock = test_utils::MockReadWrite { buffer: expected };
+        let lenient = Request::read_from_stream_lenient(&mut mock, &ReadLimits::new(1000))
+            .expect("Failed to read request");
+        assert_eq!(lenient.response_auth_nonce, request.response_auth_nonce);
+
+        // The flag follows the presence of the nonce
+        let mut lenient = LenientRequest::from(get_request_1());
+        lenient.header.flags = RESPONSE_AUTH_FLAG;
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        lenient
+            .write_to_stream(&mut mock)
+            .expect("Failed to write request");
+        assert_eq!(mock.buffer, get_request_1_bytes());
+
+        // A missing nonce is a truncated request
+        let mut truncated = get_request_1_bytes();
+        truncated[8] = 0x01;
+        assert_eq!(
+            Request::read_from_stream(&mut truncated.as_slice(), 1000).unwrap_err(),
+            ResponseStatus::ConnectionError
+        );
+    }
+
     fn get_request_1() -> Request {
         let body = RequestBody::from_bytes(vec![0x70, 0x80, 0x90]);
         let auth = RequestAuth::new(vec![0xa0, 0xb0, 0xc0]);
@@ -325,7 +550,12 @@ mod tests {
             auth_type: AuthType::Direct,
             opcode: Opcode::Ping,
         };
-        Request { header, body, auth }
+        Request {
+            header,
+            body,
+            auth,
+            response_auth_nonce: None,
+        }
     }
 
     fn get_request_2() -> Request {
@@ -339,7 +569,12 @@ mod tests {
             auth_type: AuthType::Direct,
             opcode: Opcode::Ping,
         };
-        Request { header, body, auth }
+        Request {
+            header,
+            body,
+            auth,
+            response_auth_nonce: None,
+        }
     }
 
     fn get_request_1_bytes() -> Vec<u8> {
@@ -105,3 +105,176 @@ impl From<RequestHeader> for Raw {
         }
     }
 }
+
+/// A lenient native representation of the request header.
+///
+/// Contrary to `RequestHeader`, the provider, content type, accept type, authentication type and
+/// opcode are kept as their raw values, so that requests using values unknown to this version of
+/// the crate can be inspected and forwarded unchanged. Converting it back to the raw header
+/// produces the same fields as the ones it was created from, apart from the lengths.
+#[cfg_attr(feature = "fuzz", derive(Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LenientRequestHeader {
+    /// Implementation-defined flags
+    pub flags: u16,
+    /// Raw provider ID value
+    pub provider: u8,
+    /// Session handle
+    pub session: u64,
+    /// Raw content type
+    pub content_type: u8,
+    /// Raw accept type
+    pub accept_type: u8,
+    /// Raw authentication type
+    pub auth_type: u8,
+    /// Raw opcode of the operation to perform
+    pub opcode: u32,
+    /// Status field, unused in requests
+    pub status: u16,
+}
+
+impl LenientRequestHeader {
+    /// Provider ID, if known by this version of the crate
+    pub fn provider(&self) -> Option<ProviderId> {
+        FromPrimitive::from_u8(self.provider)
+    }
+
+    /// Content type, if known by this version of the crate
+    pub fn content_type(&self) -> Option<BodyType> {
+        FromPrimitive::from_u8(self.content_type)
+    }
+
+    /// Accept type, if known by this version of the crate
+    pub fn accept_type(&self) -> Option<BodyType> {
+        FromPrimitive::from_u8(self.accept_type)
+    }
+
+    /// Authentication type, if known by this version of the crate
+    pub fn auth_type(&self) -> Option<AuthType> {
+        FromPrimitive::from_u8(self.auth_type)
+    }
+
+    /// Opcode, if known by this version of the crate
+    pub fn opcode(&self) -> Option<Opcode> {
+        FromPrimitive::from_u32(self.opcode)
+    }
+}
+
+/// Conversion from the raw to the lenient request header, which never fails.
+impl From<Raw> for LenientRequestHeader {
+    fn from(header: Raw) -> Self {
+        LenientRequestHeader {
+            flags: header.flags,
+            provider: header.provider,
+            session: header.session,
+            content_type: header.content_type,
+            accept_type: header.accept_type,
+            auth_type: header.auth_type,
+            opcode: header.opcode,
+            status: header.status,
+        }
+    }
+}
+
+/// Conversion from the lenient to the raw request header.
+///
+/// The body and authentication lengths are left to zero.
+impl From<LenientRequestHeader> for Raw {
+    fn from(header: LenientRequestHeader) -> Self {
+        Raw {
+            flags: header.flags,
+            provider: header.provider,
+            session: header.session,
+            content_type: header.content_type,
+            accept_type: header.accept_type,
+            auth_type: header.auth_type,
+            body_len: 0,
+            auth_len: 0,
+            opcode: header.opcode,
+            status: header.status,
+            reserved1: 0,
+            reserved2: 0,
+        }
+    }
+}
+
+impl From<RequestHeader> for LenientRequestHeader {
+    fn from(header: RequestHeader) -> Self {
+        Raw::from(header).into()
+    }
+}
+
+/// Strict conversion from the lenient to the native request header.
+///
+/// Fails in the same way as the conversion from the raw header if a value is not known.
+impl TryFrom<LenientRequestHeader> for RequestHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: LenientRequestHeader) -> ::std::result::Result<Self, Self::Error> {
+        RequestHeader::try_from(Raw::from(header))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::utils::tests as test_utils;
+
+    fn get_raw_header() -> Raw {
+        Raw {
+            flags: 0,
+            provider: 0xa0,
+            session: 0x11_22_33_44_55_66_77_88,
+            content_type: 0,
+            accept_type: 0,
+            auth_type: 0xf0,
+            body_len: 0,
+            auth_len: 0,
+            opcode: 0xffff_0001,
+            status: 0,
+            reserved1: 0,
+            reserved2: 0,
+        }
+    }
+
+    fn to_bytes(header: Raw) -> Vec<u8> {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        header.write_to_stream(&mut mock).unwrap();
+        mock.buffer
+    }
+
+    #[test]
+    fn unknown_values_round_trip() {
+        let raw = get_raw_header();
+        let header = LenientRequestHeader::from(raw);
+        assert_eq!(header.provider(), None);
+        assert_eq!(header.auth_type(), None);
+        assert_eq!(header.opcode(), None);
+        assert_eq!(header.content_type(), Some(BodyType::Protobuf));
+        assert_eq!(to_bytes(header.into()), to_bytes(raw));
+    }
+
+    #[test]
+    fn unknown_values_are_rejected_by_strict_conversion() {
+        let mut header = LenientRequestHeader::from(get_raw_header());
+        assert_eq!(
+            RequestHeader::try_from(header).unwrap_err(),
+            ResponseStatus::AuthenticatorDoesNotExist
+        );
+        header.auth_type = AuthType::Direct as u8;
+        assert_eq!(
+            RequestHeader::try_from(header).unwrap_err(),
+            ResponseStatus::OpcodeDoesNotExist
+        );
+        header.opcode = Opcode::PsaSignHash as u32;
+        assert_eq!(
+            RequestHeader::try_from(header).unwrap_err(),
+            ResponseStatus::ProviderDoesNotExist
+        );
+        header.provider = ProviderId::MbedCrypto as u8;
+        let strict = RequestHeader::try_from(header).unwrap();
+        assert_eq!(strict.opcode, Opcode::PsaSignHash);
+        assert_eq!(strict.session, header.session);
+        assert_eq!(LenientRequestHeader::from(strict), header);
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+/// Details about the failure of a request.
+///
+/// The service may send them in the body of responses whose status is not
+/// `ResponseStatus::Success`, encoded with the `content_type` of the response header. Error
+/// responses have an empty body otherwise, so clients ignoring it keep working.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorDetails {
+    /// Human readable description of the error.
+    pub message: String,
+    /// Path of the field of the operation that caused the error, with its components separated
+    /// by dots, for example `attributes.policy.permitted_algorithms`.
+    pub field_path: Option<String>,
+    /// Error code specific to the provider that handled the request, for example the return
+    /// code of a TPM command or of a PKCS 11 function.
+    pub provider_code: Option<i64>,
+}
@@ -3,18 +3,27 @@
 //! Response definition
 
 use super::common::wire_header_1_0::WireHeader as Raw;
-use super::request::RequestHeader;
+use super::common::RESPONSE_AUTH_FLAG;
+use super::request::{LenientRequestHeader, Request, RequestHeader};
+use super::ReadLimits;
 use super::ResponseStatus;
 use super::Result;
+use crate::operations::Convert;
+#[cfg(feature = "response-auth")]
+use hmac::Mac;
 use log::error;
 use std::convert::{TryFrom, TryInto};
 use std::io::{Read, Write};
 
+mod error_details;
+mod response_auth;
 mod response_body;
 mod response_header;
 
+pub use error_details::ErrorDetails;
+pub use response_auth::ResponseAuth;
 pub use response_body::ResponseBody;
-pub use response_header::ResponseHeader;
+pub use response_header::{LenientResponseHeader, ResponseHeader};
 
 #[cfg(feature = "testing")]
 pub use super::common::wire_header_1_0::WireHeader as RawHeader;
@@ -28,6 +37,8 @@ pub struct Response {
     /// is deferred to the a converter which can handle the `content_type` defined in the
     /// header.
     pub body: ResponseBody,
+    /// Optional authentication trailer, allowing the client to authenticate the service.
+    pub auth: Option<ResponseAuth>,
 }
 
 impl Response {
@@ -36,6 +47,7 @@ impl Response {
         Response {
             header: ResponseHeader::new(),
             body: ResponseBody::new(),
+            auth: None,
         }
     }
 
@@ -51,6 +63,55 @@ impl Response {
         response
     }
 
+    /// Convert request into an error response with a given `ResponseStatus` and details about
+    /// the error.
+    ///
+    /// The details are encoded in the body by the converter, which must handle the `content_type`
+    /// of the response. They are not attached to successful responses, and the body is left
+    /// empty if they can not be encoded.
+    pub fn from_request_header_with_details(
+        header: RequestHeader,
+        status: ResponseStatus,
+        details: ErrorDetails,
+        converter: &impl Convert,
+    ) -> Response {
+        let mut response = Response::from_request_header(header, status);
+        if status == ResponseStatus::Success {
+            error!("Error details can not be attached to a successful response.");
+            return response;
+        }
+        if converter.body_type() != response.header.content_type {
+            error!("The converter does not handle the content type of the response.");
+            return response;
+        }
+        match converter.error_details_to_body(details) {
+            Ok(body) => response.body = body,
+            Err(status) => error!("Failed to encode the error details ({}).", status),
+        }
+
+        response
+    }
+
+    /// Decode the details about the error from the body of the response, with a converter
+    /// handling the `content_type` of the header.
+    ///
+    /// Returns `None` for successful responses and for error responses without details.
+    ///
+    /// # Errors
+    /// - if the converter does not handle the `content_type` of the header, or if it can not
+    ///   decode the body, `ResponseStatus::DeserializingBodyFailed` is returned.
+    pub fn error_details(&self, converter: &impl Convert) -> Result<Option<ErrorDetails>> {
+        if self.header.status == ResponseStatus::Success || self.body.is_empty() {
+            return Ok(None);
+        }
+        if converter.body_type() != self.header.content_type {
+            error!("The converter does not handle the content type of the response.");
+            return Err(ResponseStatus::DeserializingBodyFailed);
+        }
+        let body = ResponseBody::from_bytes(self.body.to_vec());
+        converter.body_to_error_details(body).map(Some)
+    }
+
     /// Create an empty response with a specific status.
     pub fn from_status(status: ResponseStatus) -> Response {
         let mut response = Response::new();
@@ -69,15 +130,84 @@ impl Response {
     /// - if encoding any of the fields in the header fails, then
     /// `ResponseStatus::InvalidEncoding` is returned.
     pub fn write_to_stream(self, stream: &mut impl Write) -> Result<()> {
-        let mut raw_header: Raw = self.header.into();
-        raw_header.body_len = u32::try_from(self.body.len())?;
+        let mut raw_header = self.raw_header()?;
+        if let Some(auth) = &self.auth {
+            raw_header.auth_len = u16::try_from(auth.buffer.len())?;
+        }
 
         raw_header.write_to_stream(stream)?;
         self.body.write_to_stream(stream)?;
+        if let Some(auth) = &self.auth {
+            auth.write_to_stream(stream)?;
+        }
+
+        Ok(())
+    }
+
+    /// Raw header of the response, with its authentication length left to zero.
+    fn raw_header(&self) -> Result<Raw> {
+        let mut raw_header: Raw = self.header.into();
+        raw_header.body_len = u32::try_from(self.body.len())?;
+        if self.auth.is_some() {
+            raw_header.flags |= RESPONSE_AUTH_FLAG;
+        }
+        Ok(raw_header)
+    }
+
+    /// Bytes covered by the authentication trailer.
+    ///
+    /// They are the request the response answers, serialized as written on the wire with its
+    /// body, authentication and response authentication nonce, followed by the serialized header
+    /// of the response with the response authentication flag set and the authentication length
+    /// set to zero, and by its body. Binding the response to the whole request, including the
+    /// nonce chosen by the client, prevents a response from being replayed to another request.
+    /// Services signing their responses with another scheme than the HMAC below need to sign
+    /// these bytes.
+    ///
+    /// # Errors
+    /// - if encoding any of the fields in the headers fails, then
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    pub fn authenticated_data(&self, request: &Request) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        request.write_to_stream(&mut data)?;
+
+        let mut raw_header = self.raw_header()?;
+        raw_header.flags |= RESPONSE_AUTH_FLAG;
+        raw_header.write_to_stream(&mut data)?;
+        data.extend_from_slice(self.body.bytes());
+        Ok(data)
+    }
 
+    /// Set the authentication trailer to the HMAC-SHA256 of the response and of the request it
+    /// answers, computed with the key shared with the client sending the request.
+    #[cfg(feature = "response-auth")]
+    pub fn sign_hmac(&mut self, key: &[u8], request: &Request) -> Result<()> {
+        let mut mac = response_hmac(key)?;
+        mac.update(&self.authenticated_data(request)?);
+        self.auth = Some(ResponseAuth::new(mac.finalize().into_bytes().to_vec()));
         Ok(())
     }
 
+    /// Check that the authentication trailer is the HMAC-SHA256 of the response and of the
+    /// request it answers, computed with the key of the client shared with the service.
+    ///
+    /// # Errors
+    /// - if the response has no authentication trailer or if it does not match,
+    ///   `ResponseStatus::AuthenticationError` is returned.
+    #[cfg(feature = "response-auth")]
+    pub fn verify_hmac(&self, key: &[u8], request: &Request) -> Result<()> {
+        let auth = self.auth.as_ref().ok_or_else(|| {
+            error!("The response is not authenticated.");
+            ResponseStatus::AuthenticationError
+        })?;
+        let mut mac = response_hmac(key)?;
+        mac.update(&self.authenticated_data(request)?);
+        mac.verify_slice(&auth.buffer).map_err(|_| {
+            error!("The authentication of the response is invalid.");
+            ResponseStatus::AuthenticationError
+        })
+    }
+
     /// Deserialise response from given stream.
     ///
     /// The `body_len_limit` parameter allows the interface client to reject requests that are
@@ -89,29 +219,173 @@ impl Response {
     /// - if the request body size specified in the header is larger than the limit passed as
     /// a parameter, `BodySizeExceedsLimit` will be returned.
     pub fn read_from_stream(stream: &mut impl Read, body_len_limit: usize) -> Result<Response> {
-        let raw_header = Raw::read_from_stream(stream)?;
-        let body_len = usize::try_from(raw_header.body_len)?;
-        if body_len > body_len_limit {
-            error!(
-                "Request body length ({}) bigger than the limit given ({}).",
-                body_len, body_len_limit
-            );
-            return Err(ResponseStatus::BodySizeExceedsLimit);
-        }
-        let body = ResponseBody::read_from_stream(stream, body_len)?;
+        Response::read_from_stream_with_limits(stream, &ReadLimits::new(body_len_limit))
+    }
+
+    /// Deserialise response from given stream, applying the body length limit of its opcode.
+    ///
+    /// The limit is checked before the body buffer is allocated.
+    ///
+    /// # Errors
+    /// - if reading any of the subfields (header or body) fails, the
+    ///   corresponding `ResponseStatus` will be returned.
+    /// - if the response body size specified in the header is larger than the limit of the
+    ///   opcode, `BodySizeExceedsLimit` will be returned.
+    pub fn read_from_stream_with_limits(
+        stream: &mut impl Read,
+        limits: &ReadLimits,
+    ) -> Result<Response> {
+        let (raw_header, body, auth) = read_raw_from_stream(stream, limits)?;
 
         Ok(Response {
             header: raw_header.try_into()?,
             body,
+            auth,
+        })
+    }
+
+    /// Deserialise response from given stream, keeping the values of its header which are
+    /// unknown to this version of the crate, apart from the status.
+    ///
+    /// The body length limit of its opcode is applied as in `read_from_stream_with_limits`, the
+    /// default body length limit applying to unknown opcodes.
+    ///
+    /// # Errors
+    /// - if reading any of the subfields (header or body) fails, the
+    ///   corresponding `ResponseStatus` will be returned.
+    /// - if the response body size specified in the header is larger than the limit of the
+    ///   opcode, `BodySizeExceedsLimit` will be returned.
+    pub fn read_from_stream_lenient(
+        stream: &mut impl Read,
+        limits: &ReadLimits,
+    ) -> Result<LenientResponse> {
+        let (raw_header, body, auth) = read_raw_from_stream(stream, limits)?;
+
+        Ok(LenientResponse {
+            header: raw_header.try_into()?,
+            body,
+            auth,
         })
     }
 }
 
+/// Read the raw header, body and authentication trailer of a response.
+fn read_raw_from_stream(
+    stream: &mut impl Read,
+    limits: &ReadLimits,
+) -> Result<(Raw, ResponseBody, Option<ResponseAuth>)> {
+    let raw_header = Raw::read_from_stream(stream)?;
+    limits.check_response(&raw_header)?;
+    let body_len = usize::try_from(raw_header.body_len)?;
+    let body = ResponseBody::read_from_stream(stream, body_len)?;
+    let auth = if raw_header.flags & RESPONSE_AUTH_FLAG != 0 {
+        Some(ResponseAuth::read_from_stream(
+            stream,
+            usize::from(raw_header.auth_len),
+        )?)
+    } else {
+        None
+    };
+    Ok((raw_header, body, auth))
+}
+
+/// Representation of the response wire format with a lenient header.
+///
+/// It is read with `Response::read_from_stream_lenient` and can be written back unchanged, even
+/// if its header contains values unknown to this version of the crate.
+#[derive(PartialEq, Eq, Debug)]
+pub struct LenientResponse {
+    /// Header of the response, keeping the raw values of its fields
+    pub header: LenientResponseHeader,
+    /// Response body
+    pub body: ResponseBody,
+    /// Optional authentication trailer
+    pub auth: Option<ResponseAuth>,
+}
+
+impl LenientResponse {
+    /// Convert request into an error response with a given `ResponseStatus`.
+    ///
+    /// The relevant fields in the header are preserved and an empty body is provided.
+    pub fn from_request_header(header: LenientRequestHeader, status: ResponseStatus) -> Self {
+        LenientResponse {
+            header: LenientResponseHeader {
+                provider: header.provider,
+                session: header.session,
+                content_type: header.accept_type,
+                opcode: header.opcode,
+                status,
+            },
+            body: ResponseBody::new(),
+            auth: None,
+        }
+    }
+
+    /// Serialise response and write it to given stream.
+    ///
+    /// # Errors
+    /// - if writing any of the subfields (header or body) fails, then
+    ///   `ResponseStatus::ConnectionError` is returned.
+    /// - if encoding any of the fields in the header fails, then
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    pub fn write_to_stream(self, stream: &mut impl Write) -> Result<()> {
+        let mut raw_header: Raw = self.header.into();
+        raw_header.body_len = u32::try_from(self.body.len())?;
+        if let Some(auth) = &self.auth {
+            raw_header.flags |= RESPONSE_AUTH_FLAG;
+            raw_header.auth_len = u16::try_from(auth.buffer.len())?;
+        }
+
+        raw_header.write_to_stream(stream)?;
+        self.body.write_to_stream(stream)?;
+        if let Some(auth) = &self.auth {
+            auth.write_to_stream(stream)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Response> for LenientResponse {
+    fn from(response: Response) -> Self {
+        LenientResponse {
+            header: response.header.into(),
+            body: response.body,
+            auth: response.auth,
+        }
+    }
+}
+
+/// Strict conversion from the lenient response, failing if its header contains unknown values.
+impl TryFrom<LenientResponse> for Response {
+    type Error = ResponseStatus;
+
+    fn try_from(response: LenientResponse) -> Result<Self> {
+        Ok(Response {
+            header: response.header.try_into()?,
+            body: response.body,
+            auth: response.auth,
+        })
+    }
+}
+
+#[cfg(feature = "response-auth")]
+fn response_hmac(key: &[u8]) -> Result<hmac::Hmac<sha2::Sha256>> {
+    hmac::Hmac::new_from_slice(key).map_err(|_| {
+        error!("Invalid key for the response authentication.");
+        ResponseStatus::AuthenticationError
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::utils::tests as test_utils;
-    use super::super::{BodyType, Opcode, ProviderId, ResponseStatus};
+    use super::super::{AuthType, BodyType, Opcode, ProviderId, ResponseStatus};
     use super::*;
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::operations_protobuf::ProtobufConverter;
+    use crate::requests::common::RESPONSE_AUTH_NONCE_LEN;
+    use crate::requests::request::{RequestAuth, RequestBody};
 
     #[test]
     fn response_1_to_stream() {
@@ -187,6 +461,110 @@ mod tests {
         assert_eq!(response_status, ResponseStatus::InvalidHeader);
     }
 
+    #[test]
+    fn error_details_round_trip() {
+        let details = ErrorDetails {
+            message: String::from("Unknown key"),
+            field_path: Some(String::from("key_name")),
+            provider_code: None,
+        };
+        let response = Response::from_request_header_with_details(
+            get_request_header(),
+            ResponseStatus::PsaErrorDoesNotExist,
+            details.clone(),
+            &ProtobufConverter,
+        );
+        assert_eq!(response.header.status, ResponseStatus::PsaErrorDoesNotExist);
+        assert!(!response.body.is_empty());
+
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        response
+            .write_to_stream(&mut mock)
+            .expect("Failed to write response");
+        let response =
+            Response::read_from_stream(&mut mock, 1000).expect("Failed to read response");
+        assert_eq!(
+            response.error_details(&ProtobufConverter).unwrap(),
+            Some(details)
+        );
+    }
+
+    #[test]
+    fn no_error_details() {
+        let response = Response::from_request_header(
+            get_request_header(),
+            ResponseStatus::PsaErrorDoesNotExist,
+        );
+        assert_eq!(response.error_details(&ProtobufConverter).unwrap(), None);
+
+        // Details are not attached to successful responses, and the body of a successful
+        // response is not decoded as details.
+        let response = Response::from_request_header_with_details(
+            get_request_header(),
+            ResponseStatus::Success,
+            ErrorDetails::default(),
+            &ProtobufConverter,
+        );
+        assert!(response.body.is_empty());
+        assert_eq!(
+            get_response_1().error_details(&ProtobufConverter).unwrap(),
+            None
+        );
+    }
+
+    struct NoDetailsConverter;
+
+    impl Convert for NoDetailsConverter {
+        fn body_type(&self) -> BodyType {
+            BodyType::Protobuf
+        }
+        fn body_to_operation(
+            &self,
+            _body: RequestBody,
+            _opcode: Opcode,
+        ) -> Result<NativeOperation> {
+            unimplemented!()
+        }
+        fn operation_to_body(&self, _operation: NativeOperation) -> Result<RequestBody> {
+            unimplemented!()
+        }
+        fn body_to_result(&self, _body: ResponseBody, _opcode: Opcode) -> Result<NativeResult> {
+            unimplemented!()
+        }
+        fn result_to_body(&self, _result: NativeResult) -> Result<ResponseBody> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn unsupported_error_details() {
+        let response = Response::from_request_header_with_details(
+            get_request_header(),
+            ResponseStatus::PsaErrorDoesNotExist,
+            ErrorDetails::default(),
+            &NoDetailsConverter,
+        );
+        assert!(response.body.is_empty());
+
+        let mut response = get_response_1();
+        response.header.status = ResponseStatus::PsaErrorGenericError;
+        assert_eq!(
+            response.error_details(&NoDetailsConverter).unwrap_err(),
+            ResponseStatus::DeserializingBodyFailed
+        );
+    }
+
+    #[test]
+    fn mangled_error_details() {
+        let mut response = get_response_1();
+        response.header.status = ResponseStatus::PsaErrorGenericError;
+        response.body = ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(
+            response.error_details(&ProtobufConverter).unwrap_err(),
+            ResponseStatus::DeserializingBodyFailed
+        );
+    }
+
     #[test]
     #[should_panic(expected = "Failed to read response")]
     fn failed_read() {
@@ -235,6 +613,142 @@ mod tests {
         );
     }
 
+    #[test]
+    fn auth_trailer_round_trip() {
+        let mut response = get_response_1();
+        response.auth = Some(ResponseAuth::new(vec![0xAA, 0xBB]));
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        response
+            .write_to_stream(&mut mock)
+            .expect("Failed to write response");
+
+        let mut expected = get_response_1_bytes();
+        expected[8] = 0x01; // WireHeader::flags
+        expected[26] = 0x02; // WireHeader::auth_len
+        expected.extend(vec![0xAA, 0xBB]);
+        assert_eq!(mock.buffer, expected);
+
+        let read = Response::read_from_stream(&mut mock, 1000).expect("Failed to read response");
+        assert_eq!(read.auth, Some(ResponseAuth::new(vec![0xAA, 0xBB])));
+        assert_eq!(read.body, get_response_1().body);
+    }
+
+    #[test]
+    fn authenticated_data() {
+        let request = get_request();
+        let mut response = get_response_1();
+        let data = response.authenticated_data(&request).unwrap();
+        response.auth = Some(ResponseAuth::new(vec![0xAA; 32]));
+        assert_eq!(response.authenticated_data(&request).unwrap(), data);
+
+        let mut expected = Vec::new();
+        request.write_to_stream(&mut expected).unwrap();
+        let mut response_bytes = get_response_1_bytes();
+        response_bytes[8] = 0x01; // WireHeader::flags
+        expected.extend(response_bytes);
+        assert_eq!(data, expected);
+    }
+
+    #[cfg(feature = "response-auth")]
+    #[test]
+    fn hmac_sign_verify() {
+        let key = b"client secret";
+        let request = get_request();
+        let mut response = get_response_2();
+        assert_eq!(
+            response.verify_hmac(key, &request).unwrap_err(),
+            ResponseStatus::AuthenticationError
+        );
+        response.sign_hmac(key, &request).unwrap();
+        response.verify_hmac(key, &request).unwrap();
+        assert_eq!(
+            response
+                .verify_hmac(b"other client secret", &request)
+                .unwrap_err(),
+            ResponseStatus::AuthenticationError
+        );
+
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        response
+            .write_to_stream(&mut mock)
+            .expect("Failed to write response");
+        // Tamper with the status
+        mock.buffer[32] = 0x01;
+        let tampered =
+            Response::read_from_stream(&mut mock, 1000).expect("Failed to read response");
+        assert_eq!(
+            tampered.verify_hmac(key, &request).unwrap_err(),
+            ResponseStatus::AuthenticationError
+        );
+    }
+
+    #[cfg(feature = "response-auth")]
+    #[test]
+    fn hmac_replayed_response() {
+        let key = b"client secret";
+        let first_request = get_request();
+        let mut response = get_response_2();
+        response.sign_hmac(key, &first_request).unwrap();
+
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        response
+            .write_to_stream(&mut mock)
+            .expect("Failed to write response");
+        let replayed =
+            Response::read_from_stream(&mut mock, 1000).expect("Failed to read response");
+        replayed.verify_hmac(key, &first_request).unwrap();
+
+        // Same request with another nonce
+        let mut second_request = get_request();
+        second_request.response_auth_nonce = Some([0xA5; RESPONSE_AUTH_NONCE_LEN]);
+        assert_eq!(
+            replayed.verify_hmac(key, &second_request).unwrap_err(),
+            ResponseStatus::AuthenticationError
+        );
+
+        // Same header with another body
+        let mut second_request = get_request();
+        second_request.body = RequestBody::from_bytes(vec![0x0A, 0x02, 0x42, 0x44]);
+        assert_eq!(
+            replayed.verify_hmac(key, &second_request).unwrap_err(),
+            ResponseStatus::AuthenticationError
+        );
+
+        let mut second_request = get_request();
+        second_request.header.session = 2;
+        assert_eq!(
+            replayed.verify_hmac(key, &second_request).unwrap_err(),
+            ResponseStatus::AuthenticationError
+        );
+
+        let mut second_request = get_request();
+        second_request.auth = RequestAuth::new(vec![0xC2]);
+        assert_eq!(
+            replayed.verify_hmac(key, &second_request).unwrap_err(),
+            ResponseStatus::AuthenticationError
+        );
+    }
+
+    fn get_request() -> Request {
+        Request {
+            header: get_request_header(),
+            body: RequestBody::from_bytes(vec![0x0A, 0x02, 0x42, 0x43]),
+            auth: RequestAuth::new(vec![0xC0, 0xC1]),
+            response_auth_nonce: Some([0x5A; RESPONSE_AUTH_NONCE_LEN]),
+        }
+    }
+
+    fn get_request_header() -> RequestHeader {
+        RequestHeader {
+            provider: ProviderId::Core,
+            session: 0,
+            content_type: BodyType::Protobuf,
+            accept_type: BodyType::Protobuf,
+            auth_type: AuthType::Direct,
+            opcode: Opcode::PsaDestroyKey,
+        }
+    }
+
     fn get_response_1() -> Response {
         let body = ResponseBody::from_bytes(vec![0x70, 0x80, 0x90]);
         let header = ResponseHeader {
@@ -244,7 +758,11 @@ mod tests {
             opcode: Opcode::Ping,
             status: ResponseStatus::Success,
         };
-        Response { header, body }
+        Response {
+            header,
+            body,
+            auth: None,
+        }
     }
 
     fn get_response_2() -> Response {
@@ -256,7 +774,11 @@ mod tests {
             opcode: Opcode::Ping,
             status: ResponseStatus::Success,
         };
-        Response { header, body }
+        Response {
+            header,
+            body,
+            auth: None,
+        }
     }
 
     fn get_response_1_bytes() -> Vec<u8> {
@@ -0,0 +1,34 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::requests::Result;
+use std::io::{Read, Write};
+
+/// Authentication trailer of a response.
+///
+/// Contains a MAC or a signature over the header and body of the response, allowing the client
+/// to check that the response was produced by the genuine service.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseAuth {
+    /// Buffer holding the MAC or signature as a byte vector
+    pub buffer: Vec<u8>,
+}
+
+impl ResponseAuth {
+    /// Create a new authentication trailer for a response.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        ResponseAuth { buffer: bytes }
+    }
+
+    /// Read a response authentication trailer from the stream, given the length
+    /// of the byte stream contained.
+    pub(super) fn read_from_stream(mut stream: &mut impl Read, len: usize) -> Result<ResponseAuth> {
+        let buffer = get_from_stream!(stream; len);
+        Ok(ResponseAuth { buffer })
+    }
+
+    /// Write response authentication trailer to stream.
+    pub(super) fn write_to_stream(&self, stream: &mut impl Write) -> Result<()> {
+        stream.write_all(&self.buffer)?;
+        Ok(())
+    }
+}
@@ -95,3 +95,104 @@ impl From<ResponseHeader> for Raw {
         }
     }
 }
+
+/// A lenient native representation of the response header.
+///
+/// Contrary to `ResponseHeader`, the provider, content type and opcode are kept as their raw
+/// values, so that responses to operations unknown to this version of the crate can be inspected
+/// and forwarded unchanged. The status is still checked.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LenientResponseHeader {
+    /// Raw provider ID value
+    pub provider: u8,
+    /// Session handle
+    pub session: u64,
+    /// Raw content type
+    pub content_type: u8,
+    /// Raw opcode of the operation performed
+    pub opcode: u32,
+    /// Response status of the request.
+    pub status: ResponseStatus,
+}
+
+impl LenientResponseHeader {
+    /// Provider ID, if known by this version of the crate
+    pub fn provider(&self) -> Option<ProviderId> {
+        FromPrimitive::from_u8(self.provider)
+    }
+
+    /// Content type, if known by this version of the crate
+    pub fn content_type(&self) -> Option<BodyType> {
+        FromPrimitive::from_u8(self.content_type)
+    }
+
+    /// Opcode, if known by this version of the crate
+    pub fn opcode(&self) -> Option<Opcode> {
+        FromPrimitive::from_u32(self.opcode)
+    }
+}
+
+/// Conversion from the raw to the lenient response header, which only fails on unknown statuses.
+impl TryFrom<Raw> for LenientResponseHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: Raw) -> Result<LenientResponseHeader> {
+        let status: ResponseStatus = match FromPrimitive::from_u16(header.status) {
+            Some(status) => status,
+            None => return Err(ResponseStatus::InvalidEncoding),
+        };
+
+        Ok(LenientResponseHeader {
+            provider: header.provider,
+            session: header.session,
+            content_type: header.content_type,
+            opcode: header.opcode,
+            status,
+        })
+    }
+}
+
+/// Conversion from the lenient to the raw response header.
+///
+/// The flags and lengths are left to zero.
+impl From<LenientResponseHeader> for Raw {
+    fn from(header: LenientResponseHeader) -> Self {
+        Raw {
+            flags: 0,
+            provider: header.provider,
+            session: header.session,
+            content_type: header.content_type,
+            accept_type: 0,
+            auth_type: 0,
+            auth_len: 0,
+            body_len: 0,
+            opcode: header.opcode,
+            status: header.status as u16,
+            reserved1: 0,
+            reserved2: 0,
+        }
+    }
+}
+
+impl From<ResponseHeader> for LenientResponseHeader {
+    fn from(header: ResponseHeader) -> Self {
+        LenientResponseHeader {
+            provider: header.provider as u8,
+            session: header.session,
+            content_type: header.content_type as u8,
+            opcode: header.opcode as u32,
+            status: header.status,
+        }
+    }
+}
+
+/// Strict conversion from the lenient to the native response header.
+///
+/// Fails in the same way as the conversion from the raw header if a value is not known.
+impl TryFrom<LenientResponseHeader> for ResponseHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: LenientResponseHeader) -> Result<ResponseHeader> {
+        ResponseHeader::try_from(Raw::from(header))
+    }
+}
@@ -118,6 +118,196 @@ impl TryFrom<u16> for ResponseStatus {
     }
 }
 
+/// Broad category of a failure status, used to decide how an error should be handled
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatusCategory {
+    /// The connection with the service failed
+    Transport,
+    /// The request or response does not follow the wire protocol or its limits
+    Protocol,
+    /// The identity of the client could not be established
+    Authentication,
+    /// The client is not allowed to perform the operation
+    Authorization,
+    /// The request is well-formed but its content is invalid or unsupported
+    ClientInput,
+    /// The provider or the service failed to process a valid request
+    Provider,
+    /// The storage of keys or their mappings failed
+    Storage,
+    /// The underlying hardware failed or was tampered with
+    Hardware,
+}
+
+/// Offset between the code of a PSA `ResponseStatus` and the opposite of the `psa_status_t` value
+const PSA_STATUS_OFFSET: i32 = 1000;
+
+impl ResponseStatus {
+    /// Returns true if the status is one of the PSA Crypto API error codes
+    pub fn is_psa_error(self) -> bool {
+        Error::try_from(self).is_ok()
+    }
+
+    /// Convert the status to the native `psa_status_t` value
+    ///
+    /// `Success` maps to `PSA_SUCCESS` (0) and the PSA errors to their negative PSA values. Returns
+    /// `None` for statuses which are specific to Parsec and have no PSA equivalent.
+    pub fn to_psa_status(self) -> Option<i32> {
+        if self == ResponseStatus::Success {
+            Some(0)
+        } else if self.is_psa_error() {
+            Some(PSA_STATUS_OFFSET - self as i32)
+        } else {
+            None
+        }
+    }
+
+    /// Convert a native `psa_status_t` value to a status
+    ///
+    /// Returns `ResponseStatus::InvalidEncoding` if the value is not `PSA_SUCCESS` or a PSA error
+    /// code known by this crate.
+    pub fn from_psa_status(status: i32) -> Result<Self> {
+        let response_status = PSA_STATUS_OFFSET
+            .checked_sub(status)
+            .filter(|_| status < 0)
+            .and_then(|code| u16::try_from(code).ok())
+            .and_then(num::FromPrimitive::from_u16)
+            .filter(|response_status: &ResponseStatus| response_status.is_psa_error());
+        match (status, response_status) {
+            (0, _) => Ok(ResponseStatus::Success),
+            (_, Some(response_status)) => Ok(response_status),
+            _ => {
+                error!(
+                    "Value {} does not correspond to a known PSA status.",
+                    status
+                );
+                Err(ResponseStatus::InvalidEncoding)
+            }
+        }
+    }
+
+    /// Category of the status, or `None` for `Success`
+    pub fn category(self) -> Option<StatusCategory> {
+        match self {
+            ResponseStatus::Success => None,
+            ResponseStatus::ConnectionError | ResponseStatus::PsaErrorCommunicationFailure => {
+                Some(StatusCategory::Transport)
+            }
+            ResponseStatus::ContentTypeNotSupported
+            | ResponseStatus::AcceptTypeNotSupported
+            | ResponseStatus::WireProtocolVersionNotSupported
+            | ResponseStatus::DeserializingBodyFailed
+            | ResponseStatus::OpcodeDoesNotExist
+            | ResponseStatus::InvalidEncoding
+            | ResponseStatus::InvalidHeader
+            | ResponseStatus::WrongProviderUuid
+            | ResponseStatus::BodySizeExceedsLimit => Some(StatusCategory::Protocol),
+            ResponseStatus::AuthenticationError
+            | ResponseStatus::AuthenticatorDoesNotExist
+            | ResponseStatus::AuthenticatorNotRegistered
+            | ResponseStatus::NotAuthenticated => Some(StatusCategory::Authentication),
+            ResponseStatus::AdminOperation | ResponseStatus::PsaErrorNotPermitted => {
+                Some(StatusCategory::Authorization)
+            }
+            ResponseStatus::WrongProviderId
+            | ResponseStatus::ProviderNotRegistered
+            | ResponseStatus::ProviderDoesNotExist
+            | ResponseStatus::DeprecatedPrimitive
+            | ResponseStatus::PsaErrorNotSupported
+            | ResponseStatus::PsaErrorInvalidArgument
+            | ResponseStatus::PsaErrorInvalidHandle
+            | ResponseStatus::PsaErrorBadState
+            | ResponseStatus::PsaErrorBufferTooSmall
+            | ResponseStatus::PsaErrorAlreadyExists
+            | ResponseStatus::PsaErrorDoesNotExist
+            | ResponseStatus::PsaErrorInsufficientData
+            | ResponseStatus::PsaErrorInvalidSignature
+            | ResponseStatus::PsaErrorInvalidPadding => Some(StatusCategory::ClientInput),
+            ResponseStatus::SerializingBodyFailed
+            | ResponseStatus::ResponseTooLarge
+            | ResponseStatus::PsaErrorGenericError
+            | ResponseStatus::PsaErrorInsufficientMemory => Some(StatusCategory::Provider),
+            ResponseStatus::KeyInfoManagerError
+            | ResponseStatus::PsaErrorInsufficientStorage
+            | ResponseStatus::PsaErrorStorageFailure
+            | ResponseStatus::PsaErrorDataCorrupt
+            | ResponseStatus::PsaErrorDataInvalid => Some(StatusCategory::Storage),
+            ResponseStatus::PsaErrorHardwareFailure
+            | ResponseStatus::PsaErrorCorruptionDetected
+            | ResponseStatus::PsaErrorInsufficientEntropy => Some(StatusCategory::Hardware),
+        }
+    }
+
+    /// Returns true if the same request might succeed if sent again later
+    ///
+    /// This is the case for failures caused by a transient condition of the connection or of the
+    /// resources of the service.
+    pub fn is_retriable(self) -> bool {
+        match self {
+            ResponseStatus::ConnectionError
+            | ResponseStatus::PsaErrorCommunicationFailure
+            | ResponseStatus::PsaErrorInsufficientMemory
+            | ResponseStatus::PsaErrorInsufficientEntropy => true,
+            ResponseStatus::Success
+            | ResponseStatus::WrongProviderId
+            | ResponseStatus::ContentTypeNotSupported
+            | ResponseStatus::AcceptTypeNotSupported
+            | ResponseStatus::WireProtocolVersionNotSupported
+            | ResponseStatus::ProviderNotRegistered
+            | ResponseStatus::ProviderDoesNotExist
+            | ResponseStatus::DeserializingBodyFailed
+            | ResponseStatus::SerializingBodyFailed
+            | ResponseStatus::OpcodeDoesNotExist
+            | ResponseStatus::ResponseTooLarge
+            | ResponseStatus::AuthenticationError
+            | ResponseStatus::AuthenticatorDoesNotExist
+            | ResponseStatus::AuthenticatorNotRegistered
+            | ResponseStatus::KeyInfoManagerError
+            | ResponseStatus::InvalidEncoding
+            | ResponseStatus::InvalidHeader
+            | ResponseStatus::WrongProviderUuid
+            | ResponseStatus::NotAuthenticated
+            | ResponseStatus::BodySizeExceedsLimit
+            | ResponseStatus::AdminOperation
+            | ResponseStatus::DeprecatedPrimitive
+            | ResponseStatus::PsaErrorGenericError
+            | ResponseStatus::PsaErrorNotSupported
+            | ResponseStatus::PsaErrorNotPermitted
+            | ResponseStatus::PsaErrorBufferTooSmall
+            | ResponseStatus::PsaErrorAlreadyExists
+            | ResponseStatus::PsaErrorDoesNotExist
+            | ResponseStatus::PsaErrorBadState
+            | ResponseStatus::PsaErrorInvalidArgument
+            | ResponseStatus::PsaErrorInsufficientStorage
+            | ResponseStatus::PsaErrorStorageFailure
+            | ResponseStatus::PsaErrorDataCorrupt
+            | ResponseStatus::PsaErrorDataInvalid
+            | ResponseStatus::PsaErrorHardwareFailure
+            | ResponseStatus::PsaErrorCorruptionDetected
+            | ResponseStatus::PsaErrorInvalidSignature
+            | ResponseStatus::PsaErrorInvalidPadding
+            | ResponseStatus::PsaErrorInsufficientData
+            | ResponseStatus::PsaErrorInvalidHandle => false,
+        }
+    }
+
+    /// Returns true if the failure is caused by the request of the client rather than by the
+    /// service
+    pub fn is_client_fault(self) -> bool {
+        match self.category() {
+            Some(StatusCategory::Protocol)
+            | Some(StatusCategory::Authentication)
+            | Some(StatusCategory::Authorization)
+            | Some(StatusCategory::ClientInput) => true,
+            Some(StatusCategory::Transport)
+            | Some(StatusCategory::Provider)
+            | Some(StatusCategory::Storage)
+            | Some(StatusCategory::Hardware)
+            | None => false,
+        }
+    }
+}
+
 impl fmt::Display for ResponseStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -351,7 +541,7 @@ impl From<std::convert::Infallible> for ResponseStatus {
     }
 }
 
-use psa_crypto::types::status::Error;
+use psa_crypto::types::status::{Error, Status};
 
 impl From<Error> for ResponseStatus {
     fn from(err: Error) -> ResponseStatus {
@@ -381,5 +571,173 @@ impl From<Error> for ResponseStatus {
     }
 }
 
+impl From<Status> for ResponseStatus {
+    fn from(status: Status) -> ResponseStatus {
+        match status {
+            Status::Success => ResponseStatus::Success,
+            Status::Error(err) => err.into(),
+        }
+    }
+}
+
+/// Conversion of the PSA error statuses back to the PSA Crypto error
+///
+/// The status is returned as the error if it is `Success` or a status specific to Parsec.
+impl TryFrom<ResponseStatus> for Error {
+    type Error = ResponseStatus;
+
+    fn try_from(status: ResponseStatus) -> Result<Self> {
+        match status {
+            ResponseStatus::PsaErrorGenericError => Ok(Error::GenericError),
+            ResponseStatus::PsaErrorNotSupported => Ok(Error::NotSupported),
+            ResponseStatus::PsaErrorNotPermitted => Ok(Error::NotPermitted),
+            ResponseStatus::PsaErrorBufferTooSmall => Ok(Error::BufferTooSmall),
+            ResponseStatus::PsaErrorAlreadyExists => Ok(Error::AlreadyExists),
+            ResponseStatus::PsaErrorDoesNotExist => Ok(Error::DoesNotExist),
+            ResponseStatus::PsaErrorBadState => Ok(Error::BadState),
+            ResponseStatus::PsaErrorInvalidArgument => Ok(Error::InvalidArgument),
+            ResponseStatus::PsaErrorInsufficientMemory => Ok(Error::InsufficientMemory),
+            ResponseStatus::PsaErrorInsufficientStorage => Ok(Error::InsufficientStorage),
+            ResponseStatus::PsaErrorCommunicationFailure => Ok(Error::CommunicationFailure),
+            ResponseStatus::PsaErrorStorageFailure => Ok(Error::StorageFailure),
+            ResponseStatus::PsaErrorDataCorrupt => Ok(Error::DataCorrupt),
+            ResponseStatus::PsaErrorDataInvalid => Ok(Error::DataInvalid),
+            ResponseStatus::PsaErrorHardwareFailure => Ok(Error::HardwareFailure),
+            ResponseStatus::PsaErrorCorruptionDetected => Ok(Error::CorruptionDetected),
+            ResponseStatus::PsaErrorInsufficientEntropy => Ok(Error::InsufficientEntropy),
+            ResponseStatus::PsaErrorInvalidSignature => Ok(Error::InvalidSignature),
+            ResponseStatus::PsaErrorInvalidPadding => Ok(Error::InvalidPadding),
+            ResponseStatus::PsaErrorInsufficientData => Ok(Error::InsufficientData),
+            ResponseStatus::PsaErrorInvalidHandle => Ok(Error::InvalidHandle),
+            status => Err(status),
+        }
+    }
+}
+
 /// A Result type with the Err variant set as a ResponseStatus
 pub type Result<T> = std::result::Result<T, ResponseStatus>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PSA_ERRORS: [(Error, i32); 21] = [
+        (Error::GenericError, -132),
+        (Error::NotPermitted, -133),
+        (Error::NotSupported, -134),
+        (Error::InvalidArgument, -135),
+        (Error::InvalidHandle, -136),
+        (Error::BadState, -137),
+        (Error::BufferTooSmall, -138),
+        (Error::AlreadyExists, -139),
+        (Error::DoesNotExist, -140),
+        (Error::InsufficientMemory, -141),
+        (Error::InsufficientStorage, -142),
+        (Error::InsufficientData, -143),
+        (Error::CommunicationFailure, -145),
+        (Error::StorageFailure, -146),
+        (Error::HardwareFailure, -147),
+        (Error::InsufficientEntropy, -148),
+        (Error::InvalidSignature, -149),
+        (Error::InvalidPadding, -150),
+        (Error::CorruptionDetected, -151),
+        (Error::DataCorrupt, -152),
+        (Error::DataInvalid, -153),
+    ];
+
+    fn all_statuses() -> impl Iterator<Item = ResponseStatus> {
+        (0..=u16::MAX).filter_map(|code| ResponseStatus::try_from(code).ok())
+    }
+
+    #[test]
+    fn psa_errors_round_trip() {
+        for &(error, psa_status) in PSA_ERRORS.iter() {
+            let status = ResponseStatus::from(error);
+            assert!(status.is_psa_error());
+            assert_eq!(Error::try_from(status).unwrap(), error);
+            assert_eq!(status.to_psa_status(), Some(psa_status));
+            assert_eq!(ResponseStatus::from_psa_status(psa_status).unwrap(), status);
+            assert_eq!(ResponseStatus::from(Status::Error(error)), status);
+        }
+    }
+
+    #[test]
+    fn all_psa_statuses_are_mapped() {
+        let psa_statuses: Vec<ResponseStatus> = all_statuses()
+            .filter(|status| *status as u16 >= 1000)
+            .collect();
+        assert_eq!(psa_statuses.len(), PSA_ERRORS.len());
+        for status in psa_statuses {
+            assert!(status.is_psa_error(), "{:?}", status);
+        }
+    }
+
+    #[test]
+    fn success() {
+        let status = ResponseStatus::Success;
+        assert!(!status.is_psa_error());
+        assert_eq!(status.to_psa_status(), Some(0));
+        assert_eq!(ResponseStatus::from_psa_status(0).unwrap(), status);
+        assert_eq!(ResponseStatus::from(Status::Success), status);
+        assert_eq!(Error::try_from(status).unwrap_err(), status);
+    }
+
+    #[test]
+    fn parsec_statuses_are_not_psa() {
+        for status in all_statuses().filter(|status| (1..1000).contains(&(*status as u16))) {
+            assert!(!status.is_psa_error());
+            assert_eq!(status.to_psa_status(), None);
+            assert_eq!(Error::try_from(status).unwrap_err(), status);
+        }
+    }
+
+    #[test]
+    fn categories() {
+        assert_eq!(ResponseStatus::Success.category(), None);
+        assert!(!ResponseStatus::Success.is_retriable());
+        assert!(!ResponseStatus::Success.is_client_fault());
+        for status in all_statuses().filter(|status| *status != ResponseStatus::Success) {
+            let category = status.category().unwrap();
+            if status.is_retriable() {
+                assert!(!status.is_client_fault(), "{:?}", status);
+            }
+            if status.is_client_fault() {
+                assert!(
+                    category != StatusCategory::Transport && category != StatusCategory::Hardware
+                );
+            }
+        }
+        assert_eq!(
+            ResponseStatus::ConnectionError.category(),
+            Some(StatusCategory::Transport)
+        );
+        assert!(ResponseStatus::ConnectionError.is_retriable());
+        assert_eq!(
+            ResponseStatus::NotAuthenticated.category(),
+            Some(StatusCategory::Authentication)
+        );
+        assert!(ResponseStatus::NotAuthenticated.is_client_fault());
+        assert_eq!(
+            ResponseStatus::AdminOperation.category(),
+            Some(StatusCategory::Authorization)
+        );
+        assert!(ResponseStatus::PsaErrorInvalidArgument.is_client_fault());
+        assert!(!ResponseStatus::PsaErrorInvalidArgument.is_retriable());
+        assert_eq!(
+            ResponseStatus::PsaErrorStorageFailure.category(),
+            Some(StatusCategory::Storage)
+        );
+        assert!(!ResponseStatus::PsaErrorStorageFailure.is_client_fault());
+        assert!(ResponseStatus::PsaErrorInsufficientEntropy.is_retriable());
+    }
+
+    #[test]
+    fn unknown_psa_statuses() {
+        for psa_status in [1, -1, -131, -144, -154, 1132, i32::MIN, i32::MAX].iter() {
+            assert_eq!(
+                ResponseStatus::from_psa_status(*psa_status).unwrap_err(),
+                ResponseStatus::InvalidEncoding
+            );
+        }
+    }
+}
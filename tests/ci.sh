@@ -20,6 +20,8 @@ git submodule update --init
 RUST_BACKTRACE=1 cargo build
 RUST_BACKTRACE=1 cargo build --features testing
 RUST_BACKTRACE=1 cargo build --features regenerate-protobuf
+# The committed generated files must match the .proto definitions
+git diff --exit-code -- src/operations_protobuf/generated_ops
 
 #################
 # Static checks #
@@ -0,0 +1,1051 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Golden wire-format vectors shared with the other language clients.
+//!
+//! Every `Opcode` has one file in `tests/golden/v1` describing a complete request frame, a
+//! complete response frame and the fields of the bodies they decode to, as JSON. These tests
+//! check that `ProtobufConverter` and the `Request`/`Response` stream functions produce exactly
+//! those bytes, and that the native values of each vector match its JSON fields. See
+//! `tests/golden/README.md` for the file format and for how to regenerate them.
+use num_traits::FromPrimitive;
+use parsec_interface::operations::list_all_keys::ClientKeyInfo;
+use parsec_interface::operations::list_authenticators::AuthenticatorInfo;
+use parsec_interface::operations::list_capabilities::{KeyTypeCapability, ProviderCapabilities};
+use parsec_interface::operations::list_keys::KeyInfo;
+use parsec_interface::operations::list_providers::{ProviderInfo, Uuid};
+use parsec_interface::operations::psa_algorithm::{
+    Aead, AeadWithDefaultLengthTag, Algorithm, AsymmetricEncryption, AsymmetricSignature, Cipher,
+    Hash, RawKeyAgreement,
+};
+use parsec_interface::operations::psa_key_attributes::{
+    Attributes, EccFamily, Lifetime, Policy, Type, UsageFlags,
+};
+use parsec_interface::operations::{
+    attest_key, batch, can_do_crypto, can_do_crypto_batch, delete_client, list_all_keys,
+    list_authenticators, list_capabilities, list_clients, list_keys, list_opcodes, list_providers,
+    ping, prepare_key_attestation, psa_aead_decrypt, psa_aead_encrypt, psa_asymmetric_decrypt,
+    psa_asymmetric_encrypt, psa_cipher_decrypt, psa_cipher_encrypt, psa_close_key, psa_copy_key,
+    psa_destroy_key, psa_export_key, psa_export_public_key, psa_generate_key, psa_generate_random,
+    psa_get_key_attributes, psa_hash_compare, psa_hash_compute, psa_import_key,
+    psa_raw_key_agreement, psa_sign_hash, psa_sign_message, psa_verify_hash, psa_verify_message,
+};
+use parsec_interface::operations::{Convert, NativeOperation, NativeResult};
+use parsec_interface::operations_protobuf::ProtobufConverter;
+use parsec_interface::requests::request::{RequestAuth, RequestHeader};
+use parsec_interface::requests::response::ResponseHeader;
+use parsec_interface::requests::{
+    AuthType, BodyType, Opcode, ProviderId, Request, Response, ResponseStatus,
+};
+use parsec_interface::secrecy::Secret;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+/// Version of the vector corpus, bumped whenever existing vectors change in an incompatible way.
+const CORPUS_VERSION: &str = "v1";
+const SESSION: u64 = 0x0123_4567_89AB_CDEF;
+const AUTH: &[u8] = b"golden-vectors";
+const BODY_LEN_LIMIT: usize = 1 << 16;
+const KEY_NAME: &str = "golden key";
+
+static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+/// Native description of one golden vector.
+struct Vector {
+    name: &'static str,
+    provider: ProviderId,
+    operation: NativeOperation,
+    result: NativeResult,
+    /// Fields of the request body, in the JSON representation described in the README.
+    request_fields: Value,
+    /// Fields of the response body, in the JSON representation described in the README.
+    response_fields: Value,
+}
+
+/// Content of one golden vector file.
+#[derive(Debug, PartialEq)]
+struct VectorFile {
+    opcode: u32,
+    request: Vec<u8>,
+    response: Vec<u8>,
+    request_fields: Option<Value>,
+    response_fields: Option<Value>,
+}
+
+impl VectorFile {
+    fn parse(text: &str) -> VectorFile {
+        let field = |key: &str| -> String {
+            text.lines()
+                .find_map(|line| line.strip_prefix(&format!("{}: ", key)))
+                .unwrap_or_else(|| panic!("Missing {} field", key))
+                .to_string()
+        };
+        let json_field = |key: &str| -> Option<Value> {
+            text.lines()
+                .find_map(|line| line.strip_prefix(&format!("{}: ", key)))
+                .map(|json| serde_json::from_str(json).expect("Invalid JSON field"))
+        };
+        let opcode = field("opcode");
+        VectorFile {
+            opcode: u32::from_str_radix(opcode.trim_start_matches("0x"), 16)
+                .expect("Invalid opcode field"),
+            request: from_hex(&field("request")),
+            response: from_hex(&field("response")),
+            request_fields: json_field("request_fields"),
+            response_fields: json_field("response_fields"),
+        }
+    }
+
+    /// Whether the frames of both files are the same, whatever their JSON fields.
+    fn same_frames(&self, other: &VectorFile) -> bool {
+        self.opcode == other.opcode
+            && self.request == other.request
+            && self.response == other.response
+    }
+
+    fn render(&self, name: &str) -> String {
+        format!(
+            "# Parsec golden wire vector ({}), generated from the Rust interface.\n\
+             name: {}\n\
+             opcode: 0x{:04X}\n\
+             request: {}\n\
+             response: {}\n\
+             request_fields: {}\n\
+             response_fields: {}\n",
+            CORPUS_VERSION,
+            name,
+            self.opcode,
+            to_hex(&self.request),
+            to_hex(&self.response),
+            render_json(&self.request_fields),
+            render_json(&self.response_fields),
+        )
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn render_json(fields: &Option<Value>) -> String {
+    serde_json::to_string(fields.as_ref().expect("Missing JSON fields"))
+        .expect("Failed to serialize JSON fields")
+}
+
+/// JSON representation of a byte field.
+fn hex(bytes: &[u8]) -> Value {
+    Value::String(to_hex(bytes))
+}
+
+/// JSON representation of a PSA type, the one of the `psa-crypto` crate.
+fn psa(value: impl Serialize) -> Value {
+    serde_json::to_value(value).expect("Failed to serialize PSA value")
+}
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    assert!(hex.len() % 2 == 0, "Odd number of hex digits");
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("Invalid hex digit"))
+        .collect()
+}
+
+fn corpus_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(CORPUS_VERSION)
+}
+
+fn request_header(provider: ProviderId, opcode: Opcode) -> RequestHeader {
+    RequestHeader {
+        provider,
+        session: SESSION,
+        content_type: BodyType::Protobuf,
+        accept_type: BodyType::Protobuf,
+        auth_type: AuthType::Direct,
+        opcode,
+    }
+}
+
+fn response_header(provider: ProviderId, opcode: Opcode) -> ResponseHeader {
+    ResponseHeader {
+        provider,
+        session: SESSION,
+        content_type: BodyType::Protobuf,
+        opcode,
+        status: ResponseStatus::Success,
+    }
+}
+
+/// Encode the native values of a vector into the content of its file.
+fn encode(vector: Vector) -> VectorFile {
+    let opcode = vector.operation.opcode();
+    assert_eq!(opcode, vector.result.opcode());
+
+    let mut request = Vec::new();
+    Request {
+        header: request_header(vector.provider, opcode),
+        body: CONVERTER
+            .operation_to_body(vector.operation)
+            .expect("Failed to convert operation"),
+        auth: RequestAuth::new(AUTH.to_vec()),
+        response_auth_nonce: None,
+    }
+    .write_to_stream(&mut request)
+    .expect("Failed to write request");
+
+    let mut response = Vec::new();
+    Response {
+        header: response_header(vector.provider, opcode),
+        body: CONVERTER
+            .result_to_body(vector.result)
+            .expect("Failed to convert result"),
+        auth: None,
+    }
+    .write_to_stream(&mut response)
+    .expect("Failed to write response");
+
+    VectorFile {
+        opcode: opcode as u32,
+        request,
+        response,
+        request_fields: Some(vector.request_fields),
+        response_fields: Some(vector.response_fields),
+    }
+}
+
+fn attributes() -> Attributes {
+    let mut usage_flags = UsageFlags::default();
+    let _ = usage_flags.set_sign_hash().set_verify_hash();
+    Attributes {
+        lifetime: Lifetime::Persistent,
+        key_type: Type::EccKeyPair {
+            curve_family: EccFamily::SecpR1,
+        },
+        bits: 256,
+        policy: Policy {
+            usage_flags,
+            permitted_algorithms: Algorithm::AsymmetricSignature(ecdsa()),
+        },
+    }
+}
+
+fn ecdsa() -> AsymmetricSignature {
+    AsymmetricSignature::Ecdsa {
+        hash_alg: Hash::Sha256.into(),
+    }
+}
+
+fn gcm() -> Aead {
+    Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Gcm)
+}
+
+fn oaep() -> AsymmetricEncryption {
+    AsymmetricEncryption::RsaOaep {
+        hash_alg: Hash::Sha256,
+    }
+}
+
+fn bytes(seed: u8, len: usize) -> Vec<u8> {
+    (0..len).map(|i| seed.wrapping_add(i as u8)).collect()
+}
+
+/// One vector per opcode. Field values are arbitrary but fixed.
+fn vectors() -> Vec<Vector> {
+    let key_name = String::from(KEY_NAME);
+    vec![
+        Vector {
+            name: "ping",
+            provider: ProviderId::Core,
+            operation: ping::Operation.into(),
+            result: ping::Result {
+                wire_protocol_version_maj: 1,
+                wire_protocol_version_min: 0,
+            }
+            .into(),
+            request_fields: json!({}),
+            response_fields: json!({"wire_protocol_version_maj": 1, "wire_protocol_version_min": 0}),
+        },
+        Vector {
+            name: "psa_generate_key",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_generate_key::Operation {
+                key_name: key_name.clone(),
+                attributes: attributes(),
+            }
+            .into(),
+            result: psa_generate_key::Result::default().into(),
+            request_fields: json!({"key_name": KEY_NAME, "attributes": psa(attributes())}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "psa_destroy_key",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_destroy_key::Operation {
+                key_name: key_name.clone(),
+            }
+            .into(),
+            result: psa_destroy_key::Result.into(),
+            request_fields: json!({"key_name": KEY_NAME}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "psa_sign_hash",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_sign_hash::Operation {
+                key_name: key_name.clone(),
+                alg: ecdsa(),
+                hash: bytes(0x10, 32).into(),
+            }
+            .into(),
+            result: psa_sign_hash::Result {
+                signature: bytes(0x40, 64).into(),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME, "alg": psa(ecdsa()), "hash": hex(&bytes(0x10, 32))}),
+            response_fields: json!({"signature": hex(&bytes(0x40, 64))}),
+        },
+        Vector {
+            name: "psa_verify_hash",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_verify_hash::Operation {
+                key: key_name.as_str().into(),
+                alg: ecdsa(),
+                hash: bytes(0x10, 32).into(),
+                signature: bytes(0x40, 64).into(),
+            }
+            .into(),
+            result: psa_verify_hash::Result.into(),
+            request_fields: json!({"key_name": KEY_NAME, "alg": psa(ecdsa()), "hash": hex(&bytes(0x10, 32)), "signature": hex(&bytes(0x40, 64))}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "psa_import_key",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_import_key::Operation {
+                key_name: key_name.clone(),
+                attributes: attributes(),
+                data: Secret::new(bytes(0x20, 32)),
+            }
+            .into(),
+            result: psa_import_key::Result::default().into(),
+            request_fields: json!({"key_name": KEY_NAME, "attributes": psa(attributes()), "data": hex(&bytes(0x20, 32))}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "psa_export_public_key",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_export_public_key::Operation {
+                key_name: key_name.clone(),
+            }
+            .into(),
+            result: psa_export_public_key::Result {
+                data: bytes(0x04, 65).into(),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME}),
+            response_fields: json!({"data": hex(&bytes(0x04, 65))}),
+        },
+        Vector {
+            name: "list_providers",
+            provider: ProviderId::Core,
+            operation: list_providers::Operation.into(),
+            result: list_providers::Result {
+                providers: vec![ProviderInfo {
+                    uuid: Uuid::parse_str("1c1139dc-ad7c-47dc-ad6b-db6fdb466552")
+                        .expect("Invalid UUID"),
+                    description: String::from("Mbed Crypto provider"),
+                    vendor: String::from("Arm"),
+                    version_maj: 0,
+                    version_min: 1,
+                    version_rev: 0,
+                    id: ProviderId::MbedCrypto,
+                }],
+            }
+            .into(),
+            request_fields: json!({}),
+            response_fields: json!({"providers": [{
+                "uuid": "1c1139dc-ad7c-47dc-ad6b-db6fdb466552",
+                "description": "Mbed Crypto provider",
+                "vendor": "Arm",
+                "version_maj": 0,
+                "version_min": 1,
+                "version_rev": 0,
+                "id": ProviderId::MbedCrypto as u8,
+            }]}),
+        },
+        Vector {
+            name: "list_opcodes",
+            provider: ProviderId::Core,
+            operation: list_opcodes::Operation {
+                provider_id: ProviderId::MbedCrypto,
+            }
+            .into(),
+            // A single element keeps the encoding independent of the `HashSet` iteration order.
+            result: list_opcodes::Result {
+                opcodes: [Opcode::PsaSignHash]
+                    .iter()
+                    .copied()
+                    .collect::<HashSet<_>>(),
+            }
+            .into(),
+            request_fields: json!({"provider_id": ProviderId::MbedCrypto as u8}),
+            response_fields: json!({"opcodes": [Opcode::PsaSignHash as u32]}),
+        },
+        Vector {
+            name: "psa_asymmetric_encrypt",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_asymmetric_encrypt::Operation {
+                key: key_name.as_str().into(),
+                alg: oaep(),
+                plaintext: bytes(0x30, 16).into(),
+                salt: Some(bytes(0x50, 8).into()),
+            }
+            .into(),
+            result: psa_asymmetric_encrypt::Result {
+                ciphertext: bytes(0x60, 32).into(),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME, "alg": psa(oaep()), "plaintext": hex(&bytes(0x30, 16)), "salt": hex(&bytes(0x50, 8))}),
+            response_fields: json!({"ciphertext": hex(&bytes(0x60, 32))}),
+        },
+        Vector {
+            name: "psa_asymmetric_decrypt",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_asymmetric_decrypt::Operation {
+                key_name: key_name.clone(),
+                alg: oaep(),
+                ciphertext: bytes(0x60, 32).into(),
+                salt: None,
+            }
+            .into(),
+            result: psa_asymmetric_decrypt::Result {
+                plaintext: bytes(0x30, 16).into(),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME, "alg": psa(oaep()), "ciphertext": hex(&bytes(0x60, 32))}),
+            response_fields: json!({"plaintext": hex(&bytes(0x30, 16))}),
+        },
+        Vector {
+            name: "psa_export_key",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_export_key::Operation {
+                key_name: key_name.clone(),
+            }
+            .into(),
+            result: psa_export_key::Result {
+                data: Secret::new(bytes(0x20, 32)),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME}),
+            response_fields: json!({"data": hex(&bytes(0x20, 32))}),
+        },
+        Vector {
+            name: "psa_generate_random",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_generate_random::Operation { size: 16 }.into(),
+            result: psa_generate_random::Result {
+                random_bytes: bytes(0x70, 16).into(),
+            }
+            .into(),
+            request_fields: json!({"size": 16}),
+            response_fields: json!({"random_bytes": hex(&bytes(0x70, 16))}),
+        },
+        Vector {
+            name: "list_authenticators",
+            provider: ProviderId::Core,
+            operation: list_authenticators::Operation.into(),
+            result: list_authenticators::Result {
+                authenticators: vec![AuthenticatorInfo {
+                    description: String::from("Direct authenticator"),
+                    version_maj: 0,
+                    version_min: 1,
+                    version_rev: 0,
+                    id: AuthType::Direct,
+                }],
+            }
+            .into(),
+            request_fields: json!({}),
+            response_fields: json!({"authenticators": [{
+                "description": "Direct authenticator",
+                "version_maj": 0,
+                "version_min": 1,
+                "version_rev": 0,
+                "id": AuthType::Direct as u8,
+            }]}),
+        },
+        Vector {
+            name: "psa_hash_compute",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_hash_compute::Operation {
+                alg: Hash::Sha256,
+                input: bytes(0x80, 8).into(),
+            }
+            .into(),
+            result: psa_hash_compute::Result {
+                hash: bytes(0x10, 32).into(),
+            }
+            .into(),
+            request_fields: json!({"alg": psa(Hash::Sha256), "input": hex(&bytes(0x80, 8))}),
+            response_fields: json!({"hash": hex(&bytes(0x10, 32))}),
+        },
+        Vector {
+            name: "psa_hash_compare",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_hash_compare::Operation {
+                alg: Hash::Sha256,
+                input: bytes(0x80, 8).into(),
+                hash: bytes(0x10, 32).into(),
+            }
+            .into(),
+            result: psa_hash_compare::Result.into(),
+            request_fields: json!({"alg": psa(Hash::Sha256), "input": hex(&bytes(0x80, 8)), "hash": hex(&bytes(0x10, 32))}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "psa_aead_encrypt",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_aead_encrypt::Operation {
+                key_name: key_name.clone(),
+                alg: gcm(),
+                nonce: bytes(0x90, 12).into(),
+                additional_data: bytes(0xA0, 4).into(),
+                plaintext: bytes(0x30, 16).into(),
+            }
+            .into(),
+            result: psa_aead_encrypt::Result {
+                ciphertext: bytes(0x60, 32).into(),
+            }
+            .into(),
+            request_fields: json!({
+                "key_name": KEY_NAME,
+                "alg": psa(gcm()),
+                "nonce": hex(&bytes(0x90, 12)),
+                "additional_data": hex(&bytes(0xA0, 4)),
+                "plaintext": hex(&bytes(0x30, 16)),
+            }),
+            response_fields: json!({"ciphertext": hex(&bytes(0x60, 32))}),
+        },
+        Vector {
+            name: "psa_aead_decrypt",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_aead_decrypt::Operation {
+                key_name: key_name.clone(),
+                alg: gcm(),
+                nonce: bytes(0x90, 12).into(),
+                additional_data: bytes(0xA0, 4).into(),
+                ciphertext: bytes(0x60, 32).into(),
+            }
+            .into(),
+            result: psa_aead_decrypt::Result {
+                plaintext: bytes(0x30, 16).into(),
+            }
+            .into(),
+            request_fields: json!({
+                "key_name": KEY_NAME,
+                "alg": psa(gcm()),
+                "nonce": hex(&bytes(0x90, 12)),
+                "additional_data": hex(&bytes(0xA0, 4)),
+                "ciphertext": hex(&bytes(0x60, 32)),
+            }),
+            response_fields: json!({"plaintext": hex(&bytes(0x30, 16))}),
+        },
+        Vector {
+            name: "psa_raw_key_agreement",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_raw_key_agreement::Operation {
+                alg: RawKeyAgreement::Ecdh,
+                private_key_name: key_name.clone(),
+                peer_key: bytes(0x04, 65).into(),
+            }
+            .into(),
+            result: psa_raw_key_agreement::Result {
+                shared_secret: Secret::new(bytes(0xB0, 32)),
+            }
+            .into(),
+            request_fields: json!({"alg": psa(RawKeyAgreement::Ecdh), "private_key_name": KEY_NAME, "peer_key": hex(&bytes(0x04, 65))}),
+            response_fields: json!({"shared_secret": hex(&bytes(0xB0, 32))}),
+        },
+        Vector {
+            name: "psa_cipher_encrypt",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_cipher_encrypt::Operation {
+                key_name: key_name.clone(),
+                alg: Cipher::Ctr,
+                plaintext: bytes(0x30, 16).into(),
+            }
+            .into(),
+            result: psa_cipher_encrypt::Result {
+                ciphertext: bytes(0x60, 32).into(),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME, "alg": psa(Cipher::Ctr), "plaintext": hex(&bytes(0x30, 16))}),
+            response_fields: json!({"ciphertext": hex(&bytes(0x60, 32))}),
+        },
+        Vector {
+            name: "psa_cipher_decrypt",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_cipher_decrypt::Operation {
+                key_name: key_name.clone(),
+                alg: Cipher::Ctr,
+                ciphertext: bytes(0x60, 32).into(),
+            }
+            .into(),
+            result: psa_cipher_decrypt::Result {
+                plaintext: bytes(0x30, 16).into(),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME, "alg": psa(Cipher::Ctr), "ciphertext": hex(&bytes(0x60, 32))}),
+            response_fields: json!({"plaintext": hex(&bytes(0x30, 16))}),
+        },
+        Vector {
+            name: "psa_sign_message",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_sign_message::Operation {
+                key_name: key_name.clone(),
+                alg: ecdsa(),
+                message: bytes(0x80, 8).into(),
+            }
+            .into(),
+            result: psa_sign_message::Result {
+                signature: bytes(0x40, 64).into(),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME, "alg": psa(ecdsa()), "message": hex(&bytes(0x80, 8))}),
+            response_fields: json!({"signature": hex(&bytes(0x40, 64))}),
+        },
+        Vector {
+            name: "psa_verify_message",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_verify_message::Operation {
+                key: key_name.as_str().into(),
+                alg: ecdsa(),
+                message: bytes(0x80, 8).into(),
+                signature: bytes(0x40, 64).into(),
+            }
+            .into(),
+            result: psa_verify_message::Result.into(),
+            request_fields: json!({"key_name": KEY_NAME, "alg": psa(ecdsa()), "message": hex(&bytes(0x80, 8)), "signature": hex(&bytes(0x40, 64))}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "list_keys",
+            provider: ProviderId::Core,
+            operation: list_keys::Operation::default().into(),
+            result: list_keys::Result {
+                keys: vec![KeyInfo {
+                    provider_id: ProviderId::MbedCrypto,
+                    name: key_name.clone(),
+                    attributes: attributes(),
+                }],
+                continuation_token: None,
+            }
+            .into(),
+            request_fields: json!({}),
+            response_fields: json!({"keys": [{
+                "provider_id": ProviderId::MbedCrypto as u8,
+                "name": KEY_NAME,
+                "attributes": psa(attributes()),
+            }]}),
+        },
+        Vector {
+            name: "list_clients",
+            provider: ProviderId::Core,
+            operation: list_clients::Operation.into(),
+            result: list_clients::Result {
+                clients: vec![String::from("golden client")],
+            }
+            .into(),
+            request_fields: json!({}),
+            response_fields: json!({"clients": ["golden client"]}),
+        },
+        Vector {
+            name: "delete_client",
+            provider: ProviderId::Core,
+            operation: delete_client::Operation {
+                client: String::from("golden client"),
+            }
+            .into(),
+            result: delete_client::Result.into(),
+            request_fields: json!({"client": "golden client"}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "list_all_keys",
+            provider: ProviderId::Core,
+            operation: list_all_keys::Operation {
+                client: Some(String::from("golden client")),
+                provider_id: Some(ProviderId::MbedCrypto),
+            }
+            .into(),
+            result: list_all_keys::Result {
+                keys: vec![ClientKeyInfo {
+                    client: String::from("golden client"),
+                    key_info: KeyInfo {
+                        provider_id: ProviderId::MbedCrypto,
+                        name: key_name.clone(),
+                        attributes: attributes(),
+                    },
+                }],
+            }
+            .into(),
+            request_fields: json!({"client": "golden client", "provider_id": ProviderId::MbedCrypto as u8}),
+            response_fields: json!({"keys": [{
+                "client": "golden client",
+                "key_info": {
+                    "provider_id": ProviderId::MbedCrypto as u8,
+                    "name": KEY_NAME,
+                    "attributes": psa(attributes()),
+                },
+            }]}),
+        },
+        Vector {
+            name: "attest_key",
+            provider: ProviderId::Tpm,
+            operation: attest_key::Operation::ActivateCredential {
+                attested_key_name: key_name.clone(),
+                credential_blob: bytes(0xC0, 16).into(),
+                secret: bytes(0xD0, 16).into(),
+                attesting_key_name: None,
+            }
+            .into(),
+            result: attest_key::Result::ActivateCredential {
+                credential: bytes(0xE0, 16).into(),
+            }
+            .into(),
+            request_fields: json!({
+                "attested_key_name": KEY_NAME,
+                "parameters": {"activate_credential": {
+                    "credential_blob": hex(&bytes(0xC0, 16)),
+                    "secret": hex(&bytes(0xD0, 16)),
+                }},
+            }),
+            response_fields: json!({"output": {"activate_credential": {"credential": hex(&bytes(0xE0, 16))}}}),
+        },
+        Vector {
+            name: "prepare_key_attestation",
+            provider: ProviderId::Tpm,
+            operation: prepare_key_attestation::Operation::ActivateCredential {
+                attested_key_name: key_name,
+                attesting_key_name: Some(String::from("golden attesting key")),
+            }
+            .into(),
+            result: prepare_key_attestation::Result::ActivateCredential {
+                name: bytes(0xC0, 34).into(),
+                public: bytes(0xD0, 24).into(),
+                attesting_key_pub: bytes(0xE0, 24).into(),
+            }
+            .into(),
+            request_fields: json!({"parameters": {"activate_credential": {
+                "attested_key_name": KEY_NAME,
+                "attesting_key_name": "golden attesting key",
+            }}}),
+            response_fields: json!({"output": {"activate_credential": {
+                "name": hex(&bytes(0xC0, 34)),
+                "public": hex(&bytes(0xD0, 24)),
+                "attesting_key_pub": hex(&bytes(0xE0, 24)),
+            }}}),
+        },
+        Vector {
+            name: "can_do_crypto",
+            provider: ProviderId::MbedCrypto,
+            operation: can_do_crypto::Operation {
+                check_type: can_do_crypto::CheckType::Generate,
+                attributes: attributes(),
+            }
+            .into(),
+            result: can_do_crypto::Result.into(),
+            request_fields: json!({"check_type": 2, "attributes": psa(attributes())}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "psa_copy_key",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_copy_key::Operation {
+                source_key_name: String::from(KEY_NAME),
+                target_key_name: String::from("golden copy"),
+                attributes: attributes(),
+            }
+            .into(),
+            result: psa_copy_key::Result.into(),
+            request_fields: json!({
+                "source_key_name": KEY_NAME,
+                "target_key_name": "golden copy",
+                "attributes": psa(attributes()),
+            }),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "psa_close_key",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_close_key::Operation {
+                key_handle: Some(String::from("#volatile/1")),
+            }
+            .into(),
+            result: psa_close_key::Result.into(),
+            request_fields: json!({"key_handle": "#volatile/1"}),
+            response_fields: json!({}),
+        },
+        Vector {
+            name: "list_capabilities",
+            provider: ProviderId::Core,
+            operation: list_capabilities::Operation {
+                provider_id: Some(ProviderId::MbedCrypto),
+            }
+            .into(),
+            result: list_capabilities::Result {
+                providers: vec![ProviderCapabilities {
+                    provider_id: ProviderId::MbedCrypto,
+                    key_types: vec![KeyTypeCapability {
+                        key_type: Type::EccKeyPair {
+                            curve_family: EccFamily::SecpR1,
+                        },
+                        min_bits: 256,
+                        max_bits: 384,
+                        algorithms: vec![Algorithm::AsymmetricSignature(
+                            AsymmetricSignature::Ecdsa {
+                                hash_alg: Hash::Sha256.into(),
+                            },
+                        )],
+                        usage_flags: vec![attributes().policy.usage_flags],
+                        check_types: vec![
+                            can_do_crypto::CheckType::Use,
+                            can_do_crypto::CheckType::Generate,
+                        ],
+                    }],
+                }],
+            }
+            .into(),
+            request_fields: json!({"provider_id": ProviderId::MbedCrypto as u8}),
+            response_fields: json!({"providers": [{
+                "provider_id": ProviderId::MbedCrypto as u8,
+                "key_types": [{
+                    "key_type": psa(attributes().key_type),
+                    "min_bits": 256,
+                    "max_bits": 384,
+                    "algorithms": [psa(Algorithm::AsymmetricSignature(ecdsa()))],
+                    "usage_flags": [psa(attributes().policy.usage_flags)],
+                    "check_types": [1, 2],
+                }],
+            }]}),
+        },
+        Vector {
+            name: "can_do_crypto_batch",
+            provider: ProviderId::MbedCrypto,
+            operation: can_do_crypto_batch::Operation {
+                checks: vec![
+                    can_do_crypto::Operation {
+                        check_type: can_do_crypto::CheckType::Generate,
+                        attributes: attributes(),
+                    },
+                    can_do_crypto::Operation {
+                        check_type: can_do_crypto::CheckType::Import,
+                        attributes: attributes(),
+                    },
+                ],
+            }
+            .into(),
+            result: can_do_crypto_batch::Result {
+                statuses: vec![
+                    ResponseStatus::Success,
+                    ResponseStatus::PsaErrorNotSupported,
+                ],
+            }
+            .into(),
+            request_fields: json!({"checks": [
+                {"check_type": 2, "attributes": psa(attributes())},
+                {"check_type": 3, "attributes": psa(attributes())},
+            ]}),
+            response_fields: json!({"statuses": [ResponseStatus::Success as u32, ResponseStatus::PsaErrorNotSupported as u32]}),
+        },
+        Vector {
+            name: "batch",
+            provider: ProviderId::MbedCrypto,
+            operation: batch::Operation {
+                continue_on_error: false,
+                operations: vec![
+                    psa_generate_random::Operation { size: 16 }.into(),
+                    psa_hash_compute::Operation {
+                        alg: Hash::Sha256,
+                        input: bytes(0xA0, 16).into(),
+                    }
+                    .into(),
+                ],
+            }
+            .into(),
+            result: batch::Result {
+                results: vec![
+                    Ok(psa_generate_random::Result {
+                        random_bytes: bytes(0xB0, 16).into(),
+                    }
+                    .into()),
+                    Err(ResponseStatus::PsaErrorNotSupported),
+                ],
+            }
+            .into(),
+            request_fields: json!({
+                "continue_on_error": false,
+                "operations": [
+                    {"opcode": Opcode::PsaGenerateRandom as u32, "body": {"size": 16}},
+                    {
+                        "opcode": Opcode::PsaHashCompute as u32,
+                        "body": {"alg": psa(Hash::Sha256), "input": hex(&bytes(0xA0, 16))},
+                    },
+                ],
+            }),
+            response_fields: json!({"results": [
+                {
+                    "opcode": Opcode::PsaGenerateRandom as u32,
+                    "status": ResponseStatus::Success as u32,
+                    "body": {"random_bytes": hex(&bytes(0xB0, 16))},
+                },
+                {"status": ResponseStatus::PsaErrorNotSupported as u32},
+            ]}),
+        },
+        Vector {
+            name: "psa_get_key_attributes",
+            provider: ProviderId::MbedCrypto,
+            operation: psa_get_key_attributes::Operation {
+                key_name: String::from(KEY_NAME),
+            }
+            .into(),
+            result: psa_get_key_attributes::Result {
+                provider_id: ProviderId::MbedCrypto,
+                attributes: attributes(),
+            }
+            .into(),
+            request_fields: json!({"key_name": KEY_NAME}),
+            response_fields: json!({"provider_id": ProviderId::MbedCrypto as u8, "attributes": psa(attributes())}),
+        },
+    ]
+}
+
+fn read_vector(name: &str) -> VectorFile {
+    let path = corpus_dir().join(format!("{}.txt", name));
+    let text = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+    VectorFile::parse(&text)
+}
+
+#[test]
+fn every_opcode_has_a_vector() {
+    let covered: Vec<Opcode> = vectors()
+        .iter()
+        .map(|vector| vector.operation.opcode())
+        .collect();
+    for value in 0..=u32::from(u16::MAX) {
+        if let Some(opcode) = Opcode::from_u32(value) {
+            assert!(
+                covered.contains(&opcode),
+                "No golden vector for {:?}",
+                opcode
+            );
+        }
+    }
+}
+
+#[test]
+fn native_values_encode_to_vectors() {
+    for vector in vectors() {
+        let name = vector.name;
+        assert_eq!(
+            encode(vector),
+            read_vector(name),
+            "Golden vector {} does not match the current encoding",
+            name
+        );
+    }
+}
+
+#[test]
+fn vectors_decode_to_native_values() {
+    for vector in vectors() {
+        let file = read_vector(vector.name);
+        let operation_debug = format!("{:?}", vector.operation);
+        let result_debug = format!("{:?}", vector.result);
+
+        let request = Request::read_from_stream(&mut file.request.as_slice(), BODY_LEN_LIMIT)
+            .expect("Failed to read request");
+        let opcode = request.header.opcode;
+        assert_eq!(opcode as u32, file.opcode, "{}", vector.name);
+        assert_eq!(
+            request.header,
+            request_header(vector.provider, opcode),
+            "{}",
+            vector.name
+        );
+        let body = request.body.bytes().to_vec();
+        let operation = CONVERTER
+            .body_to_operation(request.body, opcode)
+            .expect("Failed to convert request body");
+        // Fields holding secrets are hidden by `Debug`, the re-encoding covers them.
+        assert_eq!(
+            format!("{:?}", operation),
+            operation_debug,
+            "{}",
+            vector.name
+        );
+        assert_eq!(
+            CONVERTER
+                .operation_to_body(operation)
+                .expect("Failed to convert operation")
+                .bytes(),
+            &body[..],
+            "{}",
+            vector.name
+        );
+
+        let response = Response::read_from_stream(&mut file.response.as_slice(), BODY_LEN_LIMIT)
+            .expect("Failed to read response");
+        assert_eq!(
+            response.header,
+            response_header(vector.provider, opcode),
+            "{}",
+            vector.name
+        );
+        let body = response.body.bytes().to_vec();
+        let result = CONVERTER
+            .body_to_result(response.body, opcode)
+            .expect("Failed to convert response body");
+        assert_eq!(format!("{:?}", result), result_debug, "{}", vector.name);
+        assert_eq!(
+            CONVERTER
+                .result_to_body(result)
+                .expect("Failed to convert result")
+                .bytes(),
+            &body[..],
+            "{}",
+            vector.name
+        );
+    }
+}
+
+/// Exporter for the corpus: run with `cargo test --test golden_vectors -- --ignored` to add the
+/// vectors of new opcodes. The frames of the existing vectors of a corpus version never change:
+/// the exporter fails instead of rewriting them. Their JSON fields are rewritten.
+#[test]
+#[ignore]
+fn regenerate_golden_vectors() {
+    let dir = corpus_dir();
+    fs::create_dir_all(&dir).expect("Failed to create the corpus directory");
+    for vector in vectors() {
+        let name = vector.name;
+        let path = dir.join(format!("{}.txt", name));
+        let file = encode(vector);
+        if let Ok(text) = fs::read_to_string(&path) {
+            assert!(
+                VectorFile::parse(&text).same_frames(&file),
+                "The frames of the {} vector changed: add a new corpus version instead of \
+                 rewriting {}",
+                name,
+                CORPUS_VERSION
+            );
+        }
+        fs::write(&path, file.render(name))
+            .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+    }
+}
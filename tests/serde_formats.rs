@@ -0,0 +1,72 @@
+// Copyright 2026 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON serialization of the types meant to be exchanged as JSON documents.
+//!
+//! These tests live outside of the library so that the `serde_json` impls of `PartialEq` do not
+//! leak into the type inference of the unit tests.
+use parsec_interface::operations::psa_algorithm::{Algorithm, Hash};
+use parsec_interface::operations::psa_key_attributes::{
+    Attributes, Lifetime, Policy, Type, UsageFlags,
+};
+use parsec_interface::operations::utils_compliance_profile::ComplianceProfile;
+use parsec_interface::operations::utils_public_key_format::{from_jwk, to_jwk, Jwk};
+
+fn attrs(key_type: Type, bits: usize, permitted_algorithms: Algorithm) -> Attributes {
+    Attributes {
+        lifetime: Lifetime::Persistent,
+        key_type,
+        bits,
+        policy: Policy {
+            usage_flags: UsageFlags::default(),
+            permitted_algorithms,
+        },
+    }
+}
+
+#[test]
+fn compliance_profile_load_json() {
+    let profile: ComplianceProfile = serde_json::from_str(
+        r#"{
+            "name": "legacy-permissive"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(profile, ComplianceProfile::permissive("legacy-permissive"));
+    assert!(profile
+        .evaluate_attributes(&attrs(Type::Des, 56, Algorithm::Hash(Hash::Ripemd160)))
+        .is_empty());
+}
+
+#[test]
+fn compliance_profile_json_round_trip() {
+    for profile in [
+        ComplianceProfile::default(),
+        ComplianceProfile::fips(),
+        ComplianceProfile::cnsa(),
+    ] {
+        let json = serde_json::to_string(&profile).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ComplianceProfile>(&json).unwrap(),
+            profile
+        );
+    }
+}
+
+#[test]
+fn jwk_serde() {
+    let jwk: Jwk = serde_json::from_str(
+        r#"{"kty":"EC","crv":"P-256",
+            "x":"f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU",
+            "y":"x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"}"#,
+    )
+    .unwrap();
+    let key = from_jwk(&jwk).unwrap();
+    assert_eq!(key.data.len(), 65);
+    let attributes = attrs(key.key_type, key.bits, Algorithm::None);
+    assert_eq!(to_jwk(&attributes, &key.data).unwrap(), jwk);
+    assert_eq!(
+        serde_json::to_string(&to_jwk(&attributes, &key.data).unwrap()).unwrap(),
+        r#"{"kty":"EC","crv":"P-256","x":"f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU","y":"x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"}"#
+    );
+}